@@ -38,13 +38,39 @@
 //!
 //! The last property aims to facilitate migrations which add or remove enum
 //! variants.
+//!
+//! By default the position of a variant is its index in the declaration, so
+//! removing or reordering variants shifts the bits of other variants. To avoid
+//! that, positions may be assigned explicitly with `#[acl_bit(n)]`, which maps
+//! the variant to the bits `2n + 1` (role) and `2n + 2` (admin):
+//!
+//! ```ignore
+//! #[derive(AccessControlRole)]
+//! pub enum Role {
+//!     #[acl_bit(0)]
+//!     LevelA,
+//!     // Position 1 was used by a removed variant and must not be reused.
+//!     #[acl_bit(2)]
+//!     LevelC,
+//! }
+//! ```
+//!
+//! Either all or none of the variants must specify `acl_bit`, so positions
+//! can't be shifted by adding a variant without explicit position. Positions
+//! must be unique and less than `MAX_ROLE_VARIANTS`. Gaps are allowed and
+//! reserve the bits of removed variants.
+//!
+//! Besides `AccessControlRole`, the derive generates conversions between the
+//! enum and `u8` as well as variant names. Role names are parsed with
+//! `TryFrom<&str>` or `FromStr`, which fail for strings that don't match a
+//! variant name exactly.
 
 use crate::utils::cratename;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
 use quote::quote;
 use std::convert::TryFrom;
-use syn::{parse_macro_input, ItemEnum};
+use syn::{parse_macro_input, ItemEnum, Variant};
 
 /// Roles as are represented by enum variants which are, in turn, represented by
 /// `u128` bitflags. Each variant requires two flags, one for the role itself
@@ -56,30 +82,36 @@ pub const MAX_ROLE_VARIANTS: u8 = 63;
 const DEFAULT_SUPER_ADMIN_NAME: &str = "__SUPER_ADMIN";
 const DEFAULT_BITFLAGS_TYPE_NAME: &str = "RoleFlags";
 const DEFAULT_BOUNDCHECKER_TYPE_NAME: &str = "__AclBoundchecker";
+const ACL_BIT_ATTRIBUTE: &str = "acl_bit";
 
 /// Generates the token stream that implements `AccessControlRole`.
 pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
-    // This derive doesn't take attributes, so no need to use `darling`.
+    // The only attribute is `acl_bit` on variants, so no need to use `darling`.
     let cratename = cratename();
     let input: ItemEnum = parse_macro_input!(input);
     let ItemEnum {
         ident, variants, ..
     } = input;
 
-    let variant_idents = variants.into_iter().map(|v| v.ident).collect::<Vec<_>>();
     assert!(
-        variant_idents.len() <= usize::from(MAX_ROLE_VARIANTS),
+        variants.len() <= usize::from(MAX_ROLE_VARIANTS),
         "The number of enum variants should not exceed MAX_ROLE_VARIANTS",
     );
-    let variant_idxs: Vec<_> =
-        (0..u8::try_from(variant_idents.len()).expect("Too many enum variants")).collect();
+    let variants: Vec<_> = variants.into_iter().collect();
+    let variant_idxs = match variant_positions(&variants) {
+        Ok(positions) => positions,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let variant_idents = variants.into_iter().map(|v| v.ident).collect::<Vec<_>>();
     let variant_names: Vec<_> = variant_idents.iter().map(|v| format!("{}", v)).collect();
 
     let boundchecker_type = Ident::new(DEFAULT_BOUNDCHECKER_TYPE_NAME, ident.span());
     let bitflags_type_ident = new_bitflags_type_ident(Span::call_site());
-    let bitflags_idents = bitflags_idents(variant_names.as_ref(), bitflags_type_ident.span());
-    let bitflags_idxs: Vec<_> =
-        (0..u8::try_from(bitflags_idents.len()).expect("Too many bitflags")).collect();
+    let (bitflags_idents, bitflags_idxs) = bitflags_idents(
+        variant_names.as_ref(),
+        variant_idxs.as_ref(),
+        bitflags_type_ident.span(),
+    );
 
     let output = quote! {
         // Ensure #ident satisfies bounds required for acl. This is done
@@ -159,6 +191,14 @@ pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
             }
         }
 
+        impl ::std::str::FromStr for #ident {
+            type Err = &'static str;
+
+            fn from_str(value: &str) -> Result<#ident, Self::Err> {
+                ::std::convert::TryFrom::try_from(value)
+            }
+        }
+
         /// Panics if `n` is too large.
         fn safe_leftshift(value: u128, n: u8) -> u128 {
             value
@@ -218,7 +258,70 @@ pub fn new_bitflags_type_ident(span: Span) -> Ident {
     Ident::new(DEFAULT_BITFLAGS_TYPE_NAME, span)
 }
 
-fn bitflags_idents(names: &[String], span: Span) -> Vec<Ident> {
+/// Returns the positions of `variants`, which are either assigned explicitly via `acl_bit` or
+/// correspond to the index of the variant.
+fn variant_positions(variants: &[Variant]) -> syn::Result<Vec<u8>> {
+    let explicit_positions = variants
+        .iter()
+        .map(acl_bit)
+        .collect::<syn::Result<Vec<_>>>()?;
+    if explicit_positions.iter().all(Option::is_none) {
+        return Ok((0..u8::try_from(variants.len()).expect("Too many enum variants")).collect());
+    }
+
+    let mut positions: Vec<u8> = vec![];
+    for (variant, position) in variants.iter().zip(explicit_positions) {
+        let position = position.ok_or_else(|| {
+            syn::Error::new_spanned(
+                &variant.ident,
+                "Either all or none of the variants must specify `acl_bit`",
+            )
+        })?;
+        if let Some(other) = positions.iter().position(|p| *p == position) {
+            return Err(syn::Error::new_spanned(
+                &variant.ident,
+                format!(
+                    "`acl_bit({})` is already assigned to variant `{}`",
+                    position, variants[other].ident
+                ),
+            ));
+        }
+        positions.push(position);
+    }
+    Ok(positions)
+}
+
+/// Parses the position passed via `#[acl_bit(n)]` to `variant`, if any.
+fn acl_bit(variant: &Variant) -> syn::Result<Option<u8>> {
+    let mut attrs = variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident(ACL_BIT_ATTRIBUTE));
+    let attr = match attrs.next() {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    if let Some(duplicate) = attrs.next() {
+        return Err(syn::Error::new_spanned(
+            duplicate,
+            "`acl_bit` may be specified only once per variant",
+        ));
+    }
+    let lit: syn::LitInt = attr.parse_args()?;
+    let position: u8 = lit.base10_parse()?;
+    if position >= MAX_ROLE_VARIANTS {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!("`acl_bit` must be less than {}", MAX_ROLE_VARIANTS),
+        ));
+    }
+    Ok(Some(position))
+}
+
+/// Returns the identifiers of bitflags along with the index of their bit. The
+/// role and admin flags of a variant at `position` are at `2 * position + 1`
+/// and `2 * position + 2`.
+fn bitflags_idents(names: &[String], positions: &[u8], span: Span) -> (Vec<Ident>, Vec<u8>) {
     // Assuming enum variant names are in camel case, simply converting them
     // to uppercase is not ideal. However, bitflag identifiers aren't exposed,
     // so let's not bother with converting camel to screaming-snake case.
@@ -231,9 +334,12 @@ fn bitflags_idents(names: &[String], span: Span) -> Vec<Ident> {
         .map(|name| format!("{}_ADMIN", name))
         .collect::<Vec<_>>();
     let mut idents = vec![Ident::new(DEFAULT_SUPER_ADMIN_NAME, span)];
-    for (name, admin_name) in names.iter().zip(admin_names) {
+    let mut idxs = vec![0];
+    for ((name, admin_name), position) in names.iter().zip(admin_names).zip(positions) {
         idents.push(Ident::new(name.as_ref(), span));
+        idxs.push(2 * position + 1);
         idents.push(Ident::new(admin_name.as_ref(), span));
+        idxs.push(2 * position + 2);
     }
-    idents
+    (idents, idxs)
 }
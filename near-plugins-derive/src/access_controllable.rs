@@ -4,7 +4,8 @@ use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
 use darling::FromMeta;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
-use quote::quote;
+use quote::{quote, quote_spanned};
+use std::collections::HashMap;
 use syn::{parse_macro_input, AttributeArgs, ItemFn, ItemStruct};
 
 /// Defines attributes for the `access_controllable` macro.
@@ -13,13 +14,93 @@ pub struct MacroArgs {
     #[darling(default)]
     storage_prefix: Option<String>,
     role_type: darling::util::PathList,
+    /// If set, the owner of the contract (see `Ownable`) is treated as a super-admin.
+    #[darling(default)]
+    owner_is_super_admin: bool,
+    /// The key under which `Ownable` stores the owner. Only used if `owner_is_super_admin` is
+    /// set and must match the `owner_storage_key` passed to `#[ownable]`.
+    #[darling(default)]
+    owner_storage_key: Option<String>,
+    /// Names of `pub` methods guarded by `access_control_any`, e.g.
+    /// `guarded_methods(increase_2, increase_3)`. Their guards are simulated by
+    /// `acl_would_authorize`. If set, it must list every `pub` method guarded by
+    /// `access_control_any`, which is checked at compile time.
+    #[darling(default)]
+    guarded_methods: darling::util::PathList,
+    /// If set, the most recent permission changes are stored on-chain, e.g.
+    /// `audit_log(capacity = 64)`.
+    #[darling(default)]
+    audit_log: Option<AuditLogArgs>,
+    /// The maximum `limit` accepted by paginated getters. Defaults to
+    /// `DEFAULT_MAX_PAGE_SIZE`.
+    #[darling(default)]
+    max_page_size: Option<u64>,
+    /// If set, roles may be granted with an expiration via `acl_grant_role_until`. Otherwise
+    /// checking a role doesn't read expirations from storage.
+    #[darling(default)]
+    expiring_roles: bool,
+    /// If set, `acl_revoke_super_admin` panics instead of revoking the last super-admin.
+    #[darling(default)]
+    prevent_last_super_admin_removal: bool,
+    /// Whether admins for a role may add, revoke and transfer admins for that role. Defaults to
+    /// `true`. Otherwise managing admins requires super-admin permissions.
+    #[darling(default)]
+    admins_manage_admins: Option<bool>,
+    /// Operations whose `*_unchecked` methods are exposed as `#[private]` contract methods, e.g.
+    /// `expose_unchecked(grant_role, revoke_role)`.
+    #[darling(default)]
+    expose_unchecked: darling::util::PathList,
+    /// Maps names of roles to the roles their grantees may grant and revoke in addition to the
+    /// roles they are admin for, e.g. `delegated_roles(Hr(Role::Operator, Role::Viewer))`.
+    #[darling(default)]
+    delegated_roles: HashMap<String, darling::util::PathList>,
+    /// Replaces the `standard` of emitted events, which defaults to the name of the plugin.
+    #[darling(default)]
+    event_standard: Option<String>,
+    /// Replaces the `version` of emitted events, which defaults to the version of the plugin's
+    /// events.
+    #[darling(default)]
+    event_version: Option<String>,
+    /// Path to a function `fn() -> AccountId` that returns the account whose permissions are
+    /// checked, e.g. to authorize the user on whose behalf a relayer calls the contract. Defaults
+    /// to the predecessor.
+    #[darling(default)]
+    caller_resolver: Option<syn::Path>,
+}
+
+/// Defines the arguments of `audit_log`.
+#[derive(Debug, FromMeta)]
+struct AuditLogArgs {
+    /// The maximum number of entries stored in the audit log.
+    capacity: u32,
 }
 
 const DEFAULT_STORAGE_PREFIX: &str = "__acl";
 const DEFAULT_ACL_TYPE_NAME: &str = "__Acl";
-
-const ERR_PARSE_BITFLAG: &str = "Value does not correspond to a permission";
-const ERR_PARSE_ROLE: &str = "Value does not correspond to a role";
+const DEFAULT_OWNER_STORAGE_KEY: &str = "__OWNER__";
+const DEFAULT_MAX_PAGE_SIZE: u64 = 500;
+/// The maximum `limit` of `acl_get_grantees_detailed`, which reads the roles of every returned
+/// grantee. It is lowered to `max_page_size` if the latter is smaller.
+const MAX_DETAILED_PAGE_SIZE: u64 = 50;
+/// Panic message of `acl_get_permissioned_accounts_chunk` for cursors that do not refer to a role
+/// of the contract.
+const ERR_INVALID_CURSOR: &str =
+    "ACL008: ACL: cursor does not refer to a set of permissioned accounts";
+
+// Error messages are prefixed with the codes defined by `near_plugins::PluginError`.
+const ERR_PARSE_BITFLAG: &str = "ACL003: Value does not correspond to a permission";
+const ERR_PARSE_ROLE: &str = "ACL002: Value does not correspond to a role";
+const ERR_NOT_ADMIN: &str =
+    "ACL001: Insufficient permissions: caller is not an admin of the role";
+const ERR_NOT_SUPER_ADMIN: &str =
+    "ACL001: Insufficient permissions: caller is not a super-admin";
+const ERR_EXPIRATION_NOT_IN_FUTURE: &str =
+    "ACL006: ACL: expires_at must be later than the current block timestamp";
+const ERR_EXPIRING_ROLES_DISABLED: &str =
+    "ACL: Expiring roles are disabled, pass `expiring_roles` to `access_control`";
+/// Format of the panic message of `access_control_any`, taking the name of the method and the
+/// required roles. Checks which mimic the guard, e.g. the ones of `Upgradable`, use it as well.
+pub(crate) const ERR_INSUFFICIENT_PERMISSIONS_ANY: &str = "ACL001: Insufficient permissions for method {} restricted by access control. Requires one of these roles: {:?}";
 
 /// Generates the token stream that implements `AccessControllable`.
 pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream {
@@ -36,6 +117,11 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             return TokenStream::from(e.write_errors());
         }
     };
+    let emit_event = utils::emit_event(
+        &cratename,
+        &macro_args.event_standard,
+        &macro_args.event_version,
+    );
     let storage_prefix = macro_args
         .storage_prefix
         .unwrap_or_else(|| DEFAULT_STORAGE_PREFIX.to_string());
@@ -46,9 +132,130 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
     );
     let role_type = &macro_args.role_type[0];
 
+    let effective_caller = match &macro_args.caller_resolver {
+        Some(resolver) => quote! { #resolver() },
+        None => quote! { ::near_sdk::env::predecessor_account_id() },
+    };
+    let max_page_size = macro_args.max_page_size.unwrap_or(DEFAULT_MAX_PAGE_SIZE);
+    let expiring_roles = macro_args.expiring_roles;
+    let prevent_last_super_admin_removal = macro_args.prevent_last_super_admin_removal;
+    let admins_manage_admins = macro_args.admins_manage_admins.unwrap_or(true);
+    // Constructed here, i.e. at compile time, to avoid formatting during contract execution.
+    let err_page_size_exceeded = format!("ACL005: ACL: limit must not exceed {}", max_page_size);
+    let max_detailed_page_size = max_page_size.min(MAX_DETAILED_PAGE_SIZE);
+    let err_detailed_page_size_exceeded = format!(
+        "ACL005: ACL: limit must not exceed {}",
+        max_detailed_page_size
+    );
+
+    let is_owner = if macro_args.owner_is_super_admin {
+        let owner_storage_key = macro_args
+            .owner_storage_key
+            .unwrap_or_else(|| DEFAULT_OWNER_STORAGE_KEY.to_string());
+        // With `#[ownable(multi)]` the slot holds the borsh-serialized list of co-owners. A plain
+        // account id is never a valid serialization of a list, since the length prefix of a list
+        // contains bytes which are not allowed in account ids.
+        quote! {
+            ::near_sdk::env::storage_read((#owner_storage_key).as_bytes()).map_or(false, |owner_bytes| {
+                owner_bytes == account_id.as_bytes()
+                    || <Vec<::near_sdk::AccountId> as ::near_sdk::borsh::BorshDeserialize>::try_from_slice(&owner_bytes)
+                        .map_or(false, |owners| owners.contains(account_id))
+            })
+        }
+    } else {
+        quote! {
+            let _ = account_id;
+            false
+        }
+    };
+
+    // The roles of guarded methods are provided by the functions which `access_control_any`
+    // generates next to them, hence they cannot diverge from the guards. Compilation fails with an
+    // error pointing to the name in `guarded_methods` if the method is not guarded.
+    let mut guarded_method_names = vec![];
+    let mut guarded_method_arms = vec![];
+    for method in macro_args.guarded_methods.iter() {
+        let method = match method.get_ident() {
+            Some(method) => method,
+            None => {
+                return syn::Error::new_spanned(method, "guarded_methods expects names of methods")
+                    .to_compile_error()
+                    .into()
+            }
+        };
+        let name = method.to_string();
+        let guard_roles = guard_roles_ident(method);
+        guarded_method_arms.push(quote_spanned! {method.span()=>
+            #name => Some(Self::#guard_roles()),
+        });
+        guarded_method_names.push(name);
+    }
+    // Checked by `access_control_any` to fail compilation if a guarded method is not registered.
+    let registered_guarded_methods = if guarded_method_names.is_empty() {
+        quote! { None }
+    } else {
+        quote! { Some(&[#(#guarded_method_names),*]) }
+    };
+
+    // Sort by role name to make the generated code deterministic.
+    let mut delegated_roles: Vec<_> = macro_args.delegated_roles.into_iter().collect();
+    delegated_roles.sort_by(|a, b| a.0.cmp(&b.0));
+    let delegation_checks = delegated_roles.iter().map(|(delegator, roles)| {
+        if roles.is_empty() {
+            return quote! {};
+        }
+        let delegator = syn::Ident::new(delegator, Span::call_site());
+        let roles = roles.iter();
+        quote! {
+            if matches!(role, #(#roles)|*) && self.has_role(#role_type::#delegator, account_id) {
+                return true;
+            }
+        }
+    });
+
+    // Fails compilation with an error pointing to the contract if the owner should be treated as
+    // super-admin but the contract isn't `Ownable`.
+    let assert_ownable = if macro_args.owner_is_super_admin {
+        quote_spanned! {ident.span()=>
+            const _: () = {
+                fn __assert_ownable<T: #cratename::Ownable>() {}
+                let _ = __assert_ownable::<#ident>;
+            };
+        }
+    } else {
+        quote! {}
+    };
+
+    let exposed_unchecked =
+        match expose_unchecked_methods(&ident, role_type, &macro_args.expose_unchecked) {
+            Ok(methods) => methods,
+            Err(err) => return err.to_compile_error().into(),
+        };
+
+    let (audit_log_type, record_audit_entry, recent_audit_entries) = match macro_args.audit_log {
+        Some(AuditLogArgs { capacity }) => {
+            assert!(capacity > 0, "The capacity of audit_log must be positive");
+            audit_log(&ident, capacity)
+        }
+        None => (
+            quote! {},
+            quote! {
+                let _ = (action, role, account_id);
+            },
+            quote! {
+                let _ = (skip, limit);
+                Vec::new()
+            },
+        ),
+    };
+
     let output = quote! {
         #input
 
+        #assert_ownable
+
+        #audit_log_type
+
         #[derive(::near_sdk::borsh::BorshDeserialize, ::near_sdk::borsh::BorshSerialize)]
         #[borsh(crate = "near_sdk::borsh")]
         /// NOTE: Despite `near_sdk::store::UnorderedMap` and `near_sdk::store::UnorderedSet`
@@ -98,6 +305,12 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             Bearers,
             BearersSet { permission: #bitflags_type },
             AclStorage,
+            RoleExpirations,
+            RateLimits,
+            AuditLog,
+            AuditLogEntries,
+            BearersIndex,
+            BearersIndexTree { permission: #bitflags_type },
         }
 
         /// Generates a prefix by concatenating the input parameters.
@@ -108,6 +321,11 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
         }
 
         impl #ident {
+            /// Names of the methods passed to `guarded_methods`, or `None` if there are none.
+            #[doc(hidden)]
+            pub const __ACL_GUARDED_METHODS: Option<&'static [&'static str]> =
+                #registered_guarded_methods;
+
             fn acl_get_storage(&self) -> Option<#acl_type> {
                 let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
                 near_sdk::env::storage_read(&__acl_storage_prefix(
@@ -124,6 +342,40 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 self.acl_get_storage().unwrap_or_else(|| self.acl_init_storage_unchecked())
             }
 
+            /// Returns the account whose permissions are checked by access control. It is the
+            /// predecessor, unless a `caller_resolver` is passed to `access_control`.
+            pub fn acl_effective_caller() -> ::near_sdk::AccountId {
+                #effective_caller
+            }
+
+            /// Records a call of the rate limited action identified by `key` by the predecessor
+            /// account. It panics if the predecessor already performed `max` such calls within
+            /// the current window, which starts with the first call recorded after the previous
+            /// window ended and lasts `window_ns` nanoseconds.
+            ///
+            /// Call counts are stored per `key` and account. Using e.g. the role that guards a
+            /// method as part of `key` limits calls of that method by each grantee of the role.
+            ///
+            /// This method is not exposed as a contract method. It is meant to be called at the
+            /// top of sensitive methods, as a defense in depth against compromised keys.
+            pub fn acl_check_and_record_rate_limit(&mut self, key: &str, max: u32, window_ns: u64) {
+                let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
+                let mut rate_limits: ::near_sdk::store::LookupMap<(String, ::near_sdk::AccountId), (::near_sdk::Timestamp, u32)> =
+                    ::near_sdk::store::LookupMap::new(__acl_storage_prefix(base_prefix, __AclStorageKey::RateLimits));
+
+                let now = ::near_sdk::env::block_timestamp();
+                let entry = (key.to_string(), #ident::acl_effective_caller());
+                let (window_start, count) = match rate_limits.get(&entry) {
+                    Some(&(window_start, count)) if now < window_start.saturating_add(window_ns) => (window_start, count),
+                    // No calls recorded in the current window.
+                    _ => (now, 0),
+                };
+                if count >= max {
+                    ::near_sdk::env::panic_str(&format!("ACL004: Rate limit exceeded for {}", key));
+                }
+                rate_limits.insert(entry, (window_start, count + 1));
+            }
+
             fn acl_init_storage_unchecked(&mut self) -> #acl_type {
                 let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
                 let acl_storage: #acl_type = Default::default();
@@ -143,6 +395,79 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 ::near_sdk::store::UnorderedSet::new(__acl_storage_prefix(base_prefix, specifier))
             }
 
+            /// Returns the map which stores expiration timestamps of roles granted via
+            /// `grant_role_until`. It is keyed by the grantee and the role's permission.
+            ///
+            /// The map is not a field of `#acl_type` to keep the layout of existing plugin state
+            /// unchanged.
+            fn role_expirations() -> ::near_sdk::store::LookupMap<(::near_sdk::AccountId, #bitflags_type), ::near_sdk::Timestamp> {
+                let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
+                ::near_sdk::store::LookupMap::new(__acl_storage_prefix(base_prefix, __AclStorageKey::RoleExpirations))
+            }
+
+            /// Returns the map which stores the bearers of every permission ordered by account id.
+            /// It allows retrieving a page of bearers starting at an account id without loading
+            /// all bearers.
+            ///
+            /// The map is not a field of `#acl_type` to keep the layout of existing plugin state
+            /// unchanged. Hence bearers added by versions without the index are missing from it.
+            fn bearers_index() -> ::near_sdk::store::LookupMap<#bitflags_type, #cratename::access_controllable::BearersIndex> {
+                let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
+                ::near_sdk::store::LookupMap::new(__acl_storage_prefix(base_prefix, __AclStorageKey::BearersIndex))
+            }
+
+            /// Appends an entry to the audit log. Once the log is full, the oldest entry is
+            /// overwritten. This is a no-op unless `audit_log` is passed to `#[access_control]`.
+            fn record_audit_entry(
+                action: #cratename::access_controllable::AclAuditAction,
+                role: Option<String>,
+                account_id: &::near_sdk::AccountId,
+            ) {
+                #record_audit_entry
+            }
+
+            /// Returns up to `limit` entries of the audit log, starting with the most recent one
+            /// and skipping the `skip` most recent ones.
+            fn recent_audit_entries(skip: u64, limit: u64) -> Vec<#cratename::access_controllable::AclAuditEntry> {
+                #recent_audit_entries
+            }
+
+            /// Panics if `limit` exceeds the maximum page size, which is set via `max_page_size`.
+            /// This prevents paginated getters from running out of gas due to huge limits.
+            fn assert_page_limit(limit: u64) {
+                ::near_sdk::require!(limit <= #max_page_size, #err_page_size_exceeded);
+            }
+
+            /// Like `assert_page_limit`, but with the stricter limit of getters which read the
+            /// roles of every returned account.
+            fn assert_detailed_page_limit(limit: u64) {
+                ::near_sdk::require!(limit <= #max_detailed_page_size, #err_detailed_page_size_exceeded);
+            }
+
+            /// Returns the expiration of the grant of `permission` to `account_id`, or `None` if
+            /// it doesn't expire. Storage is read only if `expiring_roles` is enabled.
+            fn role_expiration(&self, permission: #bitflags_type, account_id: &::near_sdk::AccountId) -> Option<::near_sdk::Timestamp> {
+                if !#expiring_roles {
+                    return None;
+                }
+                Self::role_expirations().get(&(account_id.clone(), permission)).copied()
+            }
+
+            /// Removes the expiration of the grant of `permission` to `account_id`. This is a
+            /// no-op unless `expiring_roles` is enabled.
+            fn clear_role_expiration(&self, permission: #bitflags_type, account_id: &::near_sdk::AccountId) {
+                if #expiring_roles {
+                    Self::role_expirations().remove(&(account_id.clone(), permission));
+                }
+            }
+
+            /// Returns whether `account_id` was granted `permission` with an expiration that has
+            /// passed.
+            fn is_expired(&self, permission: #bitflags_type, account_id: &::near_sdk::AccountId) -> bool {
+                self.role_expiration(permission, account_id)
+                    .map_or(false, |expires_at| ::near_sdk::env::block_timestamp() >= expires_at)
+            }
+
             fn get_or_insert_permissions(&mut self, account_id: ::near_sdk::AccountId) -> &mut #bitflags_type {
                 self.permissions.entry(account_id).or_insert_with(|| #bitflags_type::empty())
             }
@@ -155,18 +480,85 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 if super_admins.len() > 0 {
                     return false;
                 }
+                // Emits `SuperAdminAdded`, which makes the bootstrap auditable like any other
+                // addition of a super-admin.
                 let res = self.add_super_admin_unchecked(account_id);
                 ::near_sdk::require!(res, "Failed to init super-admin.");
                 res
             }
 
             fn add_super_admin(&mut self, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                if !self.is_super_admin(&#ident::acl_effective_caller()) {
                     return None;
                 }
                 Some(self.add_super_admin_unchecked(account_id))
             }
 
+            fn clone_permissions(&mut self, from: &::near_sdk::AccountId, to: &::near_sdk::AccountId) -> Option<u32> {
+                if !self.is_super_admin(&#ident::acl_effective_caller()) {
+                    return None;
+                }
+                Some(self.clone_permissions_unchecked(from, to))
+            }
+
+            /// Grants `to` every permission that `from` bears and `to` lacks __without__ checking
+            /// any permissions. Returns the number of permissions that were newly granted.
+            ///
+            /// Expired grants of `from` are skipped and expirations are copied along with grants.
+            /// If `to` already bears a role with an expiration, it is extended to the one of
+            /// `from` if that is later, or removed if `from` bears the role permanently.
+            fn clone_permissions_unchecked(&mut self, from: &::near_sdk::AccountId, to: &::near_sdk::AccountId) -> u32 {
+                let source = match self.permissions.get(from) {
+                    Some(&permissions) => permissions,
+                    None => return 0,
+                };
+                let mut copied = 0;
+
+                for name in <#role_type>::acl_role_variants() {
+                    let role: #role_type = ::std::convert::TryFrom::try_from(name)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+
+                    let admin_flag = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                        .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                    if source.contains(admin_flag) && self.add_admin_unchecked(role, to) {
+                        copied += 1;
+                    }
+
+                    let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                        .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                    if source.contains(flag) && !self.is_expired(flag, from) {
+                        let from_expires_at = self.role_expiration(flag, from);
+                        if !self.has_role(role, to) {
+                            match from_expires_at {
+                                Some(expires_at) => self.grant_role_until_unchecked(role, to, expires_at),
+                                None => self.grant_role_unchecked(role, to),
+                            };
+                            copied += 1;
+                        } else if let Some(to_expires_at) = self.role_expiration(flag, to) {
+                            // `to` keeps the role at least as long as `from`. Since `to` already
+                            // bears the role, this isn't counted as newly granted.
+                            match from_expires_at {
+                                Some(expires_at) if expires_at > to_expires_at => {
+                                    self.grant_role_until_unchecked(role, to, expires_at);
+                                }
+                                Some(_) => {}
+                                None => {
+                                    self.grant_role_unchecked(role, to);
+                                }
+                            }
+                        }
+                    }
+                }
+
+                let super_admin_flag = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                if source.contains(super_admin_flag) && self.add_super_admin_unchecked(to) {
+                    copied += 1;
+                }
+
+                copied
+            }
+
             /// Makes `account_id` a super-admin __without__ checking any permissions.
             /// It returns whether `account_id` is a new super-admin.
             ///
@@ -183,15 +575,29 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
 
                     let event = #cratename::access_controllable::events::SuperAdminAdded {
                         account: account_id.clone(),
-                        by: ::near_sdk::env::predecessor_account_id(),
+                        by: #ident::acl_effective_caller(),
                     };
-                    #cratename::events::AsEvent::emit(&event);
+                    #emit_event;
+                    Self::record_audit_entry(
+                        #cratename::access_controllable::AclAuditAction::SuperAdminAdded,
+                        None,
+                        account_id,
+                    );
                 }
 
                 is_new_super_admin
             }
 
+            /// Returns whether `account_id` is the owner of the contract and the owner is
+            /// configured to be treated as super-admin via `owner_is_super_admin`.
+            fn is_owner_super_admin(account_id: &::near_sdk::AccountId) -> bool {
+                #is_owner
+            }
+
             fn is_super_admin(&self, account_id: &::near_sdk::AccountId) -> bool {
+                if Self::is_owner_super_admin(account_id) {
+                    return true;
+                }
                 let permissions = {
                     match self.permissions.get(account_id) {
                         Some(permissions) => permissions,
@@ -204,14 +610,21 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             }
 
             fn revoke_super_admin(&mut self, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_super_admin(&::near_sdk::env::predecessor_account_id()) {
+                if !self.is_super_admin(&#ident::acl_effective_caller()) {
                     return None;
                 }
+                if #prevent_last_super_admin_removal {
+                    let flag = <#bitflags_type>::from_bits(<#role_type>::acl_super_admin_permission())
+                        .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                    let is_last_super_admin = self.get_bearers_count(flag) == 1
+                        && self.bearers.get(&flag).map_or(false, |set| set.contains(account_id));
+                    ::near_sdk::require!(!is_last_super_admin, "ACL007: ACL: Cannot revoke the last super-admin");
+                }
                 Some(self.revoke_super_admin_unchecked(account_id))
             }
 
             fn transfer_super_admin(&mut self, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                let current_super_admin = ::near_sdk::env::predecessor_account_id();
+                let current_super_admin = #ident::acl_effective_caller();
                 if !self.is_super_admin(&current_super_admin) {
                     return None;
                 }
@@ -253,21 +666,57 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
 
                     let event = #cratename::access_controllable::events::SuperAdminRevoked {
                         account: account_id.clone(),
-                        by: ::near_sdk::env::predecessor_account_id(),
+                        by: #ident::acl_effective_caller(),
                     };
-                    #cratename::events::AsEvent::emit(&event);
+                    #emit_event;
+                    Self::record_audit_entry(
+                        #cratename::access_controllable::AclAuditAction::SuperAdminRevoked,
+                        None,
+                        account_id,
+                    );
                 }
 
                 was_super_admin
             }
 
+            /// Returns whether `account_id` may add, revoke and transfer admins for `role`. Unless
+            /// disabled via `admins_manage_admins`, this holds for every admin for `role`.
+            /// Otherwise only super-admins may manage admins.
+            fn can_manage_admins(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                if #admins_manage_admins {
+                    self.is_admin(role, account_id)
+                } else {
+                    self.is_super_admin(account_id)
+                }
+            }
+
+            /// Returns whether `account_id` may grant and revoke `role`. This holds for every admin
+            /// for `role` and for grantees of roles that `role` is delegated to via
+            /// `delegated_roles`.
+            fn can_manage_role(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                if self.is_admin(role, account_id) {
+                    return true;
+                }
+                #(#delegation_checks)*
+                false
+            }
+
             fn add_admin(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                if !self.can_manage_admins(role, &#ident::acl_effective_caller()) {
                     return None;
                 }
                 Some(self.add_admin_unchecked(role, account_id))
             }
 
+            fn make_role_operator(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                if !self.is_super_admin(&#ident::acl_effective_caller()) {
+                    return None;
+                }
+                let is_new_admin = self.add_admin_unchecked(role, account_id);
+                let is_new_grantee = self.grant_role_unchecked(role, account_id);
+                Some(is_new_admin || is_new_grantee)
+            }
+
             /// Makes `account_id` an admin for role, __without__ checking any
             /// permissions. Returns whether `account_id` is a new admin for `role`.
             ///
@@ -285,15 +734,39 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                     let event = #cratename::access_controllable::events::AdminAdded {
                         role: role.into(),
                         account: account_id.clone(),
-                        by: ::near_sdk::env::predecessor_account_id(),
+                        by: #ident::acl_effective_caller(),
                     };
-                    #cratename::events::AsEvent::emit(&event);
+                    #emit_event;
+                    Self::record_audit_entry(
+                        #cratename::access_controllable::AclAuditAction::AdminAdded,
+                        Some(role.into()),
+                        account_id,
+                    );
                 }
 
                 is_new_admin
             }
 
+            fn transfer_admin(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
+                let current_admin = #ident::acl_effective_caller();
+                if !self.can_manage_admins(role, &current_admin) {
+                    return None;
+                }
+
+                // Following `transfer_super_admin`, the new admin is added before the current
+                // admin is revoked, and transferring to oneself returns early.
+                if account_id == &current_admin {
+                    return Some(true);
+                }
+                let is_new_admin = self.add_admin_unchecked(role, account_id);
+                self.revoke_admin_unchecked(role, &current_admin);
+                Some(is_new_admin)
+            }
+
             fn is_admin(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                if Self::is_owner_super_admin(account_id) {
+                    return true;
+                }
                 let permissions = {
                     match self.permissions.get(account_id) {
                         Some(permissions) => permissions,
@@ -307,15 +780,25 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 permissions.contains(super_admin) || permissions.contains(role_admin)
             }
 
+            /// Returns whether `account_id` bears the admin permission of `role`, ignoring
+            /// super-admin permissions.
+            fn is_role_admin_exact(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                let role_admin = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                self.permissions
+                    .get(account_id)
+                    .map_or(false, |permissions| permissions.contains(role_admin))
+            }
+
             fn revoke_admin(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                if !self.can_manage_admins(role, &#ident::acl_effective_caller()) {
                     return None;
                 }
                 Some(self.revoke_admin_unchecked(role, account_id))
             }
 
             fn renounce_admin(&mut self, role: #role_type) -> bool {
-                self.revoke_admin_unchecked(role, &::near_sdk::env::predecessor_account_id())
+                self.revoke_admin_unchecked(role, &#ident::acl_effective_caller())
             }
 
             /// Revokes admin permissions from `account_id` __without__ checking any
@@ -336,53 +819,147 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                     let event = #cratename::access_controllable::events::AdminRevoked {
                         role: role.into(),
                         account: account_id.clone(),
-                        by: ::near_sdk::env::predecessor_account_id(),
+                        by: #ident::acl_effective_caller(),
                     };
-                    #cratename::events::AsEvent::emit(&event);
+                    #emit_event;
+                    Self::record_audit_entry(
+                        #cratename::access_controllable::AclAuditAction::AdminRevoked,
+                        Some(role.into()),
+                        account_id,
+                    );
                 }
 
                 was_admin
             }
 
             fn grant_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                if !self.can_manage_role(role, &#ident::acl_effective_caller()) {
                     return None;
                 }
                 Some(self.grant_role_unchecked(role, account_id))
             }
 
+            fn grant_role_with_memo(&mut self, role: #role_type, account_id: &::near_sdk::AccountId, memo: String) -> Option<bool> {
+                if !self.can_manage_role(role, &#ident::acl_effective_caller()) {
+                    return None;
+                }
+                Some(self.grant_role_unchecked_with_memo(role, account_id, Some(memo)))
+            }
+
             /// Grants `role` to `account_id` __without__ checking any permissions.
-            /// Returns whether `role` was newly granted to `account_id`.
+            /// Returns whether `role` was newly granted to `account_id`. A grant
+            /// that has expired is not considered, i.e. granting `role` again
+            /// returns `true`.
+            ///
+            /// If `role` was granted with an expiration, the expiration is removed.
             fn grant_role_unchecked(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                self.grant_role_unchecked_with_memo(role, account_id, None)
+            }
+
+            /// Like `grant_role_unchecked`, but includes `memo` in the emitted
+            /// event. The memo is not stored.
+            fn grant_role_unchecked_with_memo(
+                &mut self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                memo: Option<String>,
+            ) -> bool {
                 let flag = <#bitflags_type>::from_bits(role.acl_permission())
                     .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
-                let mut permissions = self.get_or_insert_permissions(account_id.clone());
+                let is_new_grantee = !self.has_role(role, account_id);
+                self.clear_role_expiration(flag, account_id);
 
-                let is_new_grantee = !permissions.contains(flag);
-                if is_new_grantee {
+                let mut permissions = self.get_or_insert_permissions(account_id.clone());
+                if !permissions.contains(flag) {
                     permissions.insert(flag);
                     self.add_bearer(flag, account_id);
+                }
 
+                if is_new_grantee {
                     let event = #cratename::access_controllable::events::RoleGranted {
                         role: role.into(),
-                        by: ::near_sdk::env::predecessor_account_id(),
+                        by: #ident::acl_effective_caller(),
                         to: account_id.clone(),
+                        memo,
                     };
-                    #cratename::events::AsEvent::emit(&event);
+                    #emit_event;
+                    Self::record_audit_entry(
+                        #cratename::access_controllable::AclAuditAction::RoleGranted,
+                        Some(role.into()),
+                        account_id,
+                    );
+                }
+
+                is_new_grantee
+            }
+
+            fn grant_role_until(
+                &mut self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                expires_at: ::near_sdk::Timestamp,
+            ) -> Option<bool> {
+                if !self.can_manage_role(role, &#ident::acl_effective_caller()) {
+                    return None;
                 }
+                Some(self.grant_role_until_unchecked(role, account_id, expires_at))
+            }
 
+            /// Grants `role` to `account_id` until `expires_at` __without__
+            /// checking any permissions. Returns whether `role` was newly granted
+            /// to `account_id`.
+            ///
+            /// If `account_id` is already a grantee of `role`, the expiration is
+            /// set to `expires_at`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `expiring_roles` is not enabled or if `expires_at` is
+            /// not later than the current block timestamp, since the grant
+            /// would be expired right away.
+            fn grant_role_until_unchecked(
+                &mut self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                expires_at: ::near_sdk::Timestamp,
+            ) -> bool {
+                ::near_sdk::require!(#expiring_roles, #ERR_EXPIRING_ROLES_DISABLED);
+                ::near_sdk::require!(expires_at > ::near_sdk::env::block_timestamp(), #ERR_EXPIRATION_NOT_IN_FUTURE);
+                let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                let is_new_grantee = self.grant_role_unchecked(role, account_id);
+                Self::role_expirations().insert((account_id.clone(), flag), expires_at);
                 is_new_grantee
             }
 
             fn revoke_role(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> Option<bool> {
-                if !self.is_admin(role, &::near_sdk::env::predecessor_account_id()) {
+                if !self.can_manage_role(role, &#ident::acl_effective_caller()) {
                     return None;
                 }
                 Some(self.revoke_role_unchecked(role, account_id))
             }
 
+            /// Provides the implementation of `AccessControllable::acl_revoke_role_preview`. It
+            /// mirrors `revoke_role` and `revoke_role_unchecked` without modifying state.
+            fn revoke_role_preview(
+                &self,
+                role: #role_type,
+                account_id: &::near_sdk::AccountId,
+                revoker: &::near_sdk::AccountId,
+            ) -> #cratename::access_controllable::RevokeRolePreview {
+                let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                #cratename::access_controllable::RevokeRolePreview {
+                    authorized: self.can_manage_role(role, revoker),
+                    would_change: self
+                        .permissions
+                        .get(account_id)
+                        .map_or(false, |permissions| permissions.contains(flag)),
+                }
+            }
+
             fn renounce_role(&mut self, role: #role_type) -> bool {
-                self.revoke_role_unchecked(role, &::near_sdk::env::predecessor_account_id())
+                self.revoke_role_unchecked(role, &#ident::acl_effective_caller())
             }
 
             fn revoke_role_unchecked(&mut self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
@@ -397,29 +974,73 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 if was_grantee {
                     permissions.remove(flag);
                     self.remove_bearer(flag, account_id);
+                    self.clear_role_expiration(flag, account_id);
 
                     let event = #cratename::access_controllable::events::RoleRevoked {
                         role: role.into(),
                         from: account_id.clone(),
-                        by: ::near_sdk::env::predecessor_account_id(),
+                        by: #ident::acl_effective_caller(),
                     };
-                    #cratename::events::AsEvent::emit(&event);
+                    #emit_event;
+                    Self::record_audit_entry(
+                        #cratename::access_controllable::AclAuditAction::RoleRevoked,
+                        Some(role.into()),
+                        account_id,
+                    );
                 }
 
                 was_grantee
             }
 
+            /// Provides the implementation of `AccessControllable::acl_prune_expired_grants`.
+            /// Revokes `role` from every account in `account_ids` whose grant has expired and
+            /// returns the number of such accounts.
+            fn prune_expired_grants(&mut self, role: #role_type, account_ids: &[::near_sdk::AccountId]) -> u32 {
+                let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                let mut pruned = 0;
+                for account_id in account_ids {
+                    if self.is_expired(flag, account_id) && self.revoke_role_unchecked(role, account_id) {
+                        pruned += 1;
+                    }
+                }
+                pruned
+            }
+
+            /// Returns the roles granted to `account_id`, in the order of role variants.
+            fn get_roles(&self, account_id: &::near_sdk::AccountId) -> Vec<String> {
+                <#role_type>::acl_role_variants()
+                    .into_iter()
+                    .filter(|&role| {
+                        let role: #role_type = ::std::convert::TryFrom::try_from(role).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                        self.has_role(role, account_id)
+                    })
+                    .map(|role| role.to_string())
+                    .collect()
+            }
+
             fn has_role(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
                 match self.permissions.get(account_id) {
                     Some(permissions) => {
                         let flag = <#bitflags_type>::from_bits(role.acl_permission())
                             .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
-                        permissions.contains(flag)
+                        permissions.contains(flag) && !self.is_expired(flag, account_id)
                     }
                     None => false,
                 }
             }
 
+            /// Like `has_role`, but checks membership in the set of bearers of
+            /// `role` instead of reading the permissions of `account_id`.
+            fn role_contains(&self, role: #role_type, account_id: &::near_sdk::AccountId) -> bool {
+                let flag = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                match self.bearers.get(&flag) {
+                    Some(set) => set.contains(account_id) && !self.is_expired(flag, account_id),
+                    None => false,
+                }
+            }
+
             fn has_any_role(
                 &self, roles: Vec<#role_type>,
                 account_id: &::near_sdk::AccountId
@@ -435,7 +1056,37 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                         <#bitflags_type>::empty(),
                         |acc, x| acc | x,
                     );
-                self.has_any_permission(target, account_id)
+                if !self.has_any_permission(target, account_id) {
+                    return false;
+                }
+                // Expirations need to be checked only if the account bears any of the permissions.
+                roles.into_iter().any(|role| self.has_role(role, account_id))
+            }
+
+            fn has_all_roles(
+                &self, roles: Vec<#role_type>,
+                account_id: &::near_sdk::AccountId
+            ) -> bool {
+                // Create a bitflags value with active bits for all `roles`.
+                let target = roles
+                    .iter()
+                    .map(|role| {
+                        <#bitflags_type>::from_bits(role.acl_permission())
+                            .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG))
+                    })
+                    .fold(
+                        <#bitflags_type>::empty(),
+                        |acc, x| acc | x,
+                    );
+                let permissions = match self.permissions.get(account_id) {
+                    Some(&permissions) => permissions,
+                    None => return roles.is_empty(),
+                };
+                if !permissions.contains(target) {
+                    return false;
+                }
+                // Expirations need to be checked only if the account bears all of the permissions.
+                roles.into_iter().all(|role| self.has_role(role, account_id))
             }
 
             fn has_any_permission(&self, target: #bitflags_type, account_id: &::near_sdk::AccountId) -> bool {
@@ -464,6 +1115,16 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                     Self::new_bearers_set(permission)
                 });
                 set.insert(account_id.clone());
+
+                let mut index = Self::bearers_index();
+                index
+                    .entry(permission)
+                    .or_insert_with(|| {
+                        let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
+                        let specifier = __AclStorageKey::BearersIndexTree { permission };
+                        #cratename::access_controllable::BearersIndex::new(__acl_storage_prefix(base_prefix, specifier))
+                    })
+                    .insert(account_id.clone(), ());
             }
 
             /// Enables paginated retrieval of bearers. Returns up to `limit`
@@ -482,6 +1143,69 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 set.iter().skip(skip).take(limit).cloned().collect()
             }
 
+            /// Like `get_bearers`, but bearers are sorted by account id before
+            /// `skip` and `limit` are applied.
+            fn get_bearers_sorted(&self, permission: #bitflags_type, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                let skip: usize = ::std::convert::TryFrom::try_from(skip).unwrap_or_else(|_| ::near_sdk::env::panic_str("skip should be in the range of usize"));
+                let limit: usize = ::std::convert::TryFrom::try_from(limit).unwrap_or_else(|_| ::near_sdk::env::panic_str("limit should be in the range of usize"));
+                let mut bearers = self.get_all_bearers(permission);
+                bearers.sort();
+                bearers.into_iter().skip(skip).take(limit).collect()
+            }
+
+            /// Returns up to `limit` bearers of `permission` in lexicographic
+            /// order, starting at the first bearer that is greater than or equal
+            /// to `from`, together with the cursor of the next page.
+            ///
+            /// Bearers are read from `bearers_index`, so the cost depends on
+            /// `limit` rather than on the number of bearers. If the index lacks
+            /// bearers added by a previous version of the plugin, all bearers
+            /// are loaded and sorted instead.
+            fn get_bearers_page(
+                &self,
+                permission: #bitflags_type,
+                from: Option<::near_sdk::AccountId>,
+                limit: u64,
+            ) -> #cratename::access_controllable::GranteesPage {
+                let limit: usize = ::std::convert::TryFrom::try_from(limit).unwrap_or_else(|_| ::near_sdk::env::panic_str("limit should be in the range of usize"));
+                let index = Self::bearers_index();
+                let tree = index.get(&permission);
+                // Accounts are added to and removed from the index along with the set of bearers,
+                // hence the index is complete if it has as many entries as the set.
+                let mut accounts: Vec<::near_sdk::AccountId> = if u64::from(tree.map_or(0, |tree| tree.len())) == self.get_bearers_count(permission) {
+                    let start = match &from {
+                        Some(from) => ::std::ops::Bound::Included(from),
+                        None => ::std::ops::Bound::Unbounded,
+                    };
+                    match tree {
+                        Some(tree) => tree
+                            .range((start, ::std::ops::Bound::Unbounded))
+                            .take(limit.saturating_add(1))
+                            .map(|(account_id, _)| account_id.clone())
+                            .collect(),
+                        None => vec![],
+                    }
+                } else {
+                    let mut bearers = self.get_all_bearers(permission);
+                    bearers.sort();
+                    let start = match &from {
+                        Some(from) => bearers.partition_point(|account_id| account_id < from),
+                        None => 0,
+                    };
+                    bearers.into_iter().skip(start).take(limit.saturating_add(1)).collect()
+                };
+                let next = if accounts.len() > limit { accounts.pop() } else { None };
+                #cratename::access_controllable::GranteesPage { accounts, next }
+            }
+
+            /// Returns the number of bearers of `permission`.
+            fn get_bearers_count(&self, permission: #bitflags_type) -> u64 {
+                match self.bearers.get(&permission) {
+                    Some(set) => set.len().into(),
+                    None => 0,
+                }
+            }
+
             /// Returns _all_ bearers of `permission`. In this implementation of
             /// `AccessControllable` there is no upper bound on the number of bearers per
             /// permission, so gas limits should be considered when calling this function.
@@ -502,6 +1226,11 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                     None => return,
                 };
                 set.remove(account_id);
+
+                let mut index = Self::bearers_index();
+                if let Some(tree) = index.get_mut(&permission) {
+                    tree.remove(account_id);
+                }
             }
 
             /// Provides the implementation of `AccessControllable::acl_get_permissioned_accounts`.
@@ -522,21 +1251,7 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 for role in roles {
                     let role: #role_type = ::std::convert::TryFrom::try_from(role)
                         .unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
-                    let admin_permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
-                        .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
-                    let admins = self.get_all_bearers(admin_permission);
-
-                    let grantee_permission = <#bitflags_type>::from_bits(role.acl_permission())
-                        .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
-                    let grantees = self.get_all_bearers(grantee_permission);
-
-                    map.insert(
-                        role.into(),
-                        #cratename::access_controllable::PermissionedAccountsPerRole {
-                            admins,
-                            grantees,
-                        }
-                    );
+                    map.insert(role.into(), self.get_permissioned_accounts_for_role(role));
                 }
 
                 #cratename::access_controllable::PermissionedAccounts {
@@ -544,6 +1259,88 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                     roles: map,
                 }
             }
+
+            /// Returns all admins and grantees of `role`.
+            fn get_permissioned_accounts_for_role(&self, role: #role_type) -> #cratename::access_controllable::PermissionedAccountsPerRole {
+                let admin_permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                let admins = self.get_all_bearers(admin_permission);
+
+                let grantee_permission = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                let grantees = self.get_all_bearers(grantee_permission);
+
+                #cratename::access_controllable::PermissionedAccountsPerRole {
+                    admins,
+                    grantees,
+                }
+            }
+
+            /// Provides the implementation of
+            /// `AccessControllable::acl_get_permissioned_accounts_chunk`.
+            ///
+            /// The set of bearers of every permission is a segment. Segments are
+            /// visited in order, starting at the segment of `cursor`, until
+            /// `max_accounts` accounts have been collected. Within a segment,
+            /// accounts are read in lexicographic order via `get_bearers_page`,
+            /// starting at the account id of `cursor`.
+            fn get_permissioned_accounts_chunk(
+                &self,
+                cursor: Option<#cratename::access_controllable::AclCursor>,
+                max_accounts: u64,
+            ) -> #cratename::access_controllable::PermissionedAccountsChunk {
+                use #cratename::access_controllable::{AclCursor, AclPermissionKind, PermissionedAccount};
+
+                let roles = <#role_type>::acl_role_variants();
+                let mut segments = vec![(0u32, AclPermissionKind::SuperAdmin)];
+                for role_index in 0..roles.len() as u32 {
+                    segments.push((role_index, AclPermissionKind::Admin));
+                    segments.push((role_index, AclPermissionKind::Grantee));
+                }
+
+                let cursor = cursor.unwrap_or(AclCursor {
+                    role_index: 0,
+                    kind: AclPermissionKind::SuperAdmin,
+                    from: None,
+                });
+                let start = segments
+                    .iter()
+                    .position(|segment| *segment == (cursor.role_index, cursor.kind))
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_INVALID_CURSOR));
+
+                let mut accounts = vec![];
+                let mut from = cursor.from;
+                for &(role_index, kind) in &segments[start..] {
+                    let (permission, role) = match kind {
+                        AclPermissionKind::SuperAdmin => (<#role_type>::acl_super_admin_permission(), None),
+                        _ => {
+                            let role: #role_type = ::std::convert::TryFrom::try_from(roles[role_index as usize])
+                                .unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                            let permission = match kind {
+                                AclPermissionKind::Admin => role.acl_admin_permission(),
+                                _ => role.acl_permission(),
+                            };
+                            (permission, Some(String::from(roles[role_index as usize])))
+                        }
+                    };
+                    let permission = <#bitflags_type>::from_bits(permission)
+                        .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+
+                    let remaining = max_accounts - accounts.len() as u64;
+                    let page = self.get_bearers_page(permission, from.take(), remaining);
+                    accounts.extend(page.accounts.into_iter().map(|account_id| PermissionedAccount {
+                        account_id,
+                        kind,
+                        role: role.clone(),
+                    }));
+                    if let Some(next) = page.next {
+                        let next_cursor = Some(AclCursor { role_index, kind, from: Some(next) });
+                        return #cratename::access_controllable::PermissionedAccountsChunk { accounts, next_cursor };
+                    }
+                }
+
+                #cratename::access_controllable::PermissionedAccountsChunk { accounts, next_cursor: None }
+            }
         }
 
         fn get_default_permissioned_accounts() -> #cratename::access_controllable::PermissionedAccounts {
@@ -604,7 +1401,7 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             }
 
             fn acl_is_super_admin(&self, account_id: ::near_sdk::AccountId) -> bool {
-                return_if_none!(self.acl_get_storage(), false).is_super_admin(&account_id)
+                return_if_none!(self.acl_get_storage(), #acl_type::is_owner_super_admin(&account_id)).is_super_admin(&account_id)
             }
 
             fn acl_revoke_super_admin(&mut self, account_id: ::near_sdk::AccountId) -> Option<bool> {
@@ -620,9 +1417,19 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 self.acl_get_or_init().add_admin(role, &account_id)
             }
 
+            fn acl_transfer_admin(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init().transfer_admin(role, &account_id)
+            }
+
             fn acl_is_admin(&self, role: String, account_id: ::near_sdk::AccountId) -> bool {
                 let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
-                return_if_none!(self.acl_get_storage(), false).is_admin(role, &account_id)
+                return_if_none!(self.acl_get_storage(), #acl_type::is_owner_super_admin(&account_id)).is_admin(role, &account_id)
+            }
+
+            fn acl_is_role_admin_exact(&self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                return_if_none!(self.acl_get_storage(), false).is_role_admin_exact(role, &account_id)
             }
 
             fn acl_revoke_admin(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
@@ -640,22 +1447,115 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 self.acl_get_or_init().revoke_role(role, &account_id)
             }
 
+            fn acl_revoke_role_preview(
+                &self,
+                role: String,
+                account_id: ::near_sdk::AccountId,
+                revoker: ::near_sdk::AccountId,
+            ) -> #cratename::access_controllable::RevokeRolePreview {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                match self.acl_get_storage() {
+                    Some(acl) => acl.revoke_role_preview(role, &account_id, &revoker),
+                    None => #cratename::access_controllable::RevokeRolePreview {
+                        authorized: #acl_type::is_owner_super_admin(&revoker),
+                        would_change: false,
+                    },
+                }
+            }
+
             fn acl_renounce_role(&mut self, role: String) -> bool {
                 let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
                 self.acl_get_or_init().renounce_role(role)
             }
 
+            #[private]
+            fn acl_grant_role_to_self(&mut self, role: String) -> bool {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init().grant_role_unchecked(role, &::near_sdk::env::current_account_id())
+            }
+
+            #[private]
+            fn acl_revoke_role_from_self(&mut self, role: String) -> bool {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init().revoke_role_unchecked(role, &::near_sdk::env::current_account_id())
+            }
+
             fn acl_grant_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
                 let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
                 self.acl_get_or_init().grant_role(role, &account_id)
             }
 
+            fn acl_grant_roles(&mut self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> Vec<Option<bool>> {
+                // Parse all roles before granting any of them.
+                let roles: Vec<#role_type> = roles
+                    .iter()
+                    .map(|role| {
+                        ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE))
+                    })
+                    .collect();
+                let mut acl = self.acl_get_or_init();
+                roles
+                    .into_iter()
+                    .map(|role| acl.grant_role(role, &account_id))
+                    .collect()
+            }
+
+            fn acl_make_role_operator(&mut self, role: String, account_id: ::near_sdk::AccountId) -> Option<bool> {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init().make_role_operator(role, &account_id)
+            }
+
+            fn acl_ensure_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init()
+                    .grant_role(role, &account_id)
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_NOT_ADMIN))
+            }
+
+            fn acl_clone_permissions(&mut self, from: ::near_sdk::AccountId, to: ::near_sdk::AccountId) -> u32 {
+                self.acl_get_or_init()
+                    .clone_permissions(&from, &to)
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_NOT_SUPER_ADMIN))
+            }
+
+            fn acl_try_grant_role(&mut self, role: String, account_id: ::near_sdk::AccountId) -> #cratename::access_controllable::AclGrantResult {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                match self.acl_get_or_init().grant_role(role, &account_id) {
+                    None => #cratename::access_controllable::AclGrantResult::Unauthorized,
+                    Some(false) => #cratename::access_controllable::AclGrantResult::AlreadyGranted,
+                    Some(true) => #cratename::access_controllable::AclGrantResult::Granted,
+                }
+            }
+
+            fn acl_grant_role_with_memo(&mut self, role: String, account_id: ::near_sdk::AccountId, memo: String) -> Option<bool> {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init().grant_role_with_memo(role, &account_id, memo)
+            }
+
+            fn acl_grant_role_until(&mut self, role: String, account_id: ::near_sdk::AccountId, expires_at: ::near_sdk::json_types::U64) -> Option<bool> {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                self.acl_get_or_init().grant_role_until(role, &account_id, expires_at.0)
+            }
+
+            fn acl_prune_expired_grants(&mut self, role: String, account_ids: Vec<::near_sdk::AccountId>) -> u32 {
+                <#acl_type>::assert_page_limit(account_ids.len() as u64);
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                match self.acl_get_storage() {
+                    Some(mut acl) => acl.prune_expired_grants(role, &account_ids),
+                    None => 0,
+                }
+            }
 
             fn acl_has_role(&self, role: String, account_id: ::near_sdk::AccountId) -> bool {
                 let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
                 return_if_none!(self.acl_get_storage(), false).has_role(role, &account_id)
             }
 
+            fn acl_role_contains(&self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                return_if_none!(self.acl_get_storage(), false).role_contains(role, &account_id)
+            }
+
             fn acl_has_any_role(&self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> bool {
                 let roles: Vec<#role_type> = roles
                     .iter()
@@ -666,7 +1566,69 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 return_if_none!(self.acl_get_storage(), false).has_any_role(roles, &account_id)
             }
 
+            fn acl_would_authorize(&self, method: String, account_id: ::near_sdk::AccountId) -> bool {
+                let guard: Option<(Vec<&str>, bool)> = match method.as_str() {
+                    #(#guarded_method_arms)*
+                    _ => None,
+                };
+                let (roles, allow_super_admin) = guard.unwrap_or_else(|| {
+                    ::near_sdk::env::panic_str(&format!(
+                        "Method {} is not registered in guarded_methods of access_control",
+                        method,
+                    ))
+                });
+                let roles: Vec<String> = roles.iter().map(|&role| role.into()).collect();
+                self.acl_has_any_role(roles, account_id.clone())
+                    || (allow_super_admin && self.acl_is_super_admin(account_id))
+            }
+
+            fn acl_has_all_roles(&self, roles: Vec<String>, account_id: ::near_sdk::AccountId) -> bool {
+                let roles: Vec<#role_type> = roles
+                    .iter()
+                    .map(|role| {
+                        ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE))
+                    })
+                    .collect();
+                let acl = match self.acl_get_storage() {
+                    Some(acl) => acl,
+                    None => return roles.is_empty(),
+                };
+                acl.has_all_roles(roles, &account_id)
+            }
+
+            fn acl_my_roles(&self) -> Vec<String> {
+                let account_id = #ident::acl_effective_caller();
+                return_if_none!(self.acl_get_storage(), vec![]).get_roles(&account_id)
+            }
+
+            fn acl_am_i_super_admin(&self) -> bool {
+                self.acl_is_super_admin(#ident::acl_effective_caller())
+            }
+
+            fn acl_get_permissions_raw(&self, account_id: ::near_sdk::AccountId) -> u128 {
+                return_if_none!(self.acl_get_storage(), 0)
+                    .permissions
+                    .get(&account_id)
+                    .map_or(0, |permissions| permissions.bits())
+            }
+
+            fn acl_role_name_for_bit(&self, bit_index: u8) -> Option<String> {
+                // Role permissions are represented by odd bits, see the module documentation of
+                // `AccessControlRole`.
+                if bit_index % 2 == 0 {
+                    return None;
+                }
+                let role: #role_type = ::std::convert::TryFrom::try_from((bit_index - 1) / 2).ok()?;
+                Some(role.into())
+            }
+
+            fn acl_bit_for_role_name(&self, name: String) -> Option<u8> {
+                let role: #role_type = ::std::convert::TryFrom::try_from(name.as_str()).ok()?;
+                Some(role.acl_permission().trailing_zeros() as u8)
+            }
+
             fn acl_get_super_admins(&self, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                <#acl_type>::assert_page_limit(limit);
                 let permission = <#bitflags_type>::from_bits(
                     <#role_type>::acl_super_admin_permission()
                 )
@@ -674,7 +1636,23 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
                 return_if_none!(self.acl_get_storage(), vec![]).get_bearers(permission, skip, limit)
             }
 
+            fn acl_recent_events(&self, skip: u64, limit: u64) -> Vec<#cratename::access_controllable::AclAuditEntry> {
+                <#acl_type>::assert_page_limit(limit);
+                <#acl_type>::recent_audit_entries(skip, limit)
+            }
+
+            fn acl_get_super_admins_sorted(&self) -> Vec<::near_sdk::AccountId> {
+                let permission = <#bitflags_type>::from_bits(
+                    <#role_type>::acl_super_admin_permission()
+                )
+                .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                let mut super_admins = return_if_none!(self.acl_get_storage(), vec![]).get_all_bearers(permission);
+                super_admins.sort();
+                super_admins
+            }
+
             fn acl_get_admins(&self, role: String, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                <#acl_type>::assert_page_limit(limit);
                 let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
                 let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
                     .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
@@ -682,25 +1660,308 @@ pub fn access_controllable(attrs: TokenStream, item: TokenStream) -> TokenStream
             }
 
             fn acl_get_grantees(&self, role: String, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                <#acl_type>::assert_page_limit(limit);
                 let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
                 let permission = <#bitflags_type>::from_bits(role.acl_permission())
                     .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
                 return_if_none!(self.acl_get_storage(), vec![]).get_bearers(permission, skip, limit)
             }
 
+            fn acl_get_grantees_detailed(&self, role: String, skip: u64, limit: u64) -> Vec<(::near_sdk::AccountId, Vec<String>)> {
+                <#acl_type>::assert_detailed_page_limit(limit);
+                let grantees = self.acl_get_grantees(role, skip, limit);
+                let acl = return_if_none!(self.acl_get_storage(), vec![]);
+                grantees
+                    .into_iter()
+                    .map(|account_id| {
+                        let roles = acl.get_roles(&account_id);
+                        (account_id, roles)
+                    })
+                    .collect()
+            }
+
+            fn acl_get_role_members(&self, role: String, skip: u64, limit: u64) -> #cratename::access_controllable::PermissionedAccountsPerRole {
+                #cratename::access_controllable::PermissionedAccountsPerRole {
+                    admins: self.acl_get_admins(role.clone(), skip, limit),
+                    grantees: self.acl_get_grantees(role, skip, limit),
+                }
+            }
+
+            fn acl_get_grantees_sorted(&self, role: String, skip: u64, limit: u64) -> Vec<::near_sdk::AccountId> {
+                <#acl_type>::assert_page_limit(limit);
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                let permission = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                return_if_none!(self.acl_get_storage(), vec![]).get_bearers_sorted(permission, skip, limit)
+            }
+
+            fn acl_get_grantees_page(
+                &self,
+                role: String,
+                from: Option<::near_sdk::AccountId>,
+                limit: u64,
+            ) -> #cratename::access_controllable::GranteesPage {
+                <#acl_type>::assert_page_limit(limit);
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                let permission = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                match self.acl_get_storage() {
+                    Some(acl) => acl.get_bearers_page(permission, from, limit),
+                    None => #cratename::access_controllable::GranteesPage { accounts: vec![], next: None },
+                }
+            }
+
+            fn acl_get_super_admins_count(&self) -> u64 {
+                let permission = <#bitflags_type>::from_bits(
+                    <#role_type>::acl_super_admin_permission()
+                )
+                .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                return_if_none!(self.acl_get_storage(), 0).get_bearers_count(permission)
+            }
+
+            fn acl_get_admins_count(&self, role: String) -> u64 {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                let permission = <#bitflags_type>::from_bits(role.acl_admin_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                return_if_none!(self.acl_get_storage(), 0).get_bearers_count(permission)
+            }
+
+            fn acl_get_grantees_count(&self, role: String) -> u64 {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                let permission = <#bitflags_type>::from_bits(role.acl_permission())
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_PARSE_BITFLAG));
+                return_if_none!(self.acl_get_storage(), 0).get_bearers_count(permission)
+            }
+
             fn acl_get_permissioned_accounts(&self) -> #cratename::access_controllable::PermissionedAccounts {
                 return_if_none!(self.acl_get_storage(), get_default_permissioned_accounts()).get_permissioned_accounts()
             }
+
+            fn acl_permissions_snapshot_hash(&self) -> ::near_sdk::CryptoHash {
+                self.acl_get_permissioned_accounts().snapshot_hash()
+            }
+
+            fn acl_get_permissioned_accounts_for_role(&self, role: String) -> #cratename::access_controllable::PermissionedAccountsPerRole {
+                let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str()).unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+                match self.acl_get_storage() {
+                    Some(acl) => acl.get_permissioned_accounts_for_role(role),
+                    None => #cratename::access_controllable::PermissionedAccountsPerRole {
+                        admins: vec![],
+                        grantees: vec![],
+                    },
+                }
+            }
+
+            fn acl_get_permissioned_accounts_chunk(
+                &self,
+                cursor: Option<#cratename::access_controllable::AclCursor>,
+                max_accounts: u32,
+            ) -> #cratename::access_controllable::PermissionedAccountsChunk {
+                let max_accounts = u64::from(max_accounts);
+                ::near_sdk::require!(max_accounts > 0, "ACL009: ACL: max_accounts must be positive");
+                <#acl_type>::assert_page_limit(max_accounts);
+                match self.acl_get_storage() {
+                    Some(acl) => acl.get_permissioned_accounts_chunk(cursor, max_accounts),
+                    None => #cratename::access_controllable::PermissionedAccountsChunk {
+                        accounts: vec![],
+                        next_cursor: None,
+                    },
+                }
+            }
         }
+
+        #exposed_unchecked
     };
 
     output.into()
 }
 
-/// Defines attributes for the `access_control_any` macro.
+/// Generates `#[private]` contract methods `acl_<operation>_unchecked` which call the
+/// corresponding `*_unchecked` methods of the `Acl` struct for every operation in `operations`.
+fn expose_unchecked_methods(
+    ident: &syn::Ident,
+    role_type: &syn::Path,
+    operations: &darling::util::PathList,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let parse_role = quote! {
+        let role: #role_type = ::std::convert::TryFrom::try_from(role.as_str())
+            .unwrap_or_else(|_| ::near_sdk::env::panic_str(#ERR_PARSE_ROLE));
+    };
+
+    let mut methods = vec![];
+    for operation in operations.iter() {
+        let method = match operation
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .as_deref()
+        {
+            Some("add_super_admin") => quote! {
+                #[private]
+                pub fn acl_add_super_admin_unchecked(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                    self.acl_get_or_init().add_super_admin_unchecked(&account_id)
+                }
+            },
+            Some("revoke_super_admin") => quote! {
+                #[private]
+                pub fn acl_revoke_super_admin_unchecked(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                    self.acl_get_or_init().revoke_super_admin_unchecked(&account_id)
+                }
+            },
+            Some("add_admin") => quote! {
+                #[private]
+                pub fn acl_add_admin_unchecked(&mut self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                    #parse_role
+                    self.acl_get_or_init().add_admin_unchecked(role, &account_id)
+                }
+            },
+            Some("revoke_admin") => quote! {
+                #[private]
+                pub fn acl_revoke_admin_unchecked(&mut self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                    #parse_role
+                    self.acl_get_or_init().revoke_admin_unchecked(role, &account_id)
+                }
+            },
+            Some("grant_role") => quote! {
+                #[private]
+                pub fn acl_grant_role_unchecked(&mut self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                    #parse_role
+                    self.acl_get_or_init().grant_role_unchecked(role, &account_id)
+                }
+            },
+            Some("revoke_role") => quote! {
+                #[private]
+                pub fn acl_revoke_role_unchecked(&mut self, role: String, account_id: ::near_sdk::AccountId) -> bool {
+                    #parse_role
+                    self.acl_get_or_init().revoke_role_unchecked(role, &account_id)
+                }
+            },
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    operation,
+                    "expose_unchecked supports add_super_admin, revoke_super_admin, add_admin, \
+                     revoke_admin, grant_role and revoke_role",
+                ))
+            }
+        };
+        methods.push(method);
+    }
+
+    if methods.is_empty() {
+        return Ok(quote! {});
+    }
+    Ok(quote! {
+        #[near]
+        impl #ident {
+            #(#methods)*
+        }
+    })
+}
+
+/// Generates the type which stores the audit log, the body of `__Acl::record_audit_entry` and
+/// the body of `__Acl::recent_audit_entries`.
+///
+/// The log is a ring buffer: entry `n` (counting from zero in the order of recording) is stored at
+/// index `n % capacity` of a `Vector`, so once it holds `capacity` entries the oldest one is
+/// overwritten.
+fn audit_log(
+    ident: &syn::Ident,
+    capacity: u32,
+) -> (
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+) {
+    let cratename = cratename();
+
+    let audit_log_type = quote! {
+        #[derive(::near_sdk::borsh::BorshDeserialize, ::near_sdk::borsh::BorshSerialize)]
+        #[borsh(crate = "near_sdk::borsh")]
+        struct __AclAuditLog {
+            /// The most recent entries, in the order described in `record_audit_entry`.
+            entries: ::near_sdk::store::Vector<#cratename::access_controllable::AclAuditEntry>,
+            /// The number of entries recorded so far, including overwritten ones.
+            total: u64,
+        }
+
+        impl __AclAuditLog {
+            fn storage_key() -> Vec<u8> {
+                let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
+                __acl_storage_prefix(base_prefix, __AclStorageKey::AuditLog)
+            }
+
+            fn load() -> Option<Self> {
+                ::near_sdk::env::storage_read(&Self::storage_key()).map(|bytes| {
+                    ::near_sdk::borsh::BorshDeserialize::try_from_slice(&bytes)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("ACL: invalid audit log format"))
+                })
+            }
+        }
+    };
+
+    let record_audit_entry = quote! {
+        let mut log = __AclAuditLog::load().unwrap_or_else(|| {
+            let base_prefix = <#ident as #cratename::AccessControllable>::acl_storage_prefix();
+            __AclAuditLog {
+                entries: ::near_sdk::store::Vector::new(
+                    __acl_storage_prefix(base_prefix, __AclStorageKey::AuditLogEntries),
+                ),
+                total: 0,
+            }
+        });
+        let entry = #cratename::access_controllable::AclAuditEntry {
+            action,
+            role,
+            account: account_id.clone(),
+            by: #ident::acl_effective_caller(),
+            timestamp: ::near_sdk::env::block_timestamp(),
+        };
+
+        if log.entries.len() < #capacity {
+            log.entries.push(entry);
+        } else {
+            let index = (log.total % u64::from(#capacity)) as u32;
+            log.entries.set(index, entry);
+        }
+        log.total += 1;
+
+        log.entries.flush();
+        ::near_sdk::env::storage_write(
+            &__AclAuditLog::storage_key(),
+            &::near_sdk::borsh::to_vec(&log).unwrap_or_else(|_| ::near_sdk::env::panic_str("Audit log should be serializable")),
+        );
+    };
+
+    let recent_audit_entries = quote! {
+        let log = match __AclAuditLog::load() {
+            Some(log) => log,
+            None => return Vec::new(),
+        };
+        let limit: usize = ::std::convert::TryFrom::try_from(limit).unwrap_or_else(|_| ::near_sdk::env::panic_str("limit should be in the range of usize"));
+        // The `i`-th most recent entry is entry number `total - 1 - i`. Since `len <= total`,
+        // this does not underflow for `i < len`.
+        (skip..u64::from(log.entries.len()))
+            .take(limit)
+            .map(|i| {
+                let index = ((log.total - 1 - i) % u64::from(#capacity)) as u32;
+                log.entries
+                    .get(index)
+                    .cloned()
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str("ACL: invalid audit log format"))
+            })
+            .collect()
+    };
+
+    (audit_log_type, record_audit_entry, recent_audit_entries)
+}
+
+/// Defines attributes for the `access_control_any` and `access_control_all` macros.
 #[derive(Debug, FromMeta)]
 pub struct MacroArgsAny {
     roles: darling::util::PathList,
+    /// If set, super-admins may call the method as well, even if they weren't granted any of the
+    /// `roles`.
+    #[darling(default)]
+    allow_super_admin: bool,
 }
 
 /// Generates the token stream for the `access_control_any` macro.
@@ -721,17 +1982,62 @@ pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream
         }
     };
     let roles = macro_args.roles;
-    assert!(roles.len() > 0, "Specify at least one role");
+    assert!(!roles.is_empty(), "Specify at least one role");
+
+    let is_authorized = if macro_args.allow_super_admin {
+        quote! {
+            self.acl_has_any_role(__acl_any_roles_ser, __acl_any_account_id.clone())
+                || self.acl_is_super_admin(__acl_any_account_id)
+        }
+    } else {
+        quote! { self.acl_has_any_role(__acl_any_roles_ser, __acl_any_account_id) }
+    };
+
+    // Provides the guard to `acl_would_authorize` if the method is listed in `guarded_methods`.
+    // If any methods are listed, compilation fails unless this method is listed too. Methods
+    // without visibility may be part of trait impls, which can't contain these items.
+    let (guard_roles, assert_registered) = if utils::has_visibility(&input) {
+        let guard_roles = guard_roles_ident(&input.sig.ident);
+        let registered = quote::format_ident!("__ACL_GUARD_REGISTERED_{}", input.sig.ident);
+        let allow_super_admin = macro_args.allow_super_admin;
+        let cratename = cratename();
+        let err_not_registered = format!(
+            "Method {} is guarded by access_control_any, but missing in guarded_methods of access_control",
+            function_name,
+        );
+        (
+            quote_spanned! {input.sig.ident.span()=>
+                #[doc(hidden)]
+                #[allow(dead_code)]
+                fn #guard_roles() -> (Vec<&'static str>, bool) {
+                    (vec![#(#roles.into()),*], #allow_super_admin)
+                }
+
+                #[doc(hidden)]
+                #[allow(non_upper_case_globals)]
+                const #registered: () = assert!(
+                    #cratename::access_controllable::is_registered(Self::__ACL_GUARDED_METHODS, #function_name),
+                    #err_not_registered,
+                );
+            },
+            quote! {
+                let () = Self::#registered;
+            },
+        )
+    } else {
+        (quote! {}, quote! {})
+    };
 
     // TODO optimize case `roles.len() == 1` (speed up expected common case)
     let acl_check = quote! {
+        #assert_registered
         let __acl_any_roles: Vec<&str> = vec![#(#roles.into()),*];
         let __acl_any_roles_ser: Vec<String> =
             __acl_any_roles.iter().map(|&role| role.into()).collect();
-        let __acl_any_account_id = ::near_sdk::env::predecessor_account_id();
-        if !self.acl_has_any_role(__acl_any_roles_ser, __acl_any_account_id) {
+        let __acl_any_account_id = Self::acl_effective_caller();
+        if !(#is_authorized) {
             let message = format!(
-                "Insufficient permissions for method {} restricted by access control. Requires one of these roles: {:?}",
+                #ERR_INSUFFICIENT_PERMISSIONS_ANY,
                 #function_name,
                 __acl_any_roles,
             );
@@ -739,5 +2045,60 @@ pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream
         }
     };
 
+    let mut output = proc_macro2::TokenStream::from(utils::add_extra_code_to_fn(&input, acl_check));
+    output.extend(guard_roles);
+    output.into()
+}
+
+/// Returns the name of the function which `access_control_any` generates to return the roles and
+/// the `allow_super_admin` flag of the guard of `method`.
+fn guard_roles_ident(method: &syn::Ident) -> syn::Ident {
+    quote::format_ident!("__acl_guard_roles_{}", method)
+}
+
+/// Generates the token stream for the `access_control_all` macro.
+pub fn access_control_all(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let attr_args = parse_macro_input!(attrs as AttributeArgs);
+    let cloned_item = item.clone();
+    let input: ItemFn = parse_macro_input!(cloned_item);
+    if is_near_bindgen_wrapped_or_marshall(&input) {
+        return item;
+    }
+
+    let function_name = input.sig.ident.to_string();
+
+    let macro_args = match MacroArgsAny::from_list(&attr_args) {
+        Ok(args) => args,
+        Err(e) => {
+            return TokenStream::from(e.write_errors());
+        }
+    };
+    let roles = macro_args.roles;
+    assert!(!roles.is_empty(), "Specify at least one role");
+    assert!(
+        !macro_args.allow_super_admin,
+        "allow_super_admin is supported only by access_control_any"
+    );
+
+    let acl_check = quote! {
+        let __acl_all_roles: Vec<&str> = vec![#(#roles.into()),*];
+        let __acl_all_roles_ser: Vec<String> =
+            __acl_all_roles.iter().map(|&role| role.into()).collect();
+        let __acl_all_account_id = Self::acl_effective_caller();
+        if !self.acl_has_all_roles(__acl_all_roles_ser, __acl_all_account_id.clone()) {
+            // Determining the missing roles is required only on failure.
+            let __acl_all_missing_roles: Vec<&str> = __acl_all_roles
+                .into_iter()
+                .filter(|&role| !self.acl_has_role(role.into(), __acl_all_account_id.clone()))
+                .collect();
+            let message = format!(
+                "ACL001: Insufficient permissions for method {} restricted by access control. Requires all of these roles, missing: {:?}",
+                #function_name,
+                __acl_all_missing_roles,
+            );
+            near_sdk::env::panic_str(&message);
+        }
+    };
+
     utils::add_extra_code_to_fn(&input, acl_check)
 }
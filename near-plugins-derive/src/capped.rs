@@ -0,0 +1,123 @@
+use crate::utils::cratename;
+use darling::util::PathList;
+use darling::FromDeriveInput;
+use proc_macro::{self, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+#[derive(FromDeriveInput, Default)]
+#[darling(default, attributes(capped), forward_attrs(allow, doc, cfg))]
+struct Opts {
+    /// Storage key under which the counter is stored. If it is `None` the default value will be
+    /// used.
+    storage_prefix: Option<String>,
+    /// The maximum value of the counter, as decimal string. It is kept as literal to point
+    /// errors to it.
+    cap: Option<syn::Lit>,
+    /// Access control roles whose grantees may increase the counter.
+    adder_roles: PathList,
+    /// Replaces the `standard` of emitted events, which defaults to the name of the plugin.
+    event_standard: Option<String>,
+    /// Replaces the `version` of emitted events, which defaults to the version of the plugin's
+    /// events.
+    event_version: Option<String>,
+}
+
+const DEFAULT_STORAGE_PREFIX: &str = "__CAPPED__";
+
+const ERR_MISSING_CAP: &str = "Capped: Specify the cap via `cap = \"...\"`";
+
+const ERR_INVALID_CAP: &str = "Capped: `cap` should be a string containing a decimal u128";
+
+// The error message is prefixed with the code defined by `near_plugins::PluginError`.
+const ERR_CAP_EXCEEDED: &str = "CAP001: Capped: Cap exceeded";
+
+/// Generates the token stream that implements `Capped`.
+pub fn derive_capped(input: TokenStream) -> TokenStream {
+    let cratename = cratename();
+
+    let input = parse_macro_input!(input);
+    let opts = Opts::from_derive_input(&input).expect("Wrong options");
+    let emit_event =
+        crate::utils::emit_event(&cratename, &opts.event_standard, &opts.event_version);
+    let DeriveInput { ident, .. } = input;
+
+    let storage_prefix = opts
+        .storage_prefix
+        .unwrap_or_else(|| DEFAULT_STORAGE_PREFIX.to_string());
+    let cap: u128 = match opts.cap {
+        Some(syn::Lit::Str(cap)) => match cap.value().parse() {
+            Ok(cap) => cap,
+            Err(_) => {
+                return syn::Error::new_spanned(cap, ERR_INVALID_CAP)
+                    .to_compile_error()
+                    .into()
+            }
+        },
+        Some(cap) => {
+            return syn::Error::new_spanned(cap, ERR_INVALID_CAP)
+                .to_compile_error()
+                .into()
+        }
+        None => {
+            return syn::Error::new_spanned(&ident, ERR_MISSING_CAP)
+                .to_compile_error()
+                .into()
+        }
+    };
+    let adder_roles = opts.adder_roles;
+    assert!(
+        !adder_roles.is_empty(),
+        "Specify at least one role for adder_roles"
+    );
+
+    let output = quote! {
+        impl #ident {
+            fn capped_read_total(&self) -> u128 {
+                ::near_sdk::env::storage_read(self.capped_storage_key()).map_or(0, |total_bytes| {
+                    ::near_sdk::borsh::BorshDeserialize::try_from_slice(&total_bytes)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("Capped: Invalid u128 format"))
+                })
+            }
+        }
+
+        #[near]
+        impl #cratename::Capped for #ident {
+            fn capped_storage_key(&self) -> &'static [u8] {
+                (#storage_prefix).as_bytes()
+            }
+
+            fn capped_get_cap(&self) -> ::near_sdk::json_types::U128 {
+                #cap.into()
+            }
+
+            fn capped_get_total(&self) -> ::near_sdk::json_types::U128 {
+                self.capped_read_total().into()
+            }
+
+            #[#cratename::access_control_any(roles(#(#adder_roles),*))]
+            fn capped_add(&mut self, amount: ::near_sdk::json_types::U128) -> ::near_sdk::json_types::U128 {
+                let total = self
+                    .capped_read_total()
+                    .checked_add(amount.0)
+                    .filter(|&total| total <= #cap)
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str(#ERR_CAP_EXCEEDED));
+                ::near_sdk::env::storage_write(
+                    self.capped_storage_key(),
+                    &::near_sdk::borsh::to_vec(&total).unwrap(),
+                );
+
+                let event = #cratename::capped::CappedAdded {
+                    by: Self::acl_effective_caller(),
+                    amount,
+                    total: total.into(),
+                };
+                #emit_event;
+
+                total.into()
+            }
+        }
+    };
+
+    output.into()
+}
@@ -0,0 +1,89 @@
+use crate::utils::cratename;
+use darling::FromDeriveInput;
+use proc_macro::{self, TokenStream};
+use quote::quote;
+use syn::{parse_macro_input, DeriveInput};
+
+#[derive(FromDeriveInput, Default)]
+#[darling(default, attributes(contract_metadata), forward_attrs(allow, doc, cfg))]
+struct Opts {
+    /// Whether the state of `Ownable` is included.
+    ownable: bool,
+    /// Whether the state of `Pausable` is included.
+    pausable: bool,
+    /// Whether the state of `AccessControllable` is included.
+    access_controllable: bool,
+    /// Whether the state of `Upgradable` is included.
+    upgradable: bool,
+}
+
+/// Generates the token stream that implements `ContractMetadata`.
+pub fn derive_contract_metadata(input: TokenStream) -> TokenStream {
+    let cratename = cratename();
+
+    let input = parse_macro_input!(input);
+    let opts = Opts::from_derive_input(&input).expect("Wrong options");
+    let DeriveInput { ident, .. } = input;
+
+    // Plugins which are not passed to the macro are reported as `None`. Otherwise their state is
+    // read via their trait methods, which fails compilation if the contract doesn't implement them.
+    let ownable = if opts.ownable {
+        quote! {
+            Some(#cratename::contract_metadata::OwnableStatus {
+                owner: #cratename::Ownable::owner_get(self),
+            })
+        }
+    } else {
+        quote! { None }
+    };
+    let pausable = if opts.pausable {
+        quote! {
+            Some(#cratename::contract_metadata::PausableStatus {
+                paused: {
+                    let mut paused: Vec<String> = #cratename::Pausable::pa_all_paused(self)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .collect();
+                    paused.sort();
+                    paused
+                },
+            })
+        }
+    } else {
+        quote! { None }
+    };
+    let access_controllable = if opts.access_controllable {
+        quote! {
+            Some(#cratename::contract_metadata::AccessControllableStatus {
+                super_admin_count: #cratename::AccessControllable::acl_get_super_admins_count(self),
+            })
+        }
+    } else {
+        quote! { None }
+    };
+    let upgradable = if opts.upgradable {
+        quote! {
+            Some(#cratename::contract_metadata::UpgradableStatus {
+                staged_code_hash: #cratename::Upgradable::up_staged_code_hash(self),
+            })
+        }
+    } else {
+        quote! { None }
+    };
+
+    let output = quote! {
+        #[near]
+        impl #cratename::ContractMetadata for #ident {
+            fn plugin_status(&self) -> #cratename::contract_metadata::PluginStatus {
+                #cratename::contract_metadata::PluginStatus {
+                    ownable: #ownable,
+                    pausable: #pausable,
+                    access_controllable: #access_controllable,
+                    upgradable: #upgradable,
+                }
+            }
+        }
+    };
+
+    output.into()
+}
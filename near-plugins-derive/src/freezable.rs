@@ -0,0 +1,118 @@
+use crate::utils;
+use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
+use darling::util::PathList;
+use darling::FromDeriveInput;
+use proc_macro::{self, TokenStream};
+use quote::quote;
+use syn::{parse, parse_macro_input, DeriveInput, ItemFn};
+
+#[derive(FromDeriveInput, Default)]
+#[darling(default, attributes(freezable), forward_attrs(allow, doc, cfg))]
+struct Opts {
+    /// Storage prefix under which frozen accounts are stored. If it is `None` the default value
+    /// will be used.
+    storage_prefix: Option<String>,
+    /// Access control roles whose grantees may freeze and unfreeze accounts.
+    manager_roles: PathList,
+    /// Replaces the `standard` of emitted events, which defaults to the name of the plugin.
+    event_standard: Option<String>,
+    /// Replaces the `version` of emitted events, which defaults to the version of the plugin's
+    /// events.
+    event_version: Option<String>,
+}
+
+const DEFAULT_STORAGE_PREFIX: &str = "__FREEZE__";
+
+/// Generates the token stream that implements `Freezable`.
+pub fn derive_freezable(input: TokenStream) -> TokenStream {
+    let cratename = cratename();
+
+    let input = parse_macro_input!(input);
+    let opts = Opts::from_derive_input(&input).expect("Wrong options");
+    let emit_event = utils::emit_event(&cratename, &opts.event_standard, &opts.event_version);
+    let DeriveInput { ident, .. } = input;
+
+    let storage_prefix = opts
+        .storage_prefix
+        .unwrap_or_else(|| DEFAULT_STORAGE_PREFIX.to_string());
+    let manager_roles = opts.manager_roles;
+    assert!(
+        !manager_roles.is_empty(),
+        "Specify at least one role for manager_roles"
+    );
+
+    let output = quote! {
+        impl #ident {
+            fn fr_storage_key(&self, account_id: &::near_sdk::AccountId) -> Vec<u8> {
+                [self.fr_storage_prefix(), account_id.as_bytes()].concat()
+            }
+        }
+
+        #[near]
+        impl #cratename::Freezable for #ident {
+            fn fr_storage_prefix(&self) -> &'static [u8] {
+                (#storage_prefix).as_bytes()
+            }
+
+            fn fr_is_frozen(&self, account_id: ::near_sdk::AccountId) -> bool {
+                ::near_sdk::env::storage_has_key(self.fr_storage_key(&account_id).as_ref())
+            }
+
+            #[#cratename::access_control_any(roles(#(#manager_roles),*))]
+            fn fr_freeze_account(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                let newly_frozen = !::near_sdk::env::storage_write(self.fr_storage_key(&account_id).as_ref(), &[]);
+
+                if !newly_frozen {
+                    // Nothing to do since state was not modified.
+                    return false;
+                }
+
+                let event = #cratename::freezable::AccountFrozen {
+                    by: Self::acl_effective_caller(),
+                    account: account_id,
+                };
+                #emit_event;
+
+                true
+            }
+
+            #[#cratename::access_control_any(roles(#(#manager_roles),*))]
+            fn fr_unfreeze_account(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                let was_frozen = ::near_sdk::env::storage_remove(self.fr_storage_key(&account_id).as_ref());
+
+                if !was_frozen {
+                    // Nothing to do since state was not modified.
+                    return false;
+                }
+
+                let event = #cratename::freezable::AccountUnfrozen {
+                    by: Self::acl_effective_caller(),
+                    account: account_id,
+                };
+                #emit_event;
+
+                true
+            }
+        }
+    };
+
+    output.into()
+}
+
+/// Generates the token stream for the `frozen_guard` macro.
+pub fn frozen_guard(_attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse::<ItemFn>(item.clone()).unwrap();
+
+    if is_near_bindgen_wrapped_or_marshall(&input) {
+        return item;
+    }
+
+    let check_frozen = quote!(
+        ::near_sdk::require!(
+            !self.fr_is_frozen(Self::acl_effective_caller()),
+            "FRZ001: Freezable: Account is frozen"
+        );
+    );
+
+    utils::add_extra_code_to_fn(&input, check_frozen)
+}
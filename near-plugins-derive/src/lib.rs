@@ -2,8 +2,12 @@ use proc_macro::{self, TokenStream};
 
 mod access_control_role;
 mod access_controllable;
+mod capped;
+mod contract_metadata;
+mod freezable;
 mod ownable;
 mod pausable;
+mod time_window;
 mod upgradable;
 mod utils;
 
@@ -43,8 +47,14 @@ pub fn if_paused(attrs: TokenStream, item: TokenStream) -> TokenStream {
     pausable::if_paused(attrs, item)
 }
 
+/// Defines the attribute macro `unpaused`.
+#[proc_macro_attribute]
+pub fn unpaused(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    pausable::unpaused(attrs, item)
+}
+
 /// Defines the derive macro for `AccessControlRole`.
-#[proc_macro_derive(AccessControlRole)]
+#[proc_macro_derive(AccessControlRole, attributes(acl_bit))]
 pub fn derive_access_control_role(input: TokenStream) -> TokenStream {
     access_control_role::derive_access_control_role(input)
 }
@@ -60,3 +70,48 @@ pub fn access_control(attrs: TokenStream, item: TokenStream) -> TokenStream {
 pub fn access_control_any(attrs: TokenStream, item: TokenStream) -> TokenStream {
     access_controllable::access_control_any(attrs, item)
 }
+
+/// Defines the attribute macro `access_control_all`.
+#[proc_macro_attribute]
+pub fn access_control_all(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    access_controllable::access_control_all(attrs, item)
+}
+
+/// Defines the derive macro for `Capped`.
+#[proc_macro_derive(Capped, attributes(capped))]
+pub fn derive_capped(input: TokenStream) -> TokenStream {
+    capped::derive_capped(input)
+}
+
+/// Defines the derive macro for `ContractMetadata`.
+#[proc_macro_derive(ContractMetadata, attributes(contract_metadata))]
+pub fn derive_contract_metadata(input: TokenStream) -> TokenStream {
+    contract_metadata::derive_contract_metadata(input)
+}
+
+/// Defines the derive macro for `Freezable`.
+#[proc_macro_derive(Freezable, attributes(freezable))]
+pub fn derive_freezable(input: TokenStream) -> TokenStream {
+    freezable::derive_freezable(input)
+}
+
+/// Defines the attribute macro `frozen_guard`.
+#[proc_macro_attribute]
+pub fn frozen_guard(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    freezable::frozen_guard(attrs, item)
+}
+
+/// Defines the attribute macro `only_during`, which restricts calls of a method to a time window
+/// `[start, end)` based on the block timestamp:
+///
+/// ```ignore
+/// #[only_during(start = "Self::voting_start", end = "Self::voting_end")]
+/// pub fn vote(&mut self) { /* ... */ }
+/// ```
+///
+/// `start` and `end` are paths to functions with signature `fn(&Self) -> Timestamp`, which allows
+/// windows to be stored in contract state. Either of them may be omitted for open-ended windows.
+#[proc_macro_attribute]
+pub fn only_during(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    time_window::only_during(attrs, item)
+}
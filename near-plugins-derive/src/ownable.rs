@@ -3,12 +3,30 @@ use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
 use darling::FromDeriveInput;
 use proc_macro::{self, TokenStream};
 use quote::quote;
-use syn::{parse, parse_macro_input, DeriveInput, ItemFn};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse, parse_macro_input, DeriveInput, ItemFn, Token};
 
 #[derive(FromDeriveInput, Default)]
 #[darling(default, attributes(ownable), forward_attrs(allow, doc, cfg))]
 struct Opts {
     owner_storage_key: Option<String>,
+    /// Whether the owner may be removed by calling `owner_set(None)`. Defaults to `true`.
+    allow_renounce: Option<bool>,
+    /// Path to a function `fn(&Self, &Option<AccountId>) -> bool` which is consulted before the
+    /// owner is changed. The change is rejected if it returns `false`.
+    validate_owner: Option<String>,
+    /// Whether `owner_set` panics if the contract itself calls it in a callback, i.e. in a
+    /// function call receiving promise results.
+    no_self_reentrant_set: bool,
+    /// Whether the contract may have multiple co-owners, any of which may act as owner. The owner
+    /// slot then holds the borsh-serialized `Vec<AccountId>` of co-owners.
+    multi: bool,
+    /// Replaces the `standard` of emitted events, which defaults to the name of the plugin.
+    event_standard: Option<String>,
+    /// Replaces the `version` of emitted events, which defaults to the version of the plugin's
+    /// events.
+    event_version: Option<String>,
 }
 
 /// Generates the token stream that implements `Ownable`.
@@ -17,65 +35,404 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
 
     let input = parse_macro_input!(input);
     let opts = Opts::from_derive_input(&input).expect("Wrong options");
+    let emit_event = utils::emit_event(&cratename, &opts.event_standard, &opts.event_version);
     let DeriveInput { ident, .. } = input;
 
     let owner_storage_key = opts
         .owner_storage_key
         .unwrap_or_else(|| "__OWNER__".to_string());
 
+    // Keys of the pending ownership proposal and the nonce of the latest proposal.
+    let pending_owner_storage_key = format!("{}_PENDING", owner_storage_key);
+    let owner_nonce_storage_key = format!("{}_NONCE", owner_storage_key);
+
+    let allow_renounce = opts.allow_renounce.unwrap_or(true);
+    let renounce_check = if allow_renounce {
+        quote! {}
+    } else {
+        quote! {
+            ::near_sdk::require!(owner.is_some(), "Ownable: Renouncing ownership is not allowed");
+        }
+    };
+
+    // If a validator is specified, owner changes which it rejects panic.
+    let (validate_owner, validate_proposed_owner, validate_added_owner) = match opts.validate_owner
+    {
+        Some(validate_owner) => {
+            let validate_owner: syn::Path = match syn::parse_str(&validate_owner) {
+                Ok(path) => path,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            (
+                quote! {
+                    ::near_sdk::require!(
+                        #validate_owner(self, &owner),
+                        "Ownable: Owner rejected by validator"
+                    );
+                },
+                quote! {
+                    ::near_sdk::require!(
+                        #validate_owner(self, &Some(new_owner.clone())),
+                        "Ownable: Owner rejected by validator"
+                    );
+                },
+                quote! {
+                    ::near_sdk::require!(
+                        #validate_owner(self, &Some(account_id.clone())),
+                        "Ownable: Owner rejected by validator"
+                    );
+                },
+            )
+        }
+        None => (quote! {}, quote! {}, quote! {}),
+    };
+
+    let self_reentrancy_check = if opts.no_self_reentrant_set {
+        quote! {
+            ::near_sdk::require!(
+                ::near_sdk::env::predecessor_account_id() != ::near_sdk::env::current_account_id()
+                    || ::near_sdk::env::promise_results_count() == 0,
+                "Ownable: Owner can't be set by the contract itself in a callback"
+            );
+        }
+    } else {
+        quote! {}
+    };
+
+    // If owner is not set, only self can update the owner. Used mostly on constructor.
+    let assert_self_sets_owner = quote! {
+        assert_eq!(
+            ::near_sdk::env::predecessor_account_id(),
+            ::near_sdk::env::current_account_id(),
+            "Ownable: Owner not set. Only self can set the owner"
+        );
+    };
+    let multi = opts.multi;
+    let (read_owners, owner_is_predecessor, owner_get, write_owner, owner_set_check) = if multi {
+        (
+            quote! {
+                ::near_sdk::env::storage_read((#owner_storage_key).as_bytes()).map_or_else(Vec::new, |owners_bytes| {
+                    ::near_sdk::borsh::BorshDeserialize::try_from_slice(&owners_bytes)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid format of co-owners"))
+                })
+            },
+            quote! {
+                Self::owner_read_all().contains(&::near_sdk::env::predecessor_account_id())
+            },
+            quote! {
+                Self::owner_read_all().into_iter().next()
+            },
+            quote! {
+                self.owner_write_all_unchecked(&[owner.clone()])
+            },
+            quote! {
+                if current_owner.is_some() {
+                    ::near_sdk::require!(
+                        Self::owner_is_predecessor(),
+                        "Ownable: Only owner can update current owner"
+                    );
+                } else {
+                    #assert_self_sets_owner
+                }
+            },
+        )
+    } else {
+        (
+            quote! {
+                ::near_sdk::env::storage_read((#owner_storage_key).as_bytes())
+                    .map_or_else(Vec::new, |owner_bytes| vec![Self::owner_parse(owner_bytes)])
+            },
+            quote! {
+                ::near_sdk::env::storage_read((#owner_storage_key).as_bytes())
+                    .map_or(false, |owner_bytes| owner_bytes == ::near_sdk::env::predecessor_account_id().as_bytes())
+            },
+            quote! {
+                ::near_sdk::env::storage_read(&self.owner_storage_key()).map(Self::owner_parse)
+            },
+            quote! {
+                ::near_sdk::env::storage_write(
+                    &self.owner_storage_key(),
+                    owner.as_bytes(),
+                )
+            },
+            quote! {
+                if let Some(owner) = current_owner.as_ref() {
+                    assert_eq!(
+                        &::near_sdk::env::predecessor_account_id(),
+                        owner,
+                        "Ownable: Only owner can update current owner"
+                    );
+                } else {
+                    #assert_self_sets_owner
+                }
+            },
+        )
+    };
+
+    // Managing co-owners is available only with `multi`.
+    let (owner_helpers, owner_is_account) = if multi {
+        (
+            quote! {
+                fn owner_write_all_unchecked(&self, owners: &[::near_sdk::AccountId]) {
+                    if owners.is_empty() {
+                        ::near_sdk::env::storage_remove(&self.owner_storage_key());
+                    } else {
+                        ::near_sdk::env::storage_write(
+                            &self.owner_storage_key(),
+                            &::near_sdk::borsh::to_vec(owners).unwrap(),
+                        );
+                    }
+                }
+            },
+            quote! {
+                Self::owner_read_all().contains(&account_id)
+            },
+        )
+    } else {
+        (
+            quote! {
+                fn owner_parse(owner_bytes: Vec<u8>) -> ::near_sdk::AccountId {
+                    let owner_raw =
+                        String::from_utf8(owner_bytes).unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid string format"));
+                    std::convert::TryInto::try_into(owner_raw).unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid account id"))
+                }
+            },
+            quote! {
+                self.owner_get().map_or(false, |owner| owner == account_id)
+            },
+        )
+    };
+    let (owner_add, owner_remove) = if multi {
+        (
+            quote! {
+                let mut owners = Self::owner_read_all();
+                if owners.is_empty() {
+                    // Like `owner_set`, only self can add the first owner.
+                    ::near_sdk::assert_self();
+                } else {
+                    self.assert_owner();
+                }
+                if owners.contains(&account_id) {
+                    return false;
+                }
+                #validate_added_owner
+
+                owners.push(account_id.clone());
+                self.owner_write_all_unchecked(&owners);
+
+                let event = #cratename::ownable::OwnerAdded {
+                    account: account_id,
+                    by: ::near_sdk::env::predecessor_account_id(),
+                };
+                #emit_event;
+                true
+            },
+            quote! {
+                self.assert_owner();
+                let mut owners = Self::owner_read_all();
+                let index = match owners.iter().position(|owner| owner == &account_id) {
+                    Some(index) => index,
+                    None => return false,
+                };
+                owners.remove(index);
+                ::near_sdk::require!(
+                    #allow_renounce || !owners.is_empty(),
+                    "Ownable: Renouncing ownership is not allowed"
+                );
+                // The removed co-owner might have made the pending proposal.
+                self.owner_remove_pending_proposal();
+                self.owner_write_all_unchecked(&owners);
+
+                let event = #cratename::ownable::OwnerRemoved {
+                    account: account_id,
+                    by: ::near_sdk::env::predecessor_account_id(),
+                };
+                #emit_event;
+                true
+            },
+        )
+    } else {
+        (
+            quote! {
+                let _ = account_id;
+                ::near_sdk::env::panic_str("Ownable: Co-owners are disabled")
+            },
+            quote! {
+                let _ = account_id;
+                ::near_sdk::env::panic_str("Ownable: Co-owners are disabled")
+            },
+        )
+    };
+
     let output = quote! {
+        impl #ident {
+            /// Returns the pending ownership proposal, consisting of the proposed owner and the
+            /// proposal's nonce.
+            fn owner_pending_proposal(&self) -> Option<(::near_sdk::AccountId, u64)> {
+                ::near_sdk::env::storage_read((#pending_owner_storage_key).as_bytes()).map(|proposal_bytes| {
+                    ::near_sdk::borsh::BorshDeserialize::try_from_slice(&proposal_bytes)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid format of pending proposal"))
+                })
+            }
+
+            /// Returns whether the predecessor is the owner. In contrast to `Ownable::owner_is`,
+            /// it doesn't take a receiver, which allows `#[only(owner)]` to guard methods without
+            /// receiver.
+            fn owner_is_predecessor() -> bool {
+                #owner_is_predecessor
+            }
+
+            /// Returns all owners, which are at most one unless `multi` is set.
+            fn owner_read_all() -> Vec<::near_sdk::AccountId> {
+                #read_owners
+            }
+
+            fn owner_remove_pending_proposal(&self) {
+                ::near_sdk::env::storage_remove((#pending_owner_storage_key).as_bytes());
+            }
+
+            fn owner_write_unchecked(&self, owner: Option<&::near_sdk::AccountId>) {
+                // A change of ownership invalidates proposals made by the previous owner.
+                self.owner_remove_pending_proposal();
+                match owner {
+                    Some(owner) => {
+                        #write_owner;
+                    }
+                    None => {
+                        ::near_sdk::env::storage_remove(&self.owner_storage_key());
+                    }
+                }
+            }
+
+            #owner_helpers
+        }
+
         #[near]
         impl #cratename::Ownable for #ident {
             fn owner_storage_key(&self) -> &'static [u8] {
                 (#owner_storage_key).as_bytes()
             }
 
+            fn owner_storage_key_str(&self) -> String {
+                (#owner_storage_key).to_string()
+            }
+
             fn owner_get(&self) -> Option<::near_sdk::AccountId> {
-                ::near_sdk::env::storage_read(&self.owner_storage_key()).map(|owner_bytes| {
-                    let owner_raw =
-                        String::from_utf8(owner_bytes).unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid string format"));
-                    std::convert::TryInto::try_into(owner_raw).unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid account id"))
-                })
+                #owner_get
+            }
+
+            fn owner_get_all(&self) -> Vec<::near_sdk::AccountId> {
+                Self::owner_read_all()
+            }
+
+            fn owner_add(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                #owner_add
+            }
+
+            fn owner_remove(&mut self, account_id: ::near_sdk::AccountId) -> bool {
+                #owner_remove
             }
 
             fn owner_set(&mut self, owner: Option<::near_sdk::AccountId>) {
                 let current_owner = self.owner_get();
 
-                if let Some(owner) = current_owner.as_ref() {
-                    assert_eq!(
-                        &::near_sdk::env::predecessor_account_id(),
-                        owner,
-                        "Ownable: Only owner can update current owner"
-                    );
-                } else {
-                    // If owner is not set, only self can update the owner.
-                    // Used mostly on constructor.
-                    assert_eq!(
-                        ::near_sdk::env::predecessor_account_id(),
-                        ::near_sdk::env::current_account_id(),
-                        "Ownable: Owner not set. Only self can set the owner"
-                    );
-                }
+                #owner_set_check
+
+                #self_reentrancy_check
+                #renounce_check
+                #validate_owner
 
                 let event = #cratename::ownable::OwnershipTransferred {
                     previous_owner: current_owner,
                     new_owner: owner.clone(),
                 };
-                #cratename::events::AsEvent::emit(&event);
-
-                match owner.as_ref() {
-                    Some(owner) => ::near_sdk::env::storage_write(
-                        &self.owner_storage_key(),
-                        owner.as_bytes(),
-                    ),
-                    None => ::near_sdk::env::storage_remove(&self.owner_storage_key()),
+                #emit_event;
+
+                self.owner_write_unchecked(owner.as_ref());
+            }
+
+            fn owner_renounce(&mut self) {
+                ::near_sdk::require!(#allow_renounce, "Ownable: Renouncing ownership is not allowed");
+
+                let previous_owner = self.owner_get()
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str("Ownable: No owner to renounce"));
+                let by = ::near_sdk::env::predecessor_account_id();
+                ::near_sdk::require!(
+                    self.owner_is_account(by.clone()) || by == ::near_sdk::env::current_account_id(),
+                    "Ownable: Only owner or self can renounce ownership"
+                );
+
+                self.owner_write_unchecked(None);
+
+                let event = #cratename::ownable::OwnershipRenounced {
+                    previous_owner,
+                    by,
+                };
+                #emit_event;
+            }
+
+            fn owner_propose(&mut self, new_owner: ::near_sdk::AccountId) -> u64 {
+                self.assert_owner();
+                #validate_proposed_owner
+
+                let nonce = ::near_sdk::env::storage_read((#owner_nonce_storage_key).as_bytes())
+                    .map_or(0, |nonce_bytes| {
+                        <u64 as ::near_sdk::borsh::BorshDeserialize>::try_from_slice(&nonce_bytes)
+                            .unwrap_or_else(|_| ::near_sdk::env::panic_str("Ownable: Invalid nonce format"))
+                    })
+                    + 1;
+                ::near_sdk::env::storage_write(
+                    (#owner_nonce_storage_key).as_bytes(),
+                    &::near_sdk::borsh::to_vec(&nonce).unwrap(),
+                );
+                ::near_sdk::env::storage_write(
+                    (#pending_owner_storage_key).as_bytes(),
+                    &::near_sdk::borsh::to_vec(&(new_owner.clone(), nonce)).unwrap(),
+                );
+
+                let event = #cratename::ownable::OwnershipProposed {
+                    owner: ::near_sdk::env::predecessor_account_id(),
+                    proposed_owner: new_owner,
+                    nonce,
                 };
+                #emit_event;
+
+                nonce
+            }
+
+            fn owner_accept(&mut self, nonce: u64) {
+                let (proposed_owner, expected_nonce) = self.owner_pending_proposal()
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str("Ownable: No pending owner"));
+                ::near_sdk::require!(
+                    ::near_sdk::env::predecessor_account_id() == proposed_owner,
+                    "Ownable: Only the proposed owner can accept ownership"
+                );
+                ::near_sdk::require!(nonce == expected_nonce, "Ownable: Invalid nonce");
+
+                let event = #cratename::ownable::OwnershipTransferred {
+                    previous_owner: self.owner_get(),
+                    new_owner: Some(proposed_owner.clone()),
+                };
+                #emit_event;
+
+                self.owner_write_unchecked(Some(&proposed_owner));
+            }
+
+            fn owner_cancel_proposal(&mut self) {
+                self.assert_owner();
+                self.owner_remove_pending_proposal();
+            }
+
+            fn owner_get_pending(&self) -> Option<::near_sdk::AccountId> {
+                self.owner_pending_proposal().map(|(proposed_owner, _)| proposed_owner)
             }
 
             fn owner_is(&self) -> bool {
-                self.owner_get().map_or(false, |owner| {
-                    owner == ::near_sdk::env::predecessor_account_id()
-                })
+                self.owner_is_account(::near_sdk::env::predecessor_account_id())
+            }
+
+            fn owner_is_account(&self, account_id: ::near_sdk::AccountId) -> bool {
+                #owner_is_account
             }
         }
     };
@@ -83,43 +440,167 @@ pub fn derive_ownable(input: TokenStream) -> TokenStream {
     output.into()
 }
 
+const ERR_ROLES_WITHOUT_RECEIVER: &str = "`#[only(any_of_roles(...))]` requires the method to \
+    take `self`, `&self` or `&mut self`, since roles are checked via `AccessControllable` methods.";
+
+/// A condition passed to the `only` macro. The method may be called if any of them holds.
+enum OnlyTarget {
+    /// The predecessor is the contract itself.
+    SelfAccount,
+    /// The predecessor is the owner.
+    Owner,
+    /// The predecessor has been granted any of the roles.
+    AnyOfRoles(Vec<syn::Path>),
+}
+
+impl Parse for OnlyTarget {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![self]) {
+            input.parse::<Token![self]>()?;
+            return Ok(OnlyTarget::SelfAccount);
+        }
+        let ident: syn::Ident = input.parse()?;
+        match ident.to_string().as_str() {
+            "owner" => Ok(OnlyTarget::Owner),
+            "any_of_roles" => {
+                let content;
+                syn::parenthesized!(content in input);
+                let roles = Punctuated::<syn::Path, Token![,]>::parse_terminated(&content)?;
+                Ok(OnlyTarget::AnyOfRoles(roles.into_iter().collect()))
+            }
+            _ => Err(syn::Error::new(
+                ident.span(),
+                "Expected one of `self`, `owner` or `any_of_roles(...)`",
+            )),
+        }
+    }
+}
+
 /// Generates the token stream for the `only` macro.
 pub fn only(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse::<ItemFn>(item.clone()).unwrap();
     if is_near_bindgen_wrapped_or_marshall(&input) {
         return item;
     }
+    let targets =
+        parse_macro_input!(attrs with Punctuated::<OnlyTarget, Token![,]>::parse_terminated);
+
     let mut contains_self = false;
     let mut contains_owner = false;
-    // TODO: Use darling
-    for attr in attrs {
-        match attr.to_string().as_str() {
-            "self" => contains_self = true,
-            "owner" => contains_owner = true,
-            _ => {}
+    let mut roles = vec![];
+    for target in targets {
+        match target {
+            OnlyTarget::SelfAccount => contains_self = true,
+            OnlyTarget::Owner => contains_owner = true,
+            OnlyTarget::AnyOfRoles(any_of_roles) => roles.extend(any_of_roles),
+        }
+    }
+
+    // Methods without receiver, i.e. without `self`, `&self` or `&mut self`, can't call trait
+    // methods of the contract. Hence the owner is checked via an associated function.
+    let has_receiver = input.sig.receiver().is_some();
+
+    if !roles.is_empty() {
+        if !has_receiver {
+            return syn::Error::new_spanned(&input.sig, ERR_ROLES_WITHOUT_RECEIVER)
+                .to_compile_error()
+                .into();
         }
+        return utils::add_extra_code_to_fn(
+            &input,
+            only_with_roles(contains_self, contains_owner, &roles),
+        );
     }
 
     let owner_check = match (contains_self, contains_owner) {
-        (true, true) => quote! {
-            if !self.owner_is() {
+        (true, true) if !has_receiver => quote! {
+            if !Self::owner_is_predecessor() {
                 ::near_sdk::assert_self();
             }
         },
+        (false, true) if !has_receiver => quote! {
+            ::near_sdk::require!(
+                Self::owner_is_predecessor(),
+                "Ownable: Method must be called from owner"
+            );
+        },
+        (true, true) => quote! {
+            self.assert_owner_or_self();
+        },
         (true, false) => quote! {
             ::near_sdk::assert_self();
         },
         (false, true) => quote! {
-            ::near_sdk::require!(self.owner_is(), "Ownable: Method must be called from owner");
+            self.assert_owner();
         },
         (false, false) => {
             // The developer did not specify a target for `only`, so we panic during macro
             // expansion instead of returning a `TokenStream` that's added to the `input` function.
             // That's why this block is _not_ wrapped in `quote!` and we use `std::panic!` as opposed
             // to `near_sdk::env::panic_str`.
-            panic!("Ownable::only macro target not specified. Select at least one in [self, owner]")
+            panic!("Ownable::only macro target not specified. Select at least one in [self, owner, any_of_roles(...)]")
         }
     };
 
     utils::add_extra_code_to_fn(&input, owner_check)
 }
+
+/// Generates a single guard which passes if the caller satisfies any of the conditions. The caller
+/// is resolved by `AccessControllable`, so all conditions are checked for the same account even if
+/// a `caller_resolver` is configured.
+///
+/// Roles are checked via fully qualified calls, so a contract which isn't `AccessControllable` (or
+/// `Ownable`, if `owner` is passed) fails compilation with an unsatisfied trait bound.
+fn only_with_roles(
+    contains_self: bool,
+    contains_owner: bool,
+    roles: &[syn::Path],
+) -> proc_macro2::TokenStream {
+    let cratename = cratename();
+
+    // Construct the error message at compile time to avoid spending gas on `format!`.
+    let mut callers = vec![];
+    if contains_self {
+        callers.push("self".to_string());
+    }
+    if contains_owner {
+        callers.push("owner".to_string());
+    }
+    let role_names = roles
+        .iter()
+        .map(|role| {
+            role.segments
+                .last()
+                .map_or_else(String::new, |segment| segment.ident.to_string())
+        })
+        .collect::<Vec<_>>();
+    callers.push(format!("a grantee of any of the roles {:?}", role_names));
+    // The message is prefixed with the code of `near_plugins::PluginError::Unauthorized`.
+    let err_unauthorized = format!(
+        "OWN001: Only: Method must be called by {}",
+        callers.join(" or ")
+    );
+
+    let self_check = if contains_self {
+        quote! { __caller == ::near_sdk::env::current_account_id() }
+    } else {
+        quote! { false }
+    };
+    let owner_check = if contains_owner {
+        quote! { #cratename::Ownable::owner_is_account(self, __caller.clone()) }
+    } else {
+        quote! { false }
+    };
+
+    quote! {
+        let __caller = Self::acl_effective_caller();
+        let __roles: Vec<&str> = vec![#(#roles.into()),*];
+        let __roles: Vec<String> = __roles.iter().map(|&role| role.into()).collect();
+        ::near_sdk::require!(
+            #self_check
+                || #owner_check
+                || #cratename::AccessControllable::acl_has_any_role(self, __roles, __caller),
+            #err_unauthorized
+        );
+    }
+}
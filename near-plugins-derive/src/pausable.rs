@@ -3,7 +3,7 @@ use crate::utils::{cratename, is_near_bindgen_wrapped_or_marshall};
 use darling::util::PathList;
 use darling::{FromDeriveInput, FromMeta};
 use proc_macro::{self, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{parse, parse_macro_input, AttributeArgs, DeriveInput, ItemFn};
 
 #[derive(FromDeriveInput, Default)]
@@ -12,8 +12,156 @@ struct Opts {
     /// Storage key under which the set of paused features is stored. If it is
     /// `None` the default value will be used.
     paused_storage_key: Option<String>,
+    /// Storage prefix under which this plugin stores its state. It is an alternative to
+    /// `paused_storage_key` that is consistent with the attributes of other plugins. At most one
+    /// of them may be specified.
+    storage_prefix: Option<String>,
     /// Access control roles whose grantees may pause and unpause features.
     manager_roles: PathList,
+    /// Names of the features the contract declares as pausable. If set, it must contain the keys
+    /// and groups of all methods annotated with `#[pause]`, which is checked at compile time.
+    features: Option<FeatureNames>,
+    /// Names of features which are paused until they are unpaused for the first time.
+    default_paused: FeatureNames,
+    /// Whether all features are paused until they are unpaused for the first time.
+    default_paused_all: bool,
+    /// Name of an enum to generate with one variant per key in `features` (plus "ALL"), along
+    /// with typed methods to pause and unpause features.
+    feature_enum: Option<String>,
+    /// Names of `pub` methods annotated with `#[pause]` whose `except(roles(...))` are returned by
+    /// `pa_feature_exempt_roles`, e.g. `exempt_roles(increase_4, decrease_4)`. If set, it must
+    /// list every method passing `except(roles(...))`, which is checked at compile time.
+    exempt_roles: Option<PathList>,
+    /// Whether the most recent change of the pause state of each feature is stored, to be
+    /// returned by `pa_feature_status`.
+    track_history: bool,
+    /// Replaces the `standard` of emitted events, which defaults to the name of the plugin.
+    event_standard: Option<String>,
+    /// Replaces the `version` of emitted events, which defaults to the version of the plugin's
+    /// events.
+    event_version: Option<String>,
+}
+
+/// List of string literals passed to the `features` attribute, e.g.
+/// `features("increase_1", "Increase by two")`.
+#[derive(Default)]
+struct FeatureNames(Vec<String>);
+
+impl FromMeta for FeatureNames {
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        let mut names: Vec<String> = vec![];
+        for item in items {
+            match item {
+                syn::NestedMeta::Lit(syn::Lit::Str(name)) => {
+                    // Duplicates are dropped, keeping the order of first occurrence.
+                    if !names.contains(&name.value()) {
+                        names.push(name.value());
+                    }
+                }
+                _ => return Err(darling::Error::unexpected_type("non-string").with_span(item)),
+            }
+        }
+        Ok(Self(names))
+    }
+}
+
+const DEFAULT_STORAGE_KEY: &str = "__PAUSE__";
+
+/// Converts a feature key to the name of the corresponding enum variant, e.g. "increase_1" to
+/// `Increase1` and "Increase by two" to `IncreaseByTwo`.
+fn feature_variant_name(key: &str) -> String {
+    key.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            std::iter::once(first)
+                .chain(chars.map(|c| c.to_ascii_lowercase()))
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Generates an enum named `enum_name` whose variants map to `keys`, as well as typed wrappers
+/// around the string-based methods of `Pausable`.
+fn feature_enum_code(
+    cratename: &syn::Ident,
+    ident: &syn::Ident,
+    enum_name: &str,
+    keys: &[String],
+) -> proc_macro2::TokenStream {
+    let enum_ident = syn::Ident::new(enum_name, proc_macro2::Span::call_site());
+
+    let mut keys = keys.to_vec();
+    if !keys.iter().any(|key| key == "ALL") {
+        keys.push("ALL".to_string());
+    }
+    let mut variant_names: Vec<String> = vec![];
+    for key in keys.iter() {
+        let name = feature_variant_name(key);
+        assert!(
+            name.starts_with(|c: char| c.is_ascii_alphabetic()),
+            "Pausable: Feature {:?} can't be converted to an enum variant",
+            key
+        );
+        assert!(
+            !variant_names.contains(&name),
+            "Pausable: Features map to the same enum variant {}",
+            name
+        );
+        variant_names.push(name);
+    }
+    let variants: Vec<syn::Ident> = variant_names
+        .iter()
+        .map(|name| syn::Ident::new(name, proc_macro2::Span::call_site()))
+        .collect();
+    let variant_docs: Vec<String> = keys
+        .iter()
+        .map(|key| format!("Feature with key `{}`.", key))
+        .collect();
+
+    quote! {
+        /// Features of the `Pausable` contract. Each variant maps to the key of a feature.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum #enum_ident {
+            #(
+                #[doc = #variant_docs]
+                #variants,
+            )*
+        }
+
+        impl #enum_ident {
+            /// Returns the key under which the feature is paused.
+            pub fn key(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #keys,)*
+                }
+            }
+        }
+
+        impl From<#enum_ident> for String {
+            fn from(value: #enum_ident) -> Self {
+                value.key().to_string()
+            }
+        }
+
+        impl #ident {
+            /// Typed counterpart of `Pausable::pa_pause_feature`.
+            pub fn pa_pause(&mut self, feature: #enum_ident, reason: Option<String>) -> bool {
+                #cratename::Pausable::pa_pause_feature(self, feature.into(), reason)
+            }
+
+            /// Typed counterpart of `Pausable::pa_unpause_feature`.
+            pub fn pa_unpause(&mut self, feature: #enum_ident) -> bool {
+                #cratename::Pausable::pa_unpause_feature(self, feature.into())
+            }
+
+            /// Typed counterpart of `Pausable::pa_is_paused`.
+            pub fn pa_is_feature_paused(&self, feature: #enum_ident) -> bool {
+                #cratename::Pausable::pa_is_paused(self, feature.into())
+            }
+        }
+    }
 }
 
 /// Generates the token stream that implements `Pausable`.
@@ -22,18 +170,148 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
 
     let input = parse_macro_input!(input);
     let opts = Opts::from_derive_input(&input).expect("Wrong options");
+    let emit_event = utils::emit_event(&cratename, &opts.event_standard, &opts.event_version);
     let DeriveInput { ident, .. } = input;
 
+    assert!(
+        opts.paused_storage_key.is_none() || opts.storage_prefix.is_none(),
+        "Specify at most one of paused_storage_key and storage_prefix"
+    );
     let paused_storage_key = opts
         .paused_storage_key
-        .unwrap_or_else(|| "__PAUSE__".to_string());
+        .or(opts.storage_prefix)
+        .unwrap_or_else(|| DEFAULT_STORAGE_KEY.to_string());
     let manager_roles = opts.manager_roles;
+    // Keys of methods which are not declared fail compilation via `CheckPauseAnnotation`.
+    let (registered_features, all_feature_names) = match &opts.features {
+        Some(FeatureNames(names)) => (
+            quote! { Some(&[#(#names),*]) },
+            quote! { Some(vec![#(#names.to_string()),*]) },
+        ),
+        None => (quote! { None }, quote! { None }),
+    };
+    let feature_names = opts.features.map(|features| features.0).unwrap_or_default();
+    let feature_enum = match opts.feature_enum {
+        Some(enum_name) => feature_enum_code(&cratename, &ident, &enum_name, &feature_names),
+        None => quote! {},
+    };
+    // The keys and roles are provided by the functions which `#[pause]` generates next to the
+    // methods, hence they cannot diverge from the annotations. Methods which are not registered
+    // fail compilation via `CheckPauseAnnotation`.
+    let mut exempt_roles_methods = vec![];
+    let mut exempt_roles = vec![];
+    for method in opts.exempt_roles.iter().flat_map(|methods| methods.iter()) {
+        let method = match method.get_ident() {
+            Some(method) => method,
+            None => {
+                return syn::Error::new_spanned(method, "exempt_roles expects names of methods")
+                    .to_compile_error()
+                    .into()
+            }
+        };
+        let exempt_roles_fn = exempt_roles_ident(method);
+        exempt_roles.push(quote_spanned! {method.span()=> Self::#exempt_roles_fn() });
+        exempt_roles_methods.push(method.to_string());
+    }
+    let (registered_exempt_roles_methods, feature_exempt_roles) = match opts.exempt_roles {
+        Some(_) => (
+            quote! { Some(&[#(#exempt_roles_methods),*]) },
+            quote! {
+                let methods: Vec<(Vec<&str>, Vec<&str>)> = vec![#(#exempt_roles),*];
+                let mut roles: Vec<String> = vec![];
+                for (keys, method_roles) in methods {
+                    if !keys.contains(&key.as_str()) {
+                        continue;
+                    }
+                    for role in method_roles {
+                        // Methods of the same feature may exempt the same roles.
+                        if !roles.iter().any(|known| known == role) {
+                            roles.push(role.to_string());
+                        }
+                    }
+                }
+                Some(roles)
+            },
+        ),
+        None => (
+            quote! { None },
+            quote! {
+                let _ = key;
+                None
+            },
+        ),
+    };
+    let mut default_paused = opts.default_paused.0;
+    if opts.default_paused_all && !default_paused.iter().any(|key| key == "ALL") {
+        default_paused.push("ALL".to_string());
+    }
+    // Changes are recorded only with `track_history`. Otherwise `pa_feature_status` panics.
+    let (status_helpers, record_pause, record_unpause, feature_status) = if opts.track_history {
+        (
+            quote! {
+                impl #ident {
+                    /// Returns the key under which the most recent change of feature `key` is
+                    /// stored.
+                    fn pa_status_storage_key(&self, key: &str) -> Vec<u8> {
+                        [#cratename::Pausable::pa_storage_key(self), b"_STATUS_", key.as_bytes()].concat()
+                    }
+
+                    /// Stores that feature `key` was paused or unpaused by the predecessor in the
+                    /// current block.
+                    fn pa_record_status(&self, key: &str, paused: bool) {
+                        let status = (
+                            paused,
+                            ::near_sdk::env::block_timestamp(),
+                            Self::acl_effective_caller(),
+                        );
+                        ::near_sdk::env::storage_write(
+                            &self.pa_status_storage_key(key),
+                            &::near_sdk::borsh::to_vec(&status)
+                                .unwrap_or_else(|_| ::near_sdk::env::panic_str("Pausable: Unexpected error serializing feature status")),
+                        );
+                    }
+                }
+            },
+            quote! { self.pa_record_status(&key, true); },
+            quote! { self.pa_record_status(&key, false); },
+            quote! {
+                ::near_sdk::env::storage_read(&self.pa_status_storage_key(&key)).map(|value| {
+                    let (paused, last_changed, by): (bool, ::near_sdk::Timestamp, ::near_sdk::AccountId) =
+                        ::near_sdk::borsh::BorshDeserialize::try_from_slice(&value)
+                            .unwrap_or_else(|_| ::near_sdk::env::panic_str("Pausable: Invalid format of feature status"));
+                    #cratename::pausable::FeatureStatus { paused, last_changed, by }
+                })
+            },
+        )
+    } else {
+        (
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {
+                let _ = key;
+                ::near_sdk::env::panic_str("Pausable: History tracking is disabled")
+            },
+        )
+    };
+
+    // With default paused features, the absence of state means features are in their default
+    // state. Hence an empty set of paused keys must be stored instead of removing the storage slot.
+    let has_default_paused = !default_paused.is_empty();
     assert!(
         manager_roles.len() > 0,
         "Specify at least one role for manager_roles"
     );
 
     let output = quote! {
+        #status_helpers
+
+        impl #cratename::pausable::PausableAttributes for #ident {
+            const FEATURES: Option<&'static [&'static str]> = #registered_features;
+            const EXEMPT_ROLES_METHODS: Option<&'static [&'static str]> =
+                #registered_exempt_roles_methods;
+        }
+
         #[near]
         impl #cratename::Pausable for #ident {
             fn pa_storage_key(&self) -> &'static [u8] {
@@ -46,15 +324,52 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
                     .unwrap_or(false)
             }
 
+            fn pa_is_paused_any(&self, keys: Vec<String>) -> bool {
+                if keys.is_empty() {
+                    return false;
+                }
+                self.pa_all_paused()
+                    .map(|paused| paused.contains("ALL") || keys.iter().any(|key| paused.contains(key)))
+                    .unwrap_or(false)
+            }
+
+            fn pa_is_paused_all(&self, keys: Vec<String>) -> bool {
+                if keys.is_empty() {
+                    return true;
+                }
+                self.pa_all_paused()
+                    .map(|paused| paused.contains("ALL") || keys.iter().all(|key| paused.contains(key)))
+                    .unwrap_or(false)
+            }
+
+            fn pa_all_feature_names(&self) -> Option<Vec<String>> {
+                #all_feature_names
+            }
+
+            fn pa_feature_exempt_roles(&self, key: String) -> Option<Vec<String>> {
+                #feature_exempt_roles
+            }
+
+            fn pa_feature_status(&self, key: String) -> Option<#cratename::pausable::FeatureStatus> {
+                #feature_status
+            }
+
             fn pa_all_paused(&self) -> Option<std::collections::HashSet<String>> {
-                ::near_sdk::env::storage_read(self.pa_storage_key().as_ref()).map(|value| {
-                    std::collections::HashSet::try_from_slice(value.as_ref())
-                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("Pausable: Invalid format for paused keys"))
-                })
+                match ::near_sdk::env::storage_read(self.pa_storage_key().as_ref()) {
+                    Some(value) => Some(
+                        std::collections::HashSet::try_from_slice(value.as_ref())
+                            .unwrap_or_else(|_| ::near_sdk::env::panic_str("Pausable: Invalid format for paused keys"))
+                    ),
+                    // No state is stored yet, hence features paused by default are paused.
+                    None if #has_default_paused => Some(
+                        vec![#(#default_paused.to_string()),*].into_iter().collect()
+                    ),
+                    None => None,
+                }
             }
 
             #[#cratename::access_control_any(roles(#(#manager_roles),*))]
-            fn pa_pause_feature(&mut self, key: String) -> bool {
+            fn pa_pause_feature(&mut self, key: String, reason: Option<String>) -> bool {
                 let mut paused_keys = self.pa_all_paused().unwrap_or_default();
                 let newly_paused = paused_keys.insert(key.clone());
 
@@ -70,11 +385,14 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
                         .as_ref(),
                 );
 
-                let event = #cratename::pausable::Pause {
-                    by: ::near_sdk::env::predecessor_account_id(),
+                #record_pause
+
+                let event = #cratename::pausable::FeaturePaused {
+                    by: Self::acl_effective_caller(),
                     key,
+                    reason,
                 };
-                #cratename::events::AsEvent::emit(&event);
+                #emit_event;
 
                 // The feature is newly paused.
                 true
@@ -90,7 +408,7 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
                     return false;
                 }
 
-                if paused_keys.is_empty() {
+                if paused_keys.is_empty() && !#has_default_paused {
                     ::near_sdk::env::storage_remove(self.pa_storage_key().as_ref());
                 } else {
                     ::near_sdk::env::storage_write(
@@ -101,16 +419,77 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
                     );
                 }
 
-                let event = #cratename::pausable::Unpause {
-                    by: ::near_sdk::env::predecessor_account_id(),
+                #record_unpause
+
+                let event = #cratename::pausable::FeatureUnpaused {
+                    by: Self::acl_effective_caller(),
                     key,
                 };
-                #cratename::events::AsEvent::emit(&event);
+                #emit_event;
 
                 // The feature was paused.
                 true
             }
+
+            #[#cratename::access_control_any(roles(#(#manager_roles),*))]
+            fn pa_set_features(&mut self, pause: Vec<String>, unpause: Vec<String>) -> bool {
+                ::near_sdk::require!(
+                    !pause.iter().any(|key| unpause.contains(key)),
+                    "Pausable: A feature must not be both paused and unpaused"
+                );
+
+                let mut paused_keys = self.pa_all_paused().unwrap_or_default();
+                let newly_paused: Vec<String> = pause
+                    .into_iter()
+                    .filter(|key| paused_keys.insert(key.clone()))
+                    .collect();
+                let was_paused: Vec<String> = unpause
+                    .into_iter()
+                    .filter(|key| paused_keys.remove(key))
+                    .collect();
+
+                if newly_paused.is_empty() && was_paused.is_empty() {
+                    // Nothing to do since state is not modified.
+                    return false;
+                }
+
+                if paused_keys.is_empty() && !#has_default_paused {
+                    ::near_sdk::env::storage_remove(self.pa_storage_key().as_ref());
+                } else {
+                    ::near_sdk::env::storage_write(
+                        self.pa_storage_key().as_ref(),
+                        ::near_sdk::borsh::to_vec(&paused_keys)
+                            .unwrap_or_else(|_| ::near_sdk::env::panic_str("Pausable: Unexpected error serializing keys"))
+                            .as_ref(),
+                    );
+                }
+
+                for key in newly_paused {
+                    #record_pause
+
+                    let event = #cratename::pausable::FeaturePaused {
+                        by: Self::acl_effective_caller(),
+                        key,
+                        reason: None,
+                    };
+                    #emit_event;
+                }
+
+                for key in was_paused {
+                    #record_unpause
+
+                    let event = #cratename::pausable::FeatureUnpaused {
+                        by: Self::acl_effective_caller(),
+                        key,
+                    };
+                    #emit_event;
+                }
+
+                true
+            }
         }
+
+        #feature_enum
     };
 
     output.into()
@@ -120,8 +499,14 @@ pub fn derive_pausable(input: TokenStream) -> TokenStream {
 #[derive(Default, FromMeta, Debug)]
 #[darling(default)]
 pub struct ExceptSubArgs {
-    /// Grantees of these roles are exempted and may always call the method.
+    /// Grantees of these roles are exempted and may always call the method. Requires the contract
+    /// to be `AccessControllable`.
     roles: PathList,
+    /// If set, the owner is exempted. Requires the contract to be `Ownable`.
+    owner: bool,
+    /// If set, the contract itself is exempted.
+    #[darling(rename = "self")]
+    contract: bool,
 }
 
 /// Defines attributes for the `pause` macro.
@@ -131,6 +516,18 @@ pub struct PauseArgs {
     name: Option<String>,
     #[darling(default)]
     except: ExceptSubArgs,
+    /// Path to a function with signature `fn(&Self) -> bool`. If provided, the method is paused
+    /// only if the feature is paused _and_ the function returns `true`.
+    #[darling(default)]
+    when: Option<syn::Path>,
+    /// Message to panic with if the method is paused, prefixed with a code. Defaults to
+    /// "Pausable: Method is paused".
+    #[darling(default)]
+    message: Option<String>,
+    /// Key of a group of methods. If provided, the method is paused if either its own key or the
+    /// group is paused.
+    #[darling(default)]
+    group: Option<String>,
 }
 
 /// Generates the token stream for the `pause` macro.
@@ -145,18 +542,86 @@ pub fn pause(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let args = PauseArgs::from_list(&attr_args).expect("Invalid arguments");
 
     let fn_name = args.name.unwrap_or_else(|| input.sig.ident.to_string());
+    // The message is prefixed with the code of `near_plugins::PluginError::Paused`.
+    let err_paused = format!(
+        "PAU001: {}",
+        args.message
+            .unwrap_or_else(|| "Pausable: Method is paused".to_string())
+    );
 
     let bypass_condition = get_bypass_condition(&args.except);
 
+    // Binding the predicate to a function pointer makes compilation fail with a clear error if
+    // the referenced function is missing or has an unexpected signature.
+    let when_condition = match args.when {
+        Some(when) => quote!({
+            let __pause_when: fn(&Self) -> bool = #when;
+            __pause_when(self)
+        }),
+        None => quote!(true),
+    };
+
+    let cratename = cratename();
+    let is_paused = match &args.group {
+        Some(group) => quote! {
+            #cratename::Pausable::pa_is_paused(self, #fn_name.to_string())
+                || #cratename::Pausable::pa_is_paused(self, #group.to_string())
+        },
+        None => quote! { #cratename::Pausable::pa_is_paused(self, #fn_name.to_string()) },
+    };
+
+    // Provides the keys and exempt roles to `pa_feature_exempt_roles` if the method is listed in
+    // `exempt_roles`.
+    let exempt_roles = if utils::has_visibility(&input) {
+        let exempt_roles = exempt_roles_ident(&input.sig.ident);
+        let keys = std::iter::once(fn_name.clone()).chain(args.group.clone());
+        let roles = args.except.roles.iter();
+        quote! {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            fn #exempt_roles() -> (Vec<&'static str>, Vec<&'static str>) {
+                (vec![#(#keys),*], vec![#(#roles.into()),*])
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // Fails compilation if the method is not registered in the attributes of `#[pausable]`.
+    let method_name = input.sig.ident.to_string();
+    let keys = std::iter::once(&fn_name).chain(args.group.as_ref());
+    let has_exempt_roles = !args.except.roles.is_empty();
+    let registrable = utils::has_visibility(&input);
+    let check_annotation = quote_spanned! {input.sig.ident.span()=>
+        struct __PauseAnnotation;
+        impl #cratename::pausable::PauseAnnotation for __PauseAnnotation {
+            const METHOD: &'static str = #method_name;
+            const KEYS: &'static [&'static str] = &[#(#keys),*];
+            const HAS_EXEMPT_ROLES: bool = #has_exempt_roles;
+            const REGISTRABLE: bool = #registrable;
+        }
+        let () = #cratename::pausable::CheckPauseAnnotation::<Self, __PauseAnnotation>::CHECK;
+    };
+
+    let requires_pausable = requires_derive_pausable(&input);
     let check_pause = quote!(
-        let mut __check_paused = true;
-        #bypass_condition
-        if __check_paused {
-            ::near_sdk::require!(!self.pa_is_paused(#fn_name.to_string()), "Pausable: Method is paused");
+        #requires_pausable
+        #check_annotation
+        if !#bypass_condition && #when_condition {
+            ::near_sdk::require!(!(#is_paused), #err_paused);
         }
     );
 
-    utils::add_extra_code_to_fn(&input, check_pause)
+    let mut output =
+        proc_macro2::TokenStream::from(utils::add_extra_code_to_fn(&input, check_pause));
+    output.extend(exempt_roles);
+    output.into()
+}
+
+/// Returns the name of the function which `#[pause]` generates to return the keys and the roles
+/// passed to `except(roles(...))` of `method`.
+fn exempt_roles_ident(method: &syn::Ident) -> syn::Ident {
+    quote::format_ident!("__pa_exempt_roles_{}", method)
 }
 
 /// Defines attributes for the `if_paused` macro.
@@ -178,39 +643,98 @@ pub fn if_paused(attrs: TokenStream, item: TokenStream) -> TokenStream {
     let attr_args = parse_macro_input!(attrs as AttributeArgs);
     let args = IfPausedArgs::from_list(&attr_args).expect("Invalid arguments");
 
+    let cratename = cratename();
     let fn_name = args.name;
 
-    // Construct error messages that use `format!` here, i.e. at compile time. Doing that during
-    // contract execution would cost extra gas.
-    let err_feature_not_paused = format!("Pausable: {fn_name} must be paused to use this function");
-
     let bypass_condition = get_bypass_condition(&args.except);
 
+    let requires_pausable = requires_derive_pausable(&input);
     let check_pause = quote!(
-        let mut __check_paused = true;
-        #bypass_condition
-        if __check_paused {
-            ::near_sdk::require!(
-                self.pa_is_paused(#fn_name.to_string()),
-                #err_feature_not_paused,
-            );
+        #requires_pausable
+        if !#bypass_condition {
+            #cratename::pausable::PausableExt::pa_assert_paused(self, #fn_name);
         }
     );
 
     utils::add_extra_code_to_fn(&input, check_pause)
 }
 
-fn get_bypass_condition(args: &ExceptSubArgs) -> proc_macro2::TokenStream {
-    let except_roles = args.roles.clone();
-    quote!(
-        let __except_roles: Vec<&str> = vec![#(#except_roles.into()),*];
-        let __except_roles: Vec<String> = __except_roles.iter().map(|&x| x.into()).collect();
-        let may_bypass = self.acl_has_any_role(
-            __except_roles,
-            ::near_sdk::env::predecessor_account_id()
-        );
-        if may_bypass {
-            __check_paused = false;
+/// Defines attributes for the `unpaused` macro.
+#[derive(Debug, FromMeta)]
+pub struct UnpausedArgs {
+    name: String,
+    #[darling(default)]
+    except: ExceptSubArgs,
+}
+
+/// Generates the token stream for the `unpaused` macro.
+pub fn unpaused(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse::<ItemFn>(item.clone()).unwrap();
+
+    if is_near_bindgen_wrapped_or_marshall(&input) {
+        return item;
+    }
+
+    let attr_args = parse_macro_input!(attrs as AttributeArgs);
+    let args = UnpausedArgs::from_list(&attr_args).expect("Invalid arguments");
+
+    let cratename = cratename();
+    let fn_name = args.name;
+
+    let bypass_condition = get_bypass_condition(&args.except);
+
+    let requires_pausable = requires_derive_pausable(&input);
+    // `pa_is_paused` takes "ALL" into account.
+    let check_unpaused = quote!(
+        #requires_pausable
+        if !#bypass_condition {
+            #cratename::pausable::PausableExt::pa_assert_not_paused(self, #fn_name);
         }
-    )
+    );
+
+    utils::add_extra_code_to_fn(&input, check_unpaused)
+}
+
+/// Returns a statement that fails compilation with an unsatisfied `Pausable` bound, pointing to
+/// the method, if the contract does not derive `Pausable`. Otherwise the method body would fail
+/// with errors about missing methods like `pa_is_paused`.
+fn requires_derive_pausable(input: &ItemFn) -> proc_macro2::TokenStream {
+    let cratename = cratename();
+    quote_spanned! {input.sig.ident.span()=>
+        #cratename::pausable::requires_derive_pausable::<Self>();
+    }
+}
+
+/// Returns an expression which evaluates to whether the predecessor satisfies any of the
+/// exceptions. Roles and the owner are checked via the `AccessControllable` and `Ownable` traits,
+/// so compilation fails if they are used without the contract implementing the trait.
+fn get_bypass_condition(args: &ExceptSubArgs) -> proc_macro2::TokenStream {
+    let cratename = cratename();
+    let mut conditions = vec![];
+
+    if args.contract {
+        conditions.push(quote! {
+            ::near_sdk::env::predecessor_account_id() == ::near_sdk::env::current_account_id()
+        });
+    }
+    if args.owner {
+        conditions.push(quote! { #cratename::Ownable::owner_is(self) });
+    }
+    if !args.roles.is_empty() {
+        let except_roles = args.roles.clone();
+        conditions.push(quote!({
+            let __except_roles: Vec<&str> = vec![#(#except_roles.into()),*];
+            let __except_roles: Vec<String> = __except_roles.iter().map(|&x| x.into()).collect();
+            #cratename::AccessControllable::acl_has_any_role(
+                self,
+                __except_roles,
+                Self::acl_effective_caller()
+            )
+        }));
+    }
+
+    if conditions.is_empty() {
+        return quote!(false);
+    }
+    quote!((#(#conditions)||*))
 }
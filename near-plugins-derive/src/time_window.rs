@@ -0,0 +1,64 @@
+use crate::utils;
+use crate::utils::is_near_bindgen_wrapped_or_marshall;
+use darling::FromMeta;
+use proc_macro::{self, TokenStream};
+use quote::quote;
+use syn::{parse, parse_macro_input, AttributeArgs, ItemFn};
+
+// Error messages are prefixed with the code of `near_plugins::PluginError::OutsideTimeWindow`.
+const ERR_NOT_STARTED: &str = "WIN001: Time window: Method may not be called yet";
+const ERR_ENDED: &str = "WIN001: Time window: Method may no longer be called";
+
+/// Defines attributes for the `only_during` macro.
+#[derive(Debug, FromMeta)]
+pub struct OnlyDuringArgs {
+    /// Path to a function with signature `fn(&Self) -> Timestamp` which returns the first
+    /// timestamp at which the method may be called.
+    #[darling(default)]
+    start: Option<syn::Path>,
+    /// Path to a function with signature `fn(&Self) -> Timestamp` which returns the first
+    /// timestamp at which the method may no longer be called.
+    #[darling(default)]
+    end: Option<syn::Path>,
+}
+
+/// Generates the token stream for the `only_during` macro.
+pub fn only_during(attrs: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse::<ItemFn>(item.clone()).unwrap();
+
+    if is_near_bindgen_wrapped_or_marshall(&input) {
+        return item;
+    }
+
+    let attr_args = parse_macro_input!(attrs as AttributeArgs);
+    let args = OnlyDuringArgs::from_list(&attr_args).expect("Invalid arguments");
+    if args.start.is_none() && args.end.is_none() {
+        // Panic during macro expansion, as done by `only` if no target is specified.
+        panic!("only_during: Specify at least one of `start` and `end`");
+    }
+
+    // Binding the functions to function pointers makes compilation fail with a clear error if a
+    // referenced function is missing or has an unexpected signature.
+    let start_check = match args.start {
+        Some(start) => quote! {
+            let __only_during_start: fn(&Self) -> ::near_sdk::Timestamp = #start;
+            ::near_sdk::require!(__only_during_now >= __only_during_start(self), #ERR_NOT_STARTED);
+        },
+        None => quote! {},
+    };
+    let end_check = match args.end {
+        Some(end) => quote! {
+            let __only_during_end: fn(&Self) -> ::near_sdk::Timestamp = #end;
+            ::near_sdk::require!(__only_during_now < __only_during_end(self), #ERR_ENDED);
+        },
+        None => quote! {},
+    };
+
+    let check_window = quote! {
+        let __only_during_now = ::near_sdk::env::block_timestamp();
+        #start_check
+        #end_check
+    };
+
+    utils::add_extra_code_to_fn(&input, check_window)
+}
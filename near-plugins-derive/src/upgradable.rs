@@ -1,8 +1,9 @@
+use crate::access_controllable::ERR_INSUFFICIENT_PERMISSIONS_ANY;
 use crate::utils::cratename;
 use darling::util::PathList;
 use darling::{FromDeriveInput, FromMeta};
 use proc_macro::{self, TokenStream};
-use quote::quote;
+use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, DeriveInput};
 
 #[derive(FromDeriveInput, Default)]
@@ -11,8 +12,30 @@ struct Opts {
     /// Storage prefix under which this plugin stores its state. If it is `None` the default value
     /// will be used.
     storage_prefix: Option<String>,
-    /// Roles which are permitted to call protected methods.
-    access_control_roles: AccessControlRoles,
+    /// Roles which are permitted to call protected methods. If it is `None`, the contract likely
+    /// isn't set up for access control and a compile error is emitted.
+    access_control_roles: Option<AccessControlRoles>,
+    /// Name of a method of the contract, or path to a function with signature `fn(&mut Self)`,
+    /// which is called after code was deployed successfully. If it is `None`, no callback is
+    /// attached to deployments.
+    on_deploy: Option<String>,
+    /// If set, `Upgradable::up_deploy_code` succeeds only while "ALL" features of the contract are
+    /// paused. Requires the contract to be `Pausable`.
+    deploy_requires_paused: bool,
+    /// If set, `Upgradable::up_deploy_code` fails while "ALL" features of the contract are paused.
+    /// Requires the contract to be `Pausable`.
+    deploy_forbidden_when_paused: bool,
+    /// If set, code may be staged in and deployed from named slots in addition to the default
+    /// slot. Otherwise the methods operating on named slots panic.
+    named_slots: bool,
+    /// If set, staging code fails while code is already staged in the default slot. Staged code
+    /// must be removed via `Upgradable::up_unstage_code` before new code can be staged.
+    no_overwrite_staged: bool,
+    /// Replaces the `standard` of emitted events, which defaults to the name of the plugin.
+    event_standard: Option<String>,
+    /// Replaces the `version` of emitted events, which defaults to the version of the plugin's
+    /// events.
+    event_version: Option<String>,
 }
 
 /// Specifies which `AccessControlRole`s may call protected methods.
@@ -23,7 +46,8 @@ struct Opts {
 struct AccessControlRoles {
     /// Grantess of these roles may successfully call `Upgradable::up_stage_code`.
     code_stagers: PathList,
-    /// Grantess of these roles may successfully call `Upgradable::up_deploy_code`.
+    /// Grantess of these roles may successfully call `Upgradable::up_deploy_code`. Super-admins may
+    /// replace them via `Upgradable::up_set_code_deployer_roles`.
     code_deployers: PathList,
     /// Grantess of these roles may successfully call `Upgradable::up_init_staging_duration`.
     duration_initializers: PathList,
@@ -31,6 +55,9 @@ struct AccessControlRoles {
     duration_update_stagers: PathList,
     /// Grantess of these roles may successfully call `Upgradable::up_apply_update_staging_duration`.
     duration_update_appliers: PathList,
+    /// Grantess of these roles may successfully call `Upgradable::up_emergency_deploy_code`. It is
+    /// optional and emergency deployments are disabled if no roles are specified.
+    emergency_code_deployers: PathList,
 }
 
 impl AccessControlRoles {
@@ -66,19 +93,42 @@ impl AccessControlRoles {
 
 const DEFAULT_STORAGE_PREFIX: &str = "__up__";
 
+const ERR_CONFLICTING_PAUSE_OPTIONS: &str = "Upgradable: `deploy_requires_paused` and \
+    `deploy_forbidden_when_paused` are mutually exclusive.";
+
+const ERR_MISSING_ACCESS_CONTROL: &str = "Upgradable requires the contract to be \
+    AccessControllable. Add `#[access_control(role_type(...))]` to the contract and pass the roles \
+    that may call `Upgradable` methods via `#[upgradable(access_control_roles(...))]`.";
+
 /// Generates the token stream for the `Upgradable` macro.
 pub fn derive_upgradable(input: TokenStream) -> TokenStream {
     let cratename = cratename();
 
     let input = parse_macro_input!(input);
     let opts = Opts::from_derive_input(&input).expect("Wrong options");
+    let emit_event =
+        crate::utils::emit_event(&cratename, &opts.event_standard, &opts.event_version);
     let DeriveInput { ident, .. } = input;
 
     let storage_prefix = opts
         .storage_prefix
         .unwrap_or_else(|| DEFAULT_STORAGE_PREFIX.to_string());
-    let acl_roles = opts.access_control_roles;
+    let acl_roles = match opts.access_control_roles {
+        Some(acl_roles) => acl_roles,
+        None => {
+            // Return only the error to avoid a cascade of errors caused by generated code which
+            // relies on `AccessControllable`.
+            return syn::Error::new_spanned(&ident, ERR_MISSING_ACCESS_CONTROL)
+                .to_compile_error()
+                .into();
+        }
+    };
     acl_roles.validate();
+    if opts.deploy_requires_paused && opts.deploy_forbidden_when_paused {
+        return syn::Error::new_spanned(&ident, ERR_CONFLICTING_PAUSE_OPTIONS)
+            .to_compile_error()
+            .into();
+    }
 
     // To use fields of a struct inside `quote!`, they must be lifted into variables, see
     // https://github.com/dtolnay/quote/pull/88#pullrequestreview-180577592
@@ -87,8 +137,213 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
     let acl_roles_duration_initializers = acl_roles.duration_initializers;
     let acl_roles_duration_update_stagers = acl_roles.duration_update_stagers;
     let acl_roles_duration_update_appliers = acl_roles.duration_update_appliers;
+    let acl_roles_emergency_code_deployers = acl_roles.emergency_code_deployers;
+
+    // Emergency deployments are opt-in. Without any roles, the method unconditionally panics.
+    let emergency_deploy_code = if acl_roles_emergency_code_deployers.is_empty() {
+        quote! {
+            fn up_emergency_deploy_code(&mut self, function_call_args: Option<#cratename::upgradable::FunctionCallArgs>) -> near_sdk::Promise {
+                let _ = function_call_args;
+                ::near_sdk::env::panic_str("Upgradable: Emergency deployment is disabled")
+            }
+        }
+    } else {
+        quote! {
+            #[#cratename::access_control_any(roles(#(#acl_roles_emergency_code_deployers),*))]
+            fn up_emergency_deploy_code(&mut self, function_call_args: Option<#cratename::upgradable::FunctionCallArgs>) -> near_sdk::Promise {
+                let code = self.up_staged_code().unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: No staged code"));
+                let event = #cratename::upgradable::EmergencyCodeDeployed {
+                    by: Self::acl_effective_caller(),
+                    code_hash: std::convert::TryInto::try_into(::near_sdk::env::sha256(code.as_ref())).unwrap(),
+                };
+                #emit_event;
+                // Like `up_deploy_code` without `keep_staged`, to avoid deploying the code twice.
+                self.up_stage_or_remove_code_unchecked(None, &[], None);
+                self.up_deploy_code_unchecked(code, function_call_args, None)
+            }
+        }
+    };
+
+    // Deployments may depend on whether "ALL" features of a `Pausable` contract are paused. By
+    // default the plugins are independent.
+    let (assert_pausable, check_pause_state) =
+        if opts.deploy_requires_paused || opts.deploy_forbidden_when_paused {
+            let check = if opts.deploy_requires_paused {
+                quote! {
+                    ::near_sdk::require!(
+                        #cratename::Pausable::pa_is_paused(self, "ALL".to_string()),
+                        "UPG002: Upgradable: Deployment requires the contract to be paused"
+                    );
+                }
+            } else {
+                quote! {
+                    ::near_sdk::require!(
+                        !#cratename::Pausable::pa_is_paused(self, "ALL".to_string()),
+                        "UPG002: Upgradable: Deployment is forbidden while the contract is paused"
+                    );
+                }
+            };
+            (
+                quote_spanned! {ident.span()=>
+                    const _: () = {
+                        fn __assert_pausable<T: #cratename::Pausable>() {}
+                        let _ = __assert_pausable::<#ident>;
+                    };
+                },
+                check,
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+    let no_overwrite_staged = opts.no_overwrite_staged;
+
+    // Named slots are opt-in. Without them, the corresponding methods unconditionally panic.
+    let named_slots_methods = if opts.named_slots {
+        quote! {
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_stage_code_named(
+                &mut self,
+                #[serializer(borsh)] slot: String,
+                #[serializer(borsh)] code: Vec<u8>,
+                #[serializer(borsh)] deploy_timestamp: Option<::near_sdk::Timestamp>,
+            ) -> Option<::near_sdk::CryptoHash> {
+                self.up_stage_or_remove_code_unchecked(Some(slot), &code, deploy_timestamp)
+            }
+
+            #[result_serializer(borsh)]
+            fn up_staged_code_named(&self, #[serializer(borsh)] slot: String) -> Option<Vec<u8>> {
+                ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::NamedCode(slot)).as_ref())
+            }
+
+            fn up_staged_code_hash_named(&self, slot: String) -> Option<::near_sdk::CryptoHash> {
+                self.up_staged_code_named(slot)
+                    .map(|code| std::convert::TryInto::try_into(::near_sdk::env::sha256(code.as_ref())).unwrap())
+            }
+
+            fn up_deploy_code_named(
+                &mut self,
+                slot: String,
+                function_call_args: Option<#cratename::upgradable::FunctionCallArgs>,
+                version: Option<String>,
+            ) -> near_sdk::Promise {
+                self.up_assert_code_deployer("up_deploy_code_named");
+
+                let staging_timestamp = self.up_get_timestamp(__UpgradableStorageKey::NamedStagingTimestamp(slot.clone()))
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: No staged code in slot"));
+
+                if ::near_sdk::env::block_timestamp() < staging_timestamp {
+                    ::near_sdk::env::panic_str(
+                        format!(
+                            "UPG001: Upgradable: Deploy code too early: staging ends on {}",
+                            staging_timestamp
+                        )
+                        .as_str(),
+                    );
+                }
+
+                #check_pause_state
+
+                let code = self.up_staged_code_named(slot.clone()).unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: No staged code in slot"));
+                // Like `up_deploy_code` without `keep_staged`, to avoid deploying the code twice.
+                self.up_stage_or_remove_code_unchecked(Some(slot), &[], None);
+                self.up_deploy_code_unchecked(code, function_call_args, version)
+            }
+        }
+    } else {
+        quote! {
+            fn up_stage_code_named(
+                &mut self,
+                #[serializer(borsh)] slot: String,
+                #[serializer(borsh)] code: Vec<u8>,
+                #[serializer(borsh)] deploy_timestamp: Option<::near_sdk::Timestamp>,
+            ) -> Option<::near_sdk::CryptoHash> {
+                let _ = (slot, code, deploy_timestamp);
+                ::near_sdk::env::panic_str("Upgradable: Named slots are disabled")
+            }
+
+            #[result_serializer(borsh)]
+            fn up_staged_code_named(&self, #[serializer(borsh)] slot: String) -> Option<Vec<u8>> {
+                let _ = slot;
+                ::near_sdk::env::panic_str("Upgradable: Named slots are disabled")
+            }
+
+            fn up_staged_code_hash_named(&self, slot: String) -> Option<::near_sdk::CryptoHash> {
+                let _ = slot;
+                ::near_sdk::env::panic_str("Upgradable: Named slots are disabled")
+            }
+
+            fn up_deploy_code_named(
+                &mut self,
+                slot: String,
+                function_call_args: Option<#cratename::upgradable::FunctionCallArgs>,
+                version: Option<String>,
+            ) -> near_sdk::Promise {
+                let _ = (slot, function_call_args, version);
+                ::near_sdk::env::panic_str("Upgradable: Named slots are disabled")
+            }
+        }
+    };
+
+    // If a hook is specified, deployments are followed by a callback which calls the hook.
+    let (attach_on_deploy_callback, on_deploy_callback) = match opts.on_deploy {
+        Some(on_deploy) => {
+            let on_deploy: syn::Path = match syn::parse_str(&on_deploy) {
+                Ok(path) => path,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            // A single identifier names a method, while other paths refer to functions. Binding
+            // them to a function pointer makes compilation fail with a clear error if the
+            // signature differs.
+            let call_on_deploy = match on_deploy.get_ident() {
+                Some(method) => quote! { self.#method() },
+                None => quote! {{
+                    let __on_deploy: fn(&mut Self) = #on_deploy;
+                    __on_deploy(self)
+                }},
+            };
+            (
+                quote! {
+                    let promise = promise.then(
+                        ::near_sdk::Promise::new(::near_sdk::env::current_account_id())
+                            .function_call_weight(
+                                "up_on_deploy_callback".to_string(),
+                                vec![],
+                                ::near_sdk::NearToken::from_yoctonear(0),
+                                ::near_sdk::Gas::from_gas(0),
+                                ::near_sdk::GasWeight(1),
+                            ),
+                    );
+                },
+                quote! {
+                    ::near_sdk::require!(
+                        ::near_sdk::env::promise_results_count() == 1,
+                        "Upgradable: Expected the result of the deployment"
+                    );
+                    match ::near_sdk::env::promise_result(0) {
+                        ::near_sdk::PromiseResult::Successful(_) => #call_on_deploy,
+                        // Panic to keep the failure visible to the caller of `up_deploy_code`,
+                        // which receives the result of this callback.
+                        ::near_sdk::PromiseResult::Failed => ::near_sdk::env::panic_str("Upgradable: Deployment failed"),
+                    }
+                },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    // Fails compilation with an error pointing to the contract if it isn't `AccessControllable`.
+    let assert_access_controllable = quote_spanned! {ident.span()=>
+        const _: () = {
+            fn __assert_access_controllable<T: #cratename::AccessControllable>() {}
+            let _ = __assert_access_controllable::<#ident>;
+        };
+    };
 
     let output = quote! {
+        #assert_access_controllable
+        #assert_pausable
+
         /// Used to make storage prefixes unique. Not to be used directly,
         /// instead it should be prepended to the storage prefix specified by
         /// the user.
@@ -100,6 +355,12 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
             StagingDuration,
             NewStagingDuration,
             NewStagingDurationTimestamp,
+            ChunkBuffer,
+            ChunkBufferTotalLength,
+            Version,
+            NamedCode(String),
+            NamedStagingTimestamp(String),
+            CodeDeployerRoles,
         }
 
         impl #ident {
@@ -137,9 +398,143 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
                 ::near_sdk::env::storage_write(self.up_storage_key(key).as_ref(), &value);
             }
 
+            /// Returns the roles whose grantees may deploy staged code. Unless they were updated via
+            /// `up_set_code_deployer_roles`, these are the roles passed to `code_deployers`.
+            fn up_code_deployer_roles(&self) -> Vec<String> {
+                match ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::CodeDeployerRoles).as_ref()) {
+                    Some(roles_bytes) => <Vec<String> as ::near_sdk::borsh::BorshDeserialize>::try_from_slice(&roles_bytes)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("Upgradable: Invalid format of roles")),
+                    None => {
+                        let roles: Vec<&str> = vec![#(#acl_roles_code_deployers.into()),*];
+                        roles.into_iter().map(String::from).collect()
+                    }
+                }
+            }
+
+            /// Panics unless the predecessor is a grantee of one of the code deployer roles. The
+            /// message equals the one of `access_control_any`.
+            fn up_assert_code_deployer(&self, method: &str) {
+                let roles = self.up_code_deployer_roles();
+                let account_id = Self::acl_effective_caller();
+                if !#cratename::AccessControllable::acl_has_any_role(self, roles.clone(), account_id) {
+                    let message = format!(
+                        #ERR_INSUFFICIENT_PERMISSIONS_ANY,
+                        method,
+                        roles,
+                    );
+                    ::near_sdk::env::panic_str(&message);
+                }
+            }
+
+            fn up_duration_from_secs(&self, secs: u64) -> ::near_sdk::Duration {
+                secs.checked_mul(1_000_000_000)
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: Staging duration overflows"))
+            }
+
             fn up_set_staging_duration_unchecked(&self, staging_duration: near_sdk::Duration) {
                 self.up_storage_write(__UpgradableStorageKey::StagingDuration, &::near_sdk::borsh::to_vec(&staging_duration).unwrap());
             }
+
+            /// Returns the staging timestamp of code that is staged now.
+            fn up_staging_timestamp_for(&self, deploy_timestamp: Option<::near_sdk::Timestamp>) -> ::near_sdk::Timestamp {
+                let timestamp = ::near_sdk::env::block_timestamp() + self.up_get_duration(__UpgradableStorageKey::StagingDuration).unwrap_or(0);
+                // An explicit deploy timestamp may only postpone the deployment.
+                deploy_timestamp.map_or(timestamp, |deploy_timestamp| deploy_timestamp.max(timestamp))
+            }
+
+            /// Returns the storage keys of the code and the staging timestamp of the named slot
+            /// `slot`, or of the default slot if `slot` is `None`.
+            fn up_slot_storage_keys(&self, slot: Option<String>) -> (Vec<u8>, Vec<u8>) {
+                let (code_key, timestamp_key) = match slot {
+                    None => (__UpgradableStorageKey::Code, __UpgradableStorageKey::StagingTimestamp),
+                    Some(slot) => (
+                        __UpgradableStorageKey::NamedCode(slot.clone()),
+                        __UpgradableStorageKey::NamedStagingTimestamp(slot),
+                    ),
+                };
+                (self.up_storage_key(code_key), self.up_storage_key(timestamp_key))
+            }
+
+            /// Stages `code` in `slot`, see `up_slot_storage_keys`.
+            fn up_stage_code_unchecked(&self, slot: Option<String>, code: &[u8], deploy_timestamp: Option<::near_sdk::Timestamp>) {
+                let (code_key, timestamp_key) = self.up_slot_storage_keys(slot);
+                if #no_overwrite_staged {
+                    ::near_sdk::require!(
+                        !::near_sdk::env::storage_has_key(&code_key),
+                        "Upgradable: Code is already staged"
+                    );
+                }
+                let timestamp = self.up_staging_timestamp_for(deploy_timestamp);
+                ::near_sdk::env::storage_write(&code_key, code);
+                ::near_sdk::env::storage_write(&timestamp_key, &::near_sdk::borsh::to_vec(&timestamp).unwrap());
+            }
+
+            /// Stages `code` in `slot` or, if it is empty, removes the staged code and the staging
+            /// timestamp of `slot`. Returns the hash of staged code or `None` if it was removed.
+            fn up_stage_or_remove_code_unchecked(&self, slot: Option<String>, code: &[u8], deploy_timestamp: Option<::near_sdk::Timestamp>) -> Option<::near_sdk::CryptoHash> {
+                if code.is_empty() {
+                    let (code_key, timestamp_key) = self.up_slot_storage_keys(slot);
+                    ::near_sdk::env::storage_remove(&code_key);
+                    ::near_sdk::env::storage_remove(&timestamp_key);
+                    None
+                } else {
+                    self.up_stage_code_unchecked(slot, code, deploy_timestamp);
+                    Some(std::convert::TryInto::try_into(::near_sdk::env::sha256(code)).unwrap())
+                }
+            }
+
+            fn up_get_chunk_buffer_total_length(&self) -> Option<u64> {
+                ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::ChunkBufferTotalLength).as_ref()).map(|length_bytes| {
+                    u64::try_from_slice(&length_bytes).unwrap_or_else(|_|
+                        near_sdk::env::panic_str("Upgradable: Invalid u64 length format")
+                    )
+                })
+            }
+
+            fn up_clear_chunk_buffer(&self) {
+                ::near_sdk::env::storage_remove(self.up_storage_key(__UpgradableStorageKey::ChunkBuffer).as_ref());
+                ::near_sdk::env::storage_remove(self.up_storage_key(__UpgradableStorageKey::ChunkBufferTotalLength).as_ref());
+            }
+
+            fn up_deploy_code_unchecked(
+                &self,
+                code: Vec<u8>,
+                function_call_args: Option<#cratename::upgradable::FunctionCallArgs>,
+                version: Option<String>,
+            ) -> near_sdk::Promise {
+                // Cheap sanity check to avoid bricking the contract by deploying bytes which are
+                // obviously not a wasm module. A wasm module starts with the magic number and a
+                // 4 byte version.
+                ::near_sdk::require!(
+                    code.len() >= 8 && code.starts_with(b"\0asm"),
+                    "Upgradable: Staged code is not a wasm module",
+                );
+                let promise = ::near_sdk::Promise::new(::near_sdk::env::current_account_id())
+                    .deploy_contract(code);
+                let promise = match function_call_args {
+                    None => promise,
+                    Some(args) => {
+                        // Execute the `DeployContract` and `FunctionCall` actions in a batch
+                        // transaction to make a failure of the function call roll back the code
+                        // deployment.
+                        promise.function_call(args.function_name, args.arguments, args.amount, args.gas)
+                    },
+                };
+                // Setting the version in the same batch ensures it is stored if and only if the
+                // deployment succeeds.
+                let promise = match version {
+                    None => promise,
+                    Some(version) => promise.function_call_weight(
+                        "up_set_version".to_string(),
+                        ::near_sdk::serde_json::to_vec(&::near_sdk::serde_json::json!({ "version": version })).unwrap(),
+                        ::near_sdk::NearToken::from_yoctonear(0),
+                        ::near_sdk::Gas::from_gas(0),
+                        ::near_sdk::GasWeight(1),
+                    ),
+                };
+                #attach_on_deploy_callback
+                promise
+            }
         }
 
         #[near]
@@ -157,16 +552,144 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
                 }
             }
 
+            fn up_get_delay_status_json(&self) -> #cratename::upgradable::UpgradableDurationStatusJson {
+                self.up_get_delay_status().into()
+            }
+
+            fn up_time_until_deployable(&self) -> Option<::near_sdk::Duration> {
+                self.up_get_timestamp(__UpgradableStorageKey::StagingTimestamp)
+                    .map(|staging_timestamp| staging_timestamp.saturating_sub(::near_sdk::env::block_timestamp()))
+            }
+
+            fn up_time_until_duration_update_applicable(&self) -> Option<::near_sdk::Duration> {
+                self.up_get_timestamp(__UpgradableStorageKey::NewStagingDurationTimestamp)
+                    .map(|staging_timestamp| staging_timestamp.saturating_sub(::near_sdk::env::block_timestamp()))
+            }
+
+            fn up_staging_duration_secs(&self) -> Option<u64> {
+                self.up_get_duration(__UpgradableStorageKey::StagingDuration)
+                    .map(|duration| duration / 1_000_000_000)
+            }
+
+            fn up_new_staging_duration_secs(&self) -> Option<u64> {
+                self.up_get_duration(__UpgradableStorageKey::NewStagingDuration)
+                    .map(|duration| duration / 1_000_000_000)
+            }
+
             #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
-            fn up_stage_code(&mut self, #[serializer(borsh)] code: Vec<u8>) {
-                if code.is_empty() {
-                    ::near_sdk::env::storage_remove(self.up_storage_key(__UpgradableStorageKey::Code).as_ref());
-                    ::near_sdk::env::storage_remove(self.up_storage_key(__UpgradableStorageKey::StagingTimestamp).as_ref());
+            fn up_stage_code(&mut self, #[serializer(borsh)] code: Vec<u8>) -> Option<::near_sdk::CryptoHash> {
+                self.up_stage_or_remove_code_unchecked(None, &code, None)
+            }
+
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_stage_code_at(
+                &mut self,
+                #[serializer(borsh)] code: Vec<u8>,
+                #[serializer(borsh)] deploy_timestamp: ::near_sdk::Timestamp,
+            ) -> Option<::near_sdk::CryptoHash> {
+                self.up_stage_or_remove_code_unchecked(None, &code, Some(deploy_timestamp))
+            }
+
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_stage_code_json(
+                &mut self,
+                code: ::near_sdk::json_types::Base64VecU8,
+                deploy_timestamp: Option<::near_sdk::json_types::U64>,
+            ) -> Option<::near_sdk::CryptoHash> {
+                self.up_stage_or_remove_code_unchecked(None, &code.0, deploy_timestamp.map(|timestamp| timestamp.0))
+            }
+
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_stage_code_chunk(
+                &mut self,
+                #[serializer(borsh)] offset: u64,
+                #[serializer(borsh)] chunk: Vec<u8>,
+                #[serializer(borsh)] total_len: u64,
+            ) {
+                let mut buffer = if offset == 0 {
+                    self.up_storage_write(__UpgradableStorageKey::ChunkBufferTotalLength, &::near_sdk::borsh::to_vec(&total_len).unwrap());
+                    vec![]
                 } else {
-                    let timestamp = ::near_sdk::env::block_timestamp() + self.up_get_duration(__UpgradableStorageKey::StagingDuration).unwrap_or(0);
-                    self.up_storage_write(__UpgradableStorageKey::Code, &code);
-                    self.up_set_timestamp(__UpgradableStorageKey::StagingTimestamp, timestamp);
-                }
+                    let expected_total_len = self.up_get_chunk_buffer_total_length()
+                        .unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: No chunked upload in progress"));
+                    ::near_sdk::require!(expected_total_len == total_len, "Upgradable: Total length differs from previous chunks");
+                    ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::ChunkBuffer).as_ref())
+                        .unwrap_or_default()
+                };
+                ::near_sdk::require!(offset == buffer.len() as u64, "Upgradable: Chunk offset must equal the length of uploaded code");
+                ::near_sdk::require!(offset + chunk.len() as u64 <= total_len, "Upgradable: Chunk exceeds total length");
+                buffer.extend(chunk);
+                self.up_storage_write(__UpgradableStorageKey::ChunkBuffer, &buffer);
+            }
+
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_finalize_staged_code(&mut self) {
+                let total_len = self.up_get_chunk_buffer_total_length()
+                    .unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: No chunked upload in progress"));
+                let code = ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::ChunkBuffer).as_ref())
+                    .unwrap_or_default();
+                ::near_sdk::require!(code.len() as u64 == total_len, "Upgradable: Length of uploaded code differs from total length");
+                ::near_sdk::require!(!code.is_empty(), "Upgradable: Uploaded code is empty");
+                self.up_stage_code_unchecked(None, &code, None);
+                self.up_clear_chunk_buffer();
+            }
+
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_unstage_code(&mut self) {
+                ::near_sdk::env::storage_remove(self.up_storage_key(__UpgradableStorageKey::Code).as_ref());
+                ::near_sdk::env::storage_remove(self.up_storage_key(__UpgradableStorageKey::StagingTimestamp).as_ref());
+                self.up_clear_chunk_buffer();
+            }
+
+            #[#cratename::access_control_any(roles(#(#acl_roles_code_stagers),*))]
+            fn up_stage_code_from_hash(
+                &mut self,
+                source_account: ::near_sdk::AccountId,
+                expected_hash: ::near_sdk::CryptoHash,
+            ) -> ::near_sdk::Promise {
+                let args = ::near_sdk::borsh::to_vec(&expected_hash).unwrap();
+                ::near_sdk::Promise::new(source_account)
+                    .function_call_weight(
+                        "get_code".to_string(),
+                        args.clone(),
+                        ::near_sdk::NearToken::from_yoctonear(0),
+                        ::near_sdk::Gas::from_gas(0),
+                        ::near_sdk::GasWeight(1),
+                    )
+                    .then(
+                        ::near_sdk::Promise::new(::near_sdk::env::current_account_id())
+                            .function_call_weight(
+                                "up_stage_code_from_hash_callback".to_string(),
+                                args,
+                                ::near_sdk::NearToken::from_yoctonear(0),
+                                ::near_sdk::Gas::from_gas(0),
+                                ::near_sdk::GasWeight(1),
+                            ),
+                    )
+            }
+
+            #[private]
+            fn up_stage_code_from_hash_callback(&mut self, #[serializer(borsh)] expected_hash: ::near_sdk::CryptoHash) {
+                ::near_sdk::require!(
+                    ::near_sdk::env::promise_results_count() == 1,
+                    "Upgradable: Expected the result of the code registry"
+                );
+                let code: Option<Vec<u8>> = match ::near_sdk::env::promise_result(0) {
+                    ::near_sdk::PromiseResult::Successful(bytes) => ::near_sdk::borsh::BorshDeserialize::try_from_slice(&bytes)
+                        .unwrap_or_else(|_| ::near_sdk::env::panic_str("Upgradable: Invalid response of the code registry")),
+                    ::near_sdk::PromiseResult::Failed => ::near_sdk::env::panic_str("Upgradable: Call of the code registry failed"),
+                };
+                let code = code.unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: Code not found in registry"));
+                ::near_sdk::require!(
+                    ::near_sdk::env::sha256(&code) == expected_hash,
+                    "Upgradable: Hash of the code from the registry does not match"
+                );
+                self.up_stage_code_unchecked(None, &code, None);
+            }
+
+            #[private]
+            fn up_on_deploy_callback(&mut self) {
+                #on_deploy_callback
             }
 
             #[result_serializer(borsh)]
@@ -174,46 +697,101 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
                 ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::Code).as_ref())
             }
 
+            fn up_staged_code_length(&self) -> Option<u64> {
+                // The code is read within the contract but, in contrast to `up_staged_code`, not
+                // serialized into the return value.
+                ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::Code).as_ref())
+                    .map(|code| code.len() as u64)
+            }
+
             fn up_staged_code_hash(&self) -> Option<::near_sdk::CryptoHash> {
                 self.up_staged_code()
                     .map(|code| std::convert::TryInto::try_into(::near_sdk::env::sha256(code.as_ref())).unwrap())
             }
 
-            #[#cratename::access_control_any(roles(#(#acl_roles_code_deployers),*))]
-            fn up_deploy_code(&mut self, function_call_args: Option<#cratename::upgradable::FunctionCallArgs>) -> near_sdk::Promise {
+            fn up_deploy_code(
+                &mut self,
+                function_call_args: Option<#cratename::upgradable::FunctionCallArgs>,
+                version: Option<String>,
+                keep_staged: Option<bool>,
+            ) -> near_sdk::Promise {
+                self.up_assert_code_deployer("up_deploy_code");
+
                 let staging_timestamp = self.up_get_timestamp(__UpgradableStorageKey::StagingTimestamp)
                     .unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: staging timestamp isn't set"));
 
                 if ::near_sdk::env::block_timestamp() < staging_timestamp {
                     ::near_sdk::env::panic_str(
                         format!(
-                            "Upgradable: Deploy code too early: staging ends on {}",
+                            "UPG001: Upgradable: Deploy code too early: staging ends on {}",
                             staging_timestamp
                         )
                         .as_str(),
                     );
                 }
 
+                #check_pause_state
+
                 let code = self.up_staged_code().unwrap_or_else(|| ::near_sdk::env::panic_str("Upgradable: No staged code"));
-                let promise = ::near_sdk::Promise::new(::near_sdk::env::current_account_id())
-                    .deploy_contract(code);
-                match function_call_args {
-                    None => promise,
-                    Some(args) => {
-                        // Execute the `DeployContract` and `FunctionCall` actions in a batch
-                        // transaction to make a failure of the function call roll back the code
-                        // deployment.
-                        promise.function_call(args.function_name, args.arguments, args.amount, args.gas)
-                    },
+                if !keep_staged.unwrap_or(false) {
+                    // Removed in this receipt since the batch promise and callbacks execute on the
+                    // new code, which might not know about staged code.
+                    self.up_stage_or_remove_code_unchecked(None, &[], None);
                 }
+                self.up_deploy_code_unchecked(code, function_call_args, version)
+            }
+
+            #[private]
+            fn up_set_version(&mut self, version: String) {
+                self.up_storage_write(__UpgradableStorageKey::Version, version.as_bytes());
             }
 
+            fn up_version(&self) -> Option<String> {
+                ::near_sdk::env::storage_read(self.up_storage_key(__UpgradableStorageKey::Version).as_ref())
+                    .map(|version| String::from_utf8(version).unwrap_or_else(|_| ::near_sdk::env::panic_str("Upgradable: Invalid version format")))
+            }
+
+            fn up_get_code_deployer_roles(&self) -> Vec<String> {
+                self.up_code_deployer_roles()
+            }
+
+            fn up_set_code_deployer_roles(&mut self, roles: Vec<String>) {
+                let by = Self::acl_effective_caller();
+                ::near_sdk::require!(
+                    #cratename::AccessControllable::acl_is_super_admin(self, by.clone()),
+                    "Upgradable: Only super-admins may set roles"
+                );
+                ::near_sdk::require!(!roles.is_empty(), "Upgradable: Specify at least one role");
+                let variants = #cratename::AccessControllable::acl_role_variants(self);
+                for role in roles.iter() {
+                    ::near_sdk::require!(variants.contains(&role.as_str()), "Upgradable: Unknown role");
+                }
+
+                self.up_storage_write(
+                    __UpgradableStorageKey::CodeDeployerRoles,
+                    &::near_sdk::borsh::to_vec(&roles).unwrap(),
+                );
+
+                let event = #cratename::upgradable::CodeDeployerRolesUpdated { by, roles };
+                #emit_event;
+            }
+
+            #emergency_deploy_code
+
+            #named_slots_methods
+
             #[#cratename::access_control_any(roles(#(#acl_roles_duration_initializers),*))]
             fn up_init_staging_duration(&mut self, staging_duration: ::near_sdk::Duration) {
                 ::near_sdk::require!(self.up_get_duration(__UpgradableStorageKey::StagingDuration).is_none(), "Upgradable: staging duration was already initialized");
                 self.up_set_staging_duration_unchecked(staging_duration);
             }
 
+            // Access control is enforced by `up_init_staging_duration`.
+            fn up_init_staging_duration_secs(&mut self, staging_duration_secs: u64) {
+                let staging_duration = self.up_duration_from_secs(staging_duration_secs);
+                self.up_init_staging_duration(staging_duration);
+            }
+
             #[#cratename::access_control_any(roles(#(#acl_roles_duration_update_stagers),*))]
             fn up_stage_update_staging_duration(&mut self, staging_duration: ::near_sdk::Duration) {
                 let current_staging_duration = self.up_get_duration(__UpgradableStorageKey::StagingDuration)
@@ -224,6 +802,12 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
                 self.up_set_timestamp(__UpgradableStorageKey::NewStagingDurationTimestamp, staging_duration_timestamp);
             }
 
+            // Access control is enforced by `up_stage_update_staging_duration`.
+            fn up_stage_update_staging_duration_secs(&mut self, staging_duration_secs: u64) {
+                let staging_duration = self.up_duration_from_secs(staging_duration_secs);
+                self.up_stage_update_staging_duration(staging_duration);
+            }
+
             #[#cratename::access_control_any(roles(#(#acl_roles_duration_update_appliers),*))]
             fn up_apply_update_staging_duration(&mut self) {
                 let staging_timestamp = self.up_get_timestamp(__UpgradableStorageKey::NewStagingDurationTimestamp)
@@ -232,7 +816,7 @@ pub fn derive_upgradable(input: TokenStream) -> TokenStream {
                 if ::near_sdk::env::block_timestamp() < staging_timestamp {
                     ::near_sdk::env::panic_str(
                         format!(
-                            "Upgradable: Update duration too early: staging ends on {}",
+                            "UPG003: Upgradable: Update duration too early: staging ends on {}",
                             staging_timestamp
                         )
                         .as_str(),
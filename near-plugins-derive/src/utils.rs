@@ -24,6 +24,32 @@ pub(crate) fn cratename() -> Ident {
     )
 }
 
+/// Returns the code emitting the event bound to the variable `event`. If `standard` or `version`
+/// are set, they replace the corresponding fields of the NEP-297 envelope.
+pub(crate) fn emit_event(
+    cratename: &Ident,
+    standard: &Option<String>,
+    version: &Option<String>,
+) -> proc_macro2::TokenStream {
+    if standard.is_none() && version.is_none() {
+        return quote::quote! { #cratename::events::AsEvent::emit(&event) };
+    }
+    let to_tokens = |value: &Option<String>| match value {
+        Some(value) => quote::quote! { Some(#value) },
+        None => quote::quote! { None },
+    };
+    let standard = to_tokens(standard);
+    let version = to_tokens(version);
+    quote::quote! { #cratename::events::AsEvent::emit_with(&event, #standard, #version) }
+}
+
+/// Returns whether `item` is declared with a visibility, e.g. `pub`. Methods in trait impls may
+/// not have a visibility, so this holds only for functions outside of trait impls. Macros may emit
+/// additional associated items next to such methods, which would fail compilation of trait impls.
+pub(crate) fn has_visibility(item: &ItemFn) -> bool {
+    !matches!(item.vis, syn::Visibility::Inherited)
+}
+
 /// Injects extra code into a function.
 pub(crate) fn add_extra_code_to_fn(
     fn_code: &ItemFn,
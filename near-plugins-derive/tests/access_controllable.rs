@@ -3,11 +3,17 @@
 pub mod common;
 
 use common::access_controllable_contract::AccessControllableContract;
+use common::ownable_contract::OwnableContract;
 use common::utils::{
-    as_sdk_account_id, assert_insufficient_acl_permissions, assert_private_method_failure,
-    assert_success_with,
+    as_sdk_account_id, assert_failure_with, assert_insufficient_acl_permissions,
+    assert_method_not_found_failure, assert_private_method_failure, assert_success_with,
+    block_timestamp, fast_forward_beyond, sdk_duration_from_secs,
 };
-use near_plugins::access_controllable::{PermissionedAccounts, PermissionedAccountsPerRole};
+use near_plugins::access_controllable::{
+    AclAuditAction, AclCursor, AclGrantResult, AclPermissionKind, PermissionedAccounts,
+    PermissionedAccountsPerRole, RevokeRolePreview,
+};
+use near_plugins::PluginError;
 use near_sdk::serde_json::json;
 use near_workspaces::network::Sandbox;
 use near_workspaces::result::ExecutionFinalResult;
@@ -17,6 +23,19 @@ use std::convert::TryFrom;
 use std::path::Path;
 
 const PROJECT_PATH: &str = "./tests/contracts/access_controllable";
+const PROJECT_PATH_STORAGE_PREFIX: &str = "./tests/contracts/access_controllable_storage_prefix";
+const PROJECT_PATH_OWNER: &str = "./tests/contracts/access_controllable_owner";
+const PROJECT_PATH_AUDIT_LOG: &str = "./tests/contracts/access_controllable_audit_log";
+const PROJECT_PATH_LAST_SUPER_ADMIN: &str =
+    "./tests/contracts/access_controllable_last_super_admin";
+const PROJECT_PATH_ADMINS_MANAGE_ADMINS: &str =
+    "./tests/contracts/access_controllable_admins_manage_admins";
+const PROJECT_PATH_EXPOSE_UNCHECKED: &str =
+    "./tests/contracts/access_controllable_expose_unchecked";
+const PROJECT_PATH_DELEGATED_ROLES: &str = "./tests/contracts/access_controllable_delegated_roles";
+const PROJECT_PATH_EVENT_STANDARD: &str = "./tests/contracts/access_controllable_event_standard";
+const PROJECT_PATH_CALLER_RESOLVER: &str = "./tests/contracts/access_controllable_caller_resolver";
+const PROJECT_PATH_ROLE_BIT_COLLISION: &str = "./tests/contracts/access_control_role_bit_collision";
 
 /// All roles which are defined in the contract in [`PROJECT_PATH`].
 const ALL_ROLES: [&str; 3] = ["ByMax2Increaser", "ByMax3Increaser", "Resetter"];
@@ -119,6 +138,18 @@ async fn call_increase_2(
         .await
 }
 
+async fn call_rate_limited_increase(
+    contract: &Contract,
+    caller: &Account,
+) -> near_workspaces::Result<ExecutionFinalResult> {
+    caller
+        .call(contract.id(), "rate_limited_increase")
+        .args_json(())
+        .max_gas()
+        .transact()
+        .await
+}
+
 /// Returns new `PermissionedAccounts` for [`ALL_ROLES`].
 fn new_permissioned_accounts() -> PermissionedAccounts {
     let mut permissioned_accounts = PermissionedAccounts {
@@ -493,6 +524,83 @@ async fn test_acl_transfer_super_admin() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_acl_transfer_admin() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let new_admin = setup.worker.dev_create_account().await?;
+
+    setup
+        .contract
+        .assert_acl_is_admin(true, role, admin.id())
+        .await;
+
+    // Transfer is a no-op if caller is not an admin for `role`.
+    let caller_unauth = setup.worker.dev_create_account().await?;
+    let res = setup
+        .contract
+        .acl_transfer_admin(&caller_unauth, role, new_admin.id())
+        .await?;
+    assert_eq!(res, None);
+    setup
+        .contract
+        .assert_acl_is_admin(true, role, admin.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_is_admin(false, role, new_admin.id())
+        .await;
+
+    // Being admin for another role is not sufficient.
+    let other_admin = setup.new_account_as_admin(&["Resetter"]).await?;
+    let res = setup
+        .contract
+        .acl_transfer_admin(&other_admin, role, new_admin.id())
+        .await?;
+    assert_eq!(res, None);
+
+    // Transfer to oneself is a no-op.
+    let res = setup
+        .contract
+        .acl_transfer_admin(&admin, role, admin.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    setup
+        .contract
+        .assert_acl_is_admin(true, role, admin.id())
+        .await;
+
+    // Transfer succeeds if the caller is an admin for `role`.
+    let res = setup
+        .contract
+        .acl_transfer_admin(&admin, role, new_admin.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    setup
+        .contract
+        .assert_acl_is_admin(false, role, admin.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_is_admin(true, role, new_admin.id())
+        .await;
+
+    // Transfer to an account that is already admin for `role` returns `Some(false)`.
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_transfer_admin(&new_admin, role, admin.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    setup
+        .contract
+        .assert_acl_is_admin(false, role, new_admin.id())
+        .await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_revoke_super_admin_unchecked() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -670,6 +778,151 @@ async fn test_acl_is_admin() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Paginated getters reject a `limit` exceeding the default maximum page size of 500.
+#[tokio::test]
+async fn test_acl_getters_max_page_size() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let contract = &setup.contract;
+
+    // Within the limit, getters behave as before.
+    let grantees = contract
+        .acl_get_grantees(&setup.account, role, 0, 500)
+        .await?;
+    assert_eq!(grantees, vec![grantee.id().clone()]);
+
+    let err_page_size = "ACL005: ACL: limit must not exceed 500";
+    for (method, args) in [
+        ("acl_get_super_admins", json!({ "skip": 0, "limit": 501 })),
+        (
+            "acl_get_grantees",
+            json!({ "role": role, "skip": 0, "limit": 501 }),
+        ),
+        (
+            "acl_get_grantees_sorted",
+            json!({ "role": role, "skip": 0, "limit": u64::MAX }),
+        ),
+        (
+            "acl_get_grantees_page",
+            json!({ "role": role, "from": null, "limit": 501 }),
+        ),
+        (
+            "acl_get_permissioned_accounts_chunk",
+            json!({ "cursor": null, "max_accounts": 501 }),
+        ),
+    ] {
+        let res = setup
+            .account
+            .call(contract.contract().id(), method)
+            .args_json(args)
+            .max_gas()
+            .transact()
+            .await?;
+        assert_failure_with(res, err_page_size);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_is_role_admin_exact() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let super_admin = setup.new_super_admin_account().await?;
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let contract = &setup.contract;
+
+    // A super-admin is admin for every role, though not an explicit admin.
+    assert!(
+        contract
+            .acl_is_admin(&setup.account, role, super_admin.id())
+            .await?
+    );
+    assert!(
+        !contract
+            .acl_is_role_admin_exact(&setup.account, role, super_admin.id())
+            .await?
+    );
+
+    // An explicit admin is admin in both views, but only for its role.
+    assert!(
+        contract
+            .acl_is_admin(&setup.account, role, admin.id())
+            .await?
+    );
+    assert!(
+        contract
+            .acl_is_role_admin_exact(&setup.account, role, admin.id())
+            .await?
+    );
+    assert!(
+        !contract
+            .acl_is_role_admin_exact(&setup.account, "Resetter", admin.id())
+            .await?
+    );
+
+    // Adding a super-admin as admin for the role makes it an explicit admin.
+    contract
+        .acl_add_admin_unchecked(setup.contract_account(), role, super_admin.id())
+        .await?
+        .into_result()?;
+    assert!(
+        contract
+            .acl_is_role_admin_exact(&setup.account, role, super_admin.id())
+            .await?
+    );
+
+    Ok(())
+}
+
+/// Asserts `acl_role_contains` and `acl_has_role` agree that `account_id` has only the role
+/// `granted`, if any.
+async fn assert_role_contains_agrees(
+    setup: &Setup,
+    account_id: &AccountId,
+    granted: Option<&str>,
+) -> anyhow::Result<()> {
+    for role in ALL_ROLES {
+        let expected = granted == Some(role);
+        let has_role = setup
+            .contract
+            .acl_has_role(&setup.account, role, account_id)
+            .await?;
+        let contains = setup
+            .contract
+            .acl_role_contains(&setup.account, role, account_id)
+            .await?;
+        assert_eq!(has_role, expected);
+        assert_eq!(contains, expected);
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_role_contains() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let account = setup.worker.dev_create_account().await?;
+    assert_role_contains_agrees(&setup, account.id(), None).await?;
+
+    setup
+        .contract
+        .acl_grant_role_unchecked(setup.contract_account(), role, account.id())
+        .await?
+        .into_result()?;
+    assert_role_contains_agrees(&setup, account.id(), Some(role)).await?;
+
+    setup
+        .contract
+        .acl_revoke_role_unchecked(setup.contract_account(), role, account.id())
+        .await?
+        .into_result()?;
+    assert_role_contains_agrees(&setup, account.id(), None).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_acl_add_admin() -> anyhow::Result<()> {
     let Setup {
@@ -948,484 +1201,1344 @@ async fn test_acl_grant_role() -> anyhow::Result<()> {
 }
 
 #[tokio::test]
-async fn test_acl_grant_role_unchecked() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
-    let contract_account = contract.contract().as_account();
-    let role = "ByMax2Increaser";
+async fn test_acl_try_grant_role() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax3Increaser";
+    let grantee = setup.worker.dev_create_account().await?;
 
-    contract
-        .assert_acl_has_role(false, role, account.id())
+    // An account which isn't admin can't grant the role.
+    let res = setup
+        .contract
+        .acl_try_grant_role(&setup.account, role, grantee.id())
+        .await?;
+    assert_eq!(res, AclGrantResult::Unauthorized);
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
         .await;
-    let res = contract
-        .acl_grant_role_unchecked(contract_account, role, account.id())
+
+    // Admin can grant the role.
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
+        .contract
+        .acl_try_grant_role(&admin, role, grantee.id())
         .await?;
-    assert_success_with(res, true);
-    contract.assert_acl_has_role(true, role, account.id()).await;
+    assert_eq!(res, AclGrantResult::Granted);
+    setup
+        .contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
 
-    // Granting a role again behaves as expected.
-    let res = contract
-        .acl_grant_role_unchecked(contract_account, role, account.id())
+    // Granting the role to an account which already is a grantee.
+    let res = setup
+        .contract
+        .acl_try_grant_role(&admin, role, grantee.id())
         .await?;
-    assert_success_with(res, false);
+    assert_eq!(res, AclGrantResult::AlreadyGranted);
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_revoke_role() -> anyhow::Result<()> {
+async fn test_acl_ensure_role() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
     let role = "ByMax3Increaser";
-    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
 
+    // In contrast to `acl_grant_role`, the call fails if the predecessor isn't admin.
+    let res = setup
+        .contract
+        .acl_ensure_role(&setup.account, role, grantee.id())
+        .await?;
+    assert_failure_with(
+        res,
+        "ACL001: Insufficient permissions: caller is not an admin of the role",
+    );
     setup
         .contract
-        .assert_acl_has_role(true, role, grantee.id())
+        .assert_acl_has_role(false, role, grantee.id())
         .await;
 
-    // Revoke is a no-op if revoker is not an admin for the role.
-    let revoker = setup.new_account_as_admin(&[]).await?;
-    let res = setup
-        .contract
-        .acl_revoke_role(&revoker, role, grantee.id())
-        .await?;
-    assert_eq!(res, None);
-    let revoker = setup.new_account_as_admin(&["ByMax2Increaser"]).await?;
+    // Granting the role modifies state.
+    let admin = setup.new_account_as_admin(&[role]).await?;
     let res = setup
         .contract
-        .acl_revoke_role(&revoker, role, grantee.id())
+        .acl_ensure_role(&admin, role, grantee.id())
         .await?;
-    assert_eq!(res, None);
+    assert_success_with(res, true);
     setup
         .contract
         .assert_acl_has_role(true, role, grantee.id())
         .await;
 
-    // Revoke succeeds if the revoker is an admin for the role.
-    let revoker = setup.new_account_as_admin(&[role]).await?;
+    // Ensuring the role again is a no-op.
     let res = setup
         .contract
-        .acl_revoke_role(&revoker, role, grantee.id())
+        .acl_ensure_role(&admin, role, grantee.id())
         .await?;
-    assert_eq!(res, Some(true));
+    assert_success_with(res, false);
     setup
         .contract
-        .assert_acl_has_role(false, role, grantee.id())
+        .assert_acl_has_role(true, role, grantee.id())
         .await;
 
-    // Revoking a role that isn't granted returns `Some(false)`.
-    let revoker = setup.new_account_as_admin(&[role]).await?;
-    let account = setup.worker.dev_create_account().await?;
-    let res = setup
-        .contract
-        .acl_revoke_role(&revoker, role, account.id())
-        .await?;
-    assert_eq!(res, Some(false));
-
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_renounce_role() -> anyhow::Result<()> {
+async fn test_acl_clone_permissions() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
-    let role = "Resetter";
+    let raw_contract = setup.contract.contract();
+    let from = setup
+        .new_account_with_roles(&["ByMax2Increaser", "ByMax3Increaser"])
+        .await?;
+    setup
+        .contract
+        .acl_add_admin_unchecked(setup.contract_account(), "Resetter", from.id())
+        .await?
+        .into_result()?;
+    let to = setup.worker.dev_create_account().await?;
 
-    // An account which is isn't grantee calls `acl_renounce_role`.
+    // Only super-admins may clone permissions.
     let res = setup
         .contract
-        .acl_renounce_role(&setup.account, role)
+        .acl_clone_permissions(&from, from.id(), to.id())
         .await?;
-    assert!(!res);
+    assert_failure_with(
+        res,
+        "ACL001: Insufficient permissions: caller is not a super-admin",
+    );
 
-    // A grantee calls `acl_renounce_admin`.
-    let grantee = setup.new_account_with_roles(&[role]).await?;
+    // Two roles and one admin permission are copied.
+    let super_admin = setup.new_super_admin_account().await?;
+    let res = setup
+        .contract
+        .acl_clone_permissions(&super_admin, from.id(), to.id())
+        .await?;
+    assert_success_with(res, 3);
+
+    // `to` passes the same guards as `from`.
+    let res = to
+        .call(raw_contract.id(), "increase_5")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 5);
+    let res = call_increase_2(raw_contract, &to).await?;
+    assert_success_with(res, 7);
     setup
         .contract
-        .assert_acl_has_role(true, role, grantee.id())
+        .assert_acl_is_admin(true, "Resetter", to.id())
         .await;
-    let res = setup.contract.acl_renounce_role(&grantee, role).await?;
-    assert!(res);
+
+    // Permissions of `from` are kept.
     setup
         .contract
-        .assert_acl_has_role(false, role, grantee.id())
+        .assert_acl_has_role(true, "ByMax2Increaser", from.id())
         .await;
 
+    // Cloning again doesn't copy anything.
+    let res = setup
+        .contract
+        .acl_clone_permissions(&super_admin, from.id(), to.id())
+        .await?;
+    assert_success_with(res, 0);
+
     Ok(())
 }
 
+/// If `to` bears a role with an expiration while `from` bears it permanently, cloning makes the
+/// role of `to` permanent as well.
 #[tokio::test]
-async fn test_acl_revoke_role_unchecked() -> anyhow::Result<()> {
+async fn test_acl_clone_permissions_extends_expiration() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
-    let account = setup
-        .new_account_with_roles(&["ByMax2Increaser", "Resetter"])
-        .await?;
+    let role = "ByMax2Increaser";
+    let from = setup.new_account_with_roles(&[role]).await?;
+    let to = setup.worker.dev_create_account().await?;
+    let super_admin = setup.new_super_admin_account().await?;
 
-    setup
+    let validity = sdk_duration_from_secs(5);
+    let expires_at = block_timestamp(&setup.worker).await + validity;
+    let granted = setup
         .contract
-        .assert_acl_has_role(true, "ByMax2Increaser", account.id())
-        .await;
-    setup
-        .contract
-        .assert_acl_has_role(true, "Resetter", account.id())
-        .await;
+        .acl_grant_role_until(&super_admin, role, to.id(), expires_at)
+        .await?;
+    assert_eq!(granted, Some(true));
 
-    // Revoke one of the roles.
+    // `to` already bears the role, hence it is not counted as newly granted.
     let res = setup
         .contract
-        .acl_revoke_role_unchecked(setup.contract_account(), "ByMax2Increaser", account.id())
+        .acl_clone_permissions(&super_admin, from.id(), to.id())
         .await?;
-    assert_success_with(res, true);
+    assert_success_with(res, 0);
+
+    // The role of `to` no longer expires.
+    fast_forward_beyond(&setup.worker, validity).await;
     setup
         .contract
-        .assert_acl_has_role(false, "ByMax2Increaser", account.id())
+        .assert_acl_has_role(true, role, to.id())
         .await;
     setup
         .contract
-        .assert_acl_has_role(true, "Resetter", account.id())
+        .assert_acl_has_role(true, role, from.id())
         .await;
 
-    // Revoke the other role too.
-    let res = setup
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_until() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
+
+    // An account which isn't admin can't grant the role.
+    let validity = sdk_duration_from_secs(5);
+    let expires_at = block_timestamp(&setup.worker).await + validity;
+    let granted = setup
         .contract
-        .acl_revoke_role_unchecked(setup.contract_account(), "Resetter", account.id())
+        .acl_grant_role_until(&setup.account, role, grantee.id(), expires_at)
         .await?;
-    assert_success_with(res, true);
+    assert_eq!(granted, None);
+
+    // Admin can grant the role with an expiration.
+    let granted = setup
+        .contract
+        .acl_grant_role_until(&admin, role, grantee.id(), expires_at)
+        .await?;
+    assert_eq!(granted, Some(true));
     setup
         .contract
-        .assert_acl_has_role(false, "ByMax2Increaser", account.id())
+        .assert_acl_has_role(true, role, grantee.id())
         .await;
+    let res = call_increase_2(raw_contract, &grantee).await?;
+    assert_success_with(res, 2);
+
+    // After the expiration the account is no longer considered a grantee.
+    fast_forward_beyond(&setup.worker, validity).await;
     setup
         .contract
-        .assert_acl_has_role(false, "Resetter", account.id())
+        .assert_acl_has_role(false, role, grantee.id())
         .await;
+    let res = call_increase_2(raw_contract, &grantee).await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "increase_2",
+        vec!["ByMax2Increaser".to_string(), "ByMax3Increaser".to_string()],
+    );
 
-    // Revoking behaves as expected if the role is not granted.
-    let res = setup
+    // Granting the role again without expiration makes it permanent.
+    let granted = setup
         .contract
-        .acl_revoke_role_unchecked(setup.contract_account(), "Resetter", account.id())
+        .acl_grant_role(&admin, role, grantee.id())
         .await?;
-    assert_success_with(res, false);
+    assert_eq!(granted, Some(true));
+    setup
+        .contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_attribute_access_control_any() -> anyhow::Result<()> {
+async fn test_acl_revoke_role_clears_expiration() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
-    let raw_contract = setup.contract.contract();
-    let method_name = "increase_2";
-    let allowed_roles = vec!["ByMax2Increaser".to_string(), "ByMax3Increaser".to_string()];
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
 
-    // Account without any of the required permissions is restricted.
-    let account = setup.new_account_with_roles(&[]).await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
-    let account = setup.new_account_with_roles(&["Resetter"]).await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+    let validity = sdk_duration_from_secs(5);
+    let expires_at = block_timestamp(&setup.worker).await + validity;
+    let granted = setup
+        .contract
+        .acl_grant_role_until(&admin, role, grantee.id(), expires_at)
+        .await?;
+    assert_eq!(granted, Some(true));
 
-    // A super-admin which has not been granted the role is restricted.
-    let super_admin = setup.new_super_admin_account().await?;
-    let res = call_increase_2(raw_contract, &super_admin).await?;
-    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+    let revoked = setup
+        .contract
+        .acl_revoke_role(&admin, role, grantee.id())
+        .await?;
+    assert_eq!(revoked, Some(true));
 
-    // An admin for a permitted role is restricted (no grantee of role itself).
-    let admin = setup.new_account_as_admin(&["ByMax2Increaser"]).await?;
-    let res = call_increase_2(raw_contract, &admin).await?;
-    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+    // A role granted after revocation is not affected by the previous expiration.
+    let granted = setup
+        .contract
+        .acl_grant_role(&admin, role, grantee.id())
+        .await?;
+    assert_eq!(granted, Some(true));
+    fast_forward_beyond(&setup.worker, validity).await;
+    setup
+        .contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
 
-    // Account with one of the required permissions succeeds.
-    let account = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_success_with(res, 2);
-    let account = setup.new_account_with_roles(&["ByMax3Increaser"]).await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_success_with(res, 4);
-    let account = setup
-        .new_account_with_roles(&["ByMax2Increaser", "Resetter"])
+    Ok(())
+}
+
+/// Expired grants are revoked by `acl_prune_expired_grants`, which may be called by any account.
+#[tokio::test]
+async fn test_acl_prune_expired_grants() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let expiring = setup.worker.dev_create_account().await?;
+    let permanent = setup.new_account_with_roles(&[role]).await?;
+
+    let validity = sdk_duration_from_secs(5);
+    let expires_at = block_timestamp(&setup.worker).await + validity;
+    let granted = setup
+        .contract
+        .acl_grant_role_until(&admin, role, expiring.id(), expires_at)
         .await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_success_with(res, 6);
+    assert_eq!(granted, Some(true));
 
-    // Account with both permissions succeeds.
-    let account = setup
-        .new_account_with_roles(&["ByMax2Increaser", "ByMax3Increaser"])
+    // Grants which haven't expired are not pruned.
+    let pruned = setup
+        .contract
+        .acl_prune_expired_grants(&setup.account, role, &[expiring.id(), permanent.id()])
         .await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_success_with(res, 8);
-    let account = setup
-        .new_account_with_roles(&["ByMax2Increaser", "ByMax3Increaser", "Resetter"])
+    assert_eq!(pruned, 0);
+
+    // The expired grantee is still listed until it is pruned.
+    fast_forward_beyond(&setup.worker, validity).await;
+    assert_eq!(
+        setup
+            .contract
+            .acl_get_grantees_count(&setup.account, role)
+            .await?,
+        2
+    );
+    let pruned = setup
+        .contract
+        .acl_prune_expired_grants(&setup.account, role, &[expiring.id(), permanent.id()])
         .await?;
-    let res = call_increase_2(raw_contract, &account).await?;
-    assert_success_with(res, 10);
+    assert_eq!(pruned, 1);
+    let grantees = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 0, 10)
+        .await?;
+    assert_eq!(grantees, vec![permanent.id().clone()]);
 
     Ok(())
 }
 
+/// Without `expiring_roles`, roles can't be granted with an expiration.
 #[tokio::test]
-async fn test_acl_init_super_admin_is_private() -> anyhow::Result<()> {
+async fn test_acl_grant_role_until_disabled() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_ADMINS_MANAGE_ADMINS),
+        "access_controllable_admins_manage_admins",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let grantee = worker.dev_create_account().await?;
+
+    let expires_at = block_timestamp(&worker).await + sdk_duration_from_secs(60);
+    let res = super_admin
+        .call(contract.id(), "acl_grant_role_until")
+        .args_json(json!({
+            "role": "Increaser",
+            "account_id": grantee.id(),
+            "expires_at": expires_at.to_string(),
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(
+        res,
+        "ACL: Expiring roles are disabled, pass `expiring_roles` to `access_control`",
+    );
+
+    Ok(())
+}
+
+/// A grant that would be expired right away is rejected.
+#[tokio::test]
+async fn test_acl_grant_role_until_past_expiration() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
+
+    let expires_at = block_timestamp(&setup.worker).await;
+    let res = admin
+        .call(setup.contract.contract().id(), "acl_grant_role_until")
+        .args_json(json!({
+            "role": role,
+            "account_id": grantee.id(),
+            "expires_at": expires_at.to_string(),
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(
+        res,
+        "ACL006: ACL: expires_at must be later than the current block timestamp",
+    );
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_unchecked() -> anyhow::Result<()> {
     let Setup {
         contract, account, ..
     } = Setup::new().await?;
+    let contract_account = contract.contract().as_account();
+    let role = "ByMax2Increaser";
+
+    contract
+        .assert_acl_has_role(false, role, account.id())
+        .await;
     let res = contract
-        .acl_init_super_admin(&account, account.id())
+        .acl_grant_role_unchecked(contract_account, role, account.id())
         .await?;
-    assert_private_method_failure(res, "acl_init_super_admin");
+    assert_success_with(res, true);
+    contract.assert_acl_has_role(true, role, account.id()).await;
+
+    // Granting a role again behaves as expected.
+    let res = contract
+        .acl_grant_role_unchecked(contract_account, role, account.id())
+        .await?;
+    assert_success_with(res, false);
+
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_get_super_admins() -> anyhow::Result<()> {
+async fn test_acl_revoke_role() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
+    let role = "ByMax3Increaser";
+    let grantee = setup.new_account_with_roles(&[role]).await?;
 
-    let super_admin_ids = vec![
-        setup.new_super_admin_account().await?,
-        setup.new_super_admin_account().await?,
-        setup.new_super_admin_account().await?,
-    ]
-    .iter()
-    .map(|account| account.id().clone())
-    .collect::<Vec<_>>();
-
-    // Behaves as expected for limit = 0.
-    let actual = setup
+    setup
         .contract
-        .acl_get_super_admins(&setup.account, 0, 0)
-        .await?;
-    assert!(actual.is_empty());
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
 
-    // Skip outside of the number of existing super-admins.
-    let n = u64::try_from(super_admin_ids.len()).unwrap();
-    let actual = setup
+    // Revoke is a no-op if revoker is not an admin for the role.
+    let revoker = setup.new_account_as_admin(&[]).await?;
+    let res = setup
         .contract
-        .acl_get_super_admins(&setup.account, n, 1)
+        .acl_revoke_role(&revoker, role, grantee.id())
         .await?;
-    assert!(actual.is_empty());
-
-    // Retrieve super-admins with step size 1.
-    for i in 0..3 {
-        let actual = setup
-            .contract
-            .acl_get_super_admins(&setup.account, i, 1)
-            .await?;
-        let i = usize::try_from(i).unwrap();
-        let expected = super_admin_ids[i..i + 1].to_vec();
-        assert_eq!(actual, expected, "Mismatch at position {}", i,);
-    }
-
-    // Retrieve super-admins with step size 2.
-    let actual = setup
+    assert_eq!(res, None);
+    let revoker = setup.new_account_as_admin(&["ByMax2Increaser"]).await?;
+    let res = setup
         .contract
-        .acl_get_super_admins(&setup.account, 0, 2)
+        .acl_revoke_role(&revoker, role, grantee.id())
         .await?;
-    let expected = super_admin_ids[0..2].to_vec();
-    assert_eq!(actual, expected);
-    let actual = setup
+    assert_eq!(res, None);
+    setup
         .contract
-        .acl_get_super_admins(&setup.account, 2, 2)
-        .await?;
-    let expected = vec![super_admin_ids[2].clone()];
-    assert_eq!(actual, expected);
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
 
-    // Retrieve all super-admins at once.
-    let actual = setup
+    // Revoke succeeds if the revoker is an admin for the role.
+    let revoker = setup.new_account_as_admin(&[role]).await?;
+    let res = setup
         .contract
-        .acl_get_super_admins(&setup.account, 0, 3)
+        .acl_revoke_role(&revoker, role, grantee.id())
         .await?;
-    assert_eq!(actual, super_admin_ids);
+    assert_eq!(res, Some(true));
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
 
-    // Limit larger than the number of existing super-admins.
-    let actual = setup
+    // Revoking a role that isn't granted returns `Some(false)`.
+    let revoker = setup.new_account_as_admin(&[role]).await?;
+    let account = setup.worker.dev_create_account().await?;
+    let res = setup
         .contract
-        .acl_get_super_admins(&setup.account, 0, 4)
+        .acl_revoke_role(&revoker, role, account.id())
         .await?;
-    assert_eq!(actual, super_admin_ids);
+    assert_eq!(res, Some(false));
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_get_admins() -> anyhow::Result<()> {
+async fn test_acl_renounce_role() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
-    let role = "ByMax3Increaser";
-
-    let admin_ids = vec![
-        setup.new_account_as_admin(&[role]).await?,
-        setup.new_account_as_admin(&[role]).await?,
-        setup.new_account_as_admin(&[role]).await?,
-    ]
-    .iter()
-    .map(|account| account.id().clone())
-    .collect::<Vec<_>>();
+    let role = "Resetter";
 
-    // Behaves as expected for limit = 0.
-    let actual = setup
+    // An account which is isn't grantee calls `acl_renounce_role`.
+    let res = setup
         .contract
-        .acl_get_admins(&setup.account, role, 0, 0)
+        .acl_renounce_role(&setup.account, role)
         .await?;
-    assert!(actual.is_empty());
+    assert!(!res);
 
-    // Skip outside of the number of existing admins.
-    let n = u64::try_from(admin_ids.len()).unwrap();
-    let actual = setup
+    // A grantee calls `acl_renounce_admin`.
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    setup
         .contract
-        .acl_get_admins(&setup.account, role, n, 1)
-        .await?;
-    assert!(actual.is_empty());
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
+    let res = setup.contract.acl_renounce_role(&grantee, role).await?;
+    assert!(res);
+    setup
+        .contract
+        .assert_acl_has_role(false, role, grantee.id())
+        .await;
 
-    // Retrieve admins with step size 1.
-    for i in 0..3 {
-        let actual = setup
-            .contract
-            .acl_get_admins(&setup.account, role, i, 1)
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_to_self() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "Resetter";
+    let contract_id = setup.contract_account().id().clone();
+
+    // Only the contract itself may call the methods.
+    for method in ["acl_grant_role_to_self", "acl_revoke_role_from_self"] {
+        let res = setup
+            .account
+            .call(setup.contract.contract().id(), method)
+            .args_json(json!({ "role": role }))
+            .max_gas()
+            .transact()
             .await?;
-        let i = usize::try_from(i).unwrap();
-        let expected = admin_ids[i..i + 1].to_vec();
-        assert_eq!(actual, expected, "Mismatch at position {}", i,);
+        assert_private_method_failure(res, method);
     }
 
-    // Retrieve admins with step size 2.
-    let actual = setup
+    let res = setup
         .contract
-        .acl_get_admins(&setup.account, role, 0, 2)
+        .acl_grant_role_to_self(setup.contract_account(), role)
         .await?;
-    let expected = admin_ids[0..2].to_vec();
-    assert_eq!(actual, expected);
-    let actual = setup
+    assert_success_with(res, true);
+    setup
         .contract
-        .acl_get_admins(&setup.account, role, 2, 2)
-        .await?;
-    let expected = vec![admin_ids[2].clone()];
-    assert_eq!(actual, expected);
-
-    // Retrieve all admins at once.
-    let actual = setup
+        .assert_acl_has_role(true, role, &contract_id)
+        .await;
+    let res = setup
         .contract
-        .acl_get_admins(&setup.account, role, 0, 3)
+        .acl_revoke_role_from_self(setup.contract_account(), role)
         .await?;
-    assert_eq!(actual, admin_ids);
-
-    // Limit larger than the number of existing admins.
-    let actual = setup
+    assert_success_with(res, true);
+    setup
         .contract
-        .acl_get_admins(&setup.account, role, 0, 4)
+        .assert_acl_has_role(false, role, &contract_id)
+        .await;
+
+    // A private method temporarily grants a role to the contract to call a guarded method.
+    let res = setup
+        .contract_account()
+        .call(setup.contract.contract().id(), "increase_3_as_self")
+        .max_gas()
+        .transact()
         .await?;
-    assert_eq!(actual, admin_ids);
+    assert_success_with(res, 3);
+    setup
+        .contract
+        .assert_acl_has_role(false, "ByMax3Increaser", &contract_id)
+        .await;
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_get_grantees() -> anyhow::Result<()> {
+async fn test_acl_revoke_role_unchecked() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
-    let role = "ByMax2Increaser";
-
-    let grantee_ids = vec![
-        setup.new_account_with_roles(&[role]).await?,
-        setup.new_account_with_roles(&[role]).await?,
-        setup.new_account_with_roles(&[role]).await?,
-    ]
-    .iter()
-    .map(|account| account.id().clone())
-    .collect::<Vec<_>>();
-
-    // Behaves as expected for limit = 0.
-    let actual = setup
-        .contract
-        .acl_get_grantees(&setup.account, role, 0, 0)
+    let account = setup
+        .new_account_with_roles(&["ByMax2Increaser", "Resetter"])
         .await?;
-    assert!(actual.is_empty());
 
-    // Skip outside of the number of existing grantees.
-    let n = u64::try_from(grantee_ids.len()).unwrap();
-    let actual = setup
+    setup
         .contract
-        .acl_get_grantees(&setup.account, role, n, 1)
-        .await?;
-    assert!(actual.is_empty());
-
-    // Retrieve grantees with step size 1.
-    for i in 0..3 {
-        let actual = setup
-            .contract
-            .acl_get_grantees(&setup.account, role, i, 1)
-            .await?;
-        let i = usize::try_from(i).unwrap();
-        let expected = grantee_ids[i..i + 1].to_vec();
-        assert_eq!(actual, expected, "Mismatch at position {}", i,);
-    }
+        .assert_acl_has_role(true, "ByMax2Increaser", account.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(true, "Resetter", account.id())
+        .await;
 
-    // Retrieve grantees with step size 2.
-    let actual = setup
+    // Revoke one of the roles.
+    let res = setup
         .contract
-        .acl_get_grantees(&setup.account, role, 0, 2)
+        .acl_revoke_role_unchecked(setup.contract_account(), "ByMax2Increaser", account.id())
         .await?;
-    let expected = grantee_ids[0..2].to_vec();
-    assert_eq!(actual, expected);
-    let actual = setup
+    assert_success_with(res, true);
+    setup
         .contract
-        .acl_get_grantees(&setup.account, role, 2, 2)
-        .await?;
-    let expected = vec![grantee_ids[2].clone()];
-    assert_eq!(actual, expected);
+        .assert_acl_has_role(false, "ByMax2Increaser", account.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(true, "Resetter", account.id())
+        .await;
 
-    // Retrieve all grantees at once.
-    let actual = setup
+    // Revoke the other role too.
+    let res = setup
         .contract
-        .acl_get_grantees(&setup.account, role, 0, 3)
+        .acl_revoke_role_unchecked(setup.contract_account(), "Resetter", account.id())
         .await?;
-    assert_eq!(actual, grantee_ids);
+    assert_success_with(res, true);
+    setup
+        .contract
+        .assert_acl_has_role(false, "ByMax2Increaser", account.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(false, "Resetter", account.id())
+        .await;
 
-    // Limit larger than the number of existing grantees.
-    let actual = setup
+    // Revoking behaves as expected if the role is not granted.
+    let res = setup
         .contract
-        .acl_get_grantees(&setup.account, role, 0, 4)
+        .acl_revoke_role_unchecked(setup.contract_account(), "Resetter", account.id())
         .await?;
-    assert_eq!(actual, grantee_ids);
+    assert_success_with(res, false);
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_get_permissioned_accounts() -> anyhow::Result<()> {
+async fn test_attribute_access_control_any() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let method_name = "increase_2";
+    let allowed_roles = vec!["ByMax2Increaser".to_string(), "ByMax3Increaser".to_string()];
 
-    // Verify returned `PermissionedAccounts` are empty when there are no outstanding permissions.
-    let permissioned_accounts = setup
-        .contract
-        .acl_get_permissioned_accounts(&setup.account)
+    // Account without any of the required permissions is restricted.
+    let account = setup.new_account_with_roles(&[]).await?;
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+    let account = setup.new_account_with_roles(&["Resetter"]).await?;
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+
+    // A super-admin which has not been granted the role is restricted.
+    let super_admin = setup.new_super_admin_account().await?;
+    let res = call_increase_2(raw_contract, &super_admin).await?;
+    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+
+    // An admin for a permitted role is restricted (no grantee of role itself).
+    let admin = setup.new_account_as_admin(&["ByMax2Increaser"]).await?;
+    let res = call_increase_2(raw_contract, &admin).await?;
+    assert_insufficient_acl_permissions(res, method_name, allowed_roles.clone());
+
+    // Account with one of the required permissions succeeds.
+    let account = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_success_with(res, 2);
+    let account = setup.new_account_with_roles(&["ByMax3Increaser"]).await?;
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_success_with(res, 4);
+    let account = setup
+        .new_account_with_roles(&["ByMax2Increaser", "Resetter"])
         .await?;
-    let mut expected = new_permissioned_accounts();
-    assert_permissioned_account_equivalence(&permissioned_accounts, &expected);
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_success_with(res, 6);
 
-    // Add a super admin to the contract's Acl.
-    let super_admin = setup.worker.dev_create_account().await?;
-    let res = setup
-        .contract
-        .acl_init_super_admin(setup.contract_account(), super_admin.id())
+    // Account with both permissions succeeds.
+    let account = setup
+        .new_account_with_roles(&["ByMax2Increaser", "ByMax3Increaser"])
         .await?;
-    assert_success_with(res, true);
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_success_with(res, 8);
+    let account = setup
+        .new_account_with_roles(&["ByMax2Increaser", "ByMax3Increaser", "Resetter"])
+        .await?;
+    let res = call_increase_2(raw_contract, &account).await?;
+    assert_success_with(res, 10);
 
-    // Add admins and grantees to the contract's Acl.
-    let admin_0 = setup.new_account_as_admin(&[ALL_ROLES[0]]).await?;
-    let admin_2 = setup.new_account_as_admin(&[ALL_ROLES[2]]).await?;
-    let grantee_1_a = setup.new_account_with_roles(&[ALL_ROLES[1]]).await?;
-    let grantee_1_b = setup.new_account_with_roles(&[ALL_ROLES[1]]).await?;
+    Ok(())
+}
 
-    // Insert ids added to contract's Acl into `expected`.
-    expected
-        .super_admins
-        .push(as_sdk_account_id(super_admin.id()));
-    expected
-        .roles
-        .get_mut(ALL_ROLES[0])
-        .unwrap()
-        .admins
-        .push(as_sdk_account_id(admin_0.id()));
-    expected
-        .roles
-        .get_mut(ALL_ROLES[1])
+#[tokio::test]
+async fn test_attribute_access_control_any_allow_super_admin() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let allowed_roles = vec!["ByMax3Increaser".to_string()];
+    let super_admin = setup.new_super_admin_account().await?;
+
+    // Without `allow_super_admin`, a super-admin is restricted.
+    let res = super_admin
+        .call(raw_contract.id(), "increase_3")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_insufficient_acl_permissions(res, "increase_3", allowed_roles.clone());
+
+    // With `allow_super_admin`, a super-admin succeeds.
+    let res = super_admin
+        .call(raw_contract.id(), "increase_4")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 4);
+
+    // Grantees of the role succeed as well.
+    let account = setup.new_account_with_roles(&["ByMax3Increaser"]).await?;
+    let res = account
+        .call(raw_contract.id(), "increase_4")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 8);
+
+    // Other accounts, including admins, are restricted.
+    let admin = setup.new_account_as_admin(&["ByMax3Increaser"]).await?;
+    let other_grantee = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
+    for account in [&admin, &other_grantee] {
+        let res = account
+            .call(raw_contract.id(), "increase_4")
+            .max_gas()
+            .transact()
+            .await?;
+        assert_insufficient_acl_permissions(res, "increase_4", allowed_roles.clone());
+    }
+
+    Ok(())
+}
+
+/// Asserts calling `increase_5` failed and `missing_roles` are exactly the roles reported missing.
+fn assert_access_control_all_failure(res: ExecutionFinalResult, missing_roles: &[&str]) {
+    let err = format!(
+        "{}",
+        res.into_result()
+            .expect_err("Transaction should have failed")
+    );
+    let must_contain =
+        "Insufficient permissions for method increase_5 restricted by access control.";
+    assert!(err.contains(must_contain), "Unexpected error: {}", err);
+    let (_, missing) = err
+        .split_once("missing:")
+        .expect("Missing roles should be listed");
+    for role in ["ByMax2Increaser", "ByMax3Increaser"] {
+        assert_eq!(
+            missing.contains(role),
+            missing_roles.contains(&role),
+            "Unexpected missing roles: {}",
+            missing,
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_attribute_access_control_all() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let call_increase_5 = |caller: Account| async move {
+        caller
+            .call(raw_contract.id(), "increase_5")
+            .max_gas()
+            .transact()
+            .await
+    };
+
+    // Account without any of the required roles is restricted.
+    let account = setup.new_account_with_roles(&["Resetter"]).await?;
+    let res = call_increase_5(account).await?;
+    assert_access_control_all_failure(res, &["ByMax2Increaser", "ByMax3Increaser"]);
+
+    // Account with only one of the required roles is restricted.
+    let account = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
+    let res = call_increase_5(account).await?;
+    assert_access_control_all_failure(res, &["ByMax3Increaser"]);
+
+    // A super-admin which has not been granted the roles is restricted.
+    let super_admin = setup.new_super_admin_account().await?;
+    let res = call_increase_5(super_admin).await?;
+    assert_access_control_all_failure(res, &["ByMax2Increaser", "ByMax3Increaser"]);
+
+    // Account with both roles succeeds.
+    let account = setup
+        .new_account_with_roles(&["ByMax2Increaser", "ByMax3Increaser"])
+        .await?;
+    let res = call_increase_5(account).await?;
+    assert_success_with(res, 5);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_init_super_admin_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_init_super_admin(&account, account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_init_super_admin");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_super_admins() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    let super_admin_ids = vec![
+        setup.new_super_admin_account().await?,
+        setup.new_super_admin_account().await?,
+        setup.new_super_admin_account().await?,
+    ]
+    .iter()
+    .map(|account| account.id().clone())
+    .collect::<Vec<_>>();
+
+    // Behaves as expected for limit = 0.
+    let actual = setup
+        .contract
+        .acl_get_super_admins(&setup.account, 0, 0)
+        .await?;
+    assert!(actual.is_empty());
+
+    // Skip outside of the number of existing super-admins.
+    let n = u64::try_from(super_admin_ids.len()).unwrap();
+    let actual = setup
+        .contract
+        .acl_get_super_admins(&setup.account, n, 1)
+        .await?;
+    assert!(actual.is_empty());
+
+    // Retrieve super-admins with step size 1.
+    for i in 0..3 {
+        let actual = setup
+            .contract
+            .acl_get_super_admins(&setup.account, i, 1)
+            .await?;
+        let i = usize::try_from(i).unwrap();
+        let expected = super_admin_ids[i..i + 1].to_vec();
+        assert_eq!(actual, expected, "Mismatch at position {}", i,);
+    }
+
+    // Retrieve super-admins with step size 2.
+    let actual = setup
+        .contract
+        .acl_get_super_admins(&setup.account, 0, 2)
+        .await?;
+    let expected = super_admin_ids[0..2].to_vec();
+    assert_eq!(actual, expected);
+    let actual = setup
+        .contract
+        .acl_get_super_admins(&setup.account, 2, 2)
+        .await?;
+    let expected = vec![super_admin_ids[2].clone()];
+    assert_eq!(actual, expected);
+
+    // Retrieve all super-admins at once.
+    let actual = setup
+        .contract
+        .acl_get_super_admins(&setup.account, 0, 3)
+        .await?;
+    assert_eq!(actual, super_admin_ids);
+
+    // Limit larger than the number of existing super-admins.
+    let actual = setup
+        .contract
+        .acl_get_super_admins(&setup.account, 0, 4)
+        .await?;
+    assert_eq!(actual, super_admin_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_super_admins_sorted() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    // Dev accounts have random ids, so super-admins are added in arbitrary order.
+    for _ in 0..4 {
+        setup.new_super_admin_account().await?;
+    }
+    let unsorted = setup
+        .contract
+        .acl_get_super_admins(&setup.account, 0, 10)
+        .await?;
+    assert_eq!(unsorted.len(), 4);
+
+    let mut expected = unsorted.clone();
+    expected.sort();
+    let actual = setup
+        .contract
+        .acl_get_super_admins_sorted(&setup.account)
+        .await?;
+    assert_eq!(actual, expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_admins() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax3Increaser";
+
+    let admin_ids = vec![
+        setup.new_account_as_admin(&[role]).await?,
+        setup.new_account_as_admin(&[role]).await?,
+        setup.new_account_as_admin(&[role]).await?,
+    ]
+    .iter()
+    .map(|account| account.id().clone())
+    .collect::<Vec<_>>();
+
+    // Behaves as expected for limit = 0.
+    let actual = setup
+        .contract
+        .acl_get_admins(&setup.account, role, 0, 0)
+        .await?;
+    assert!(actual.is_empty());
+
+    // Skip outside of the number of existing admins.
+    let n = u64::try_from(admin_ids.len()).unwrap();
+    let actual = setup
+        .contract
+        .acl_get_admins(&setup.account, role, n, 1)
+        .await?;
+    assert!(actual.is_empty());
+
+    // Retrieve admins with step size 1.
+    for i in 0..3 {
+        let actual = setup
+            .contract
+            .acl_get_admins(&setup.account, role, i, 1)
+            .await?;
+        let i = usize::try_from(i).unwrap();
+        let expected = admin_ids[i..i + 1].to_vec();
+        assert_eq!(actual, expected, "Mismatch at position {}", i,);
+    }
+
+    // Retrieve admins with step size 2.
+    let actual = setup
+        .contract
+        .acl_get_admins(&setup.account, role, 0, 2)
+        .await?;
+    let expected = admin_ids[0..2].to_vec();
+    assert_eq!(actual, expected);
+    let actual = setup
+        .contract
+        .acl_get_admins(&setup.account, role, 2, 2)
+        .await?;
+    let expected = vec![admin_ids[2].clone()];
+    assert_eq!(actual, expected);
+
+    // Retrieve all admins at once.
+    let actual = setup
+        .contract
+        .acl_get_admins(&setup.account, role, 0, 3)
+        .await?;
+    assert_eq!(actual, admin_ids);
+
+    // Limit larger than the number of existing admins.
+    let actual = setup
+        .contract
+        .acl_get_admins(&setup.account, role, 0, 4)
+        .await?;
+    assert_eq!(actual, admin_ids);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_grantees() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+
+    let grantee_ids = vec![
+        setup.new_account_with_roles(&[role]).await?,
+        setup.new_account_with_roles(&[role]).await?,
+        setup.new_account_with_roles(&[role]).await?,
+    ]
+    .iter()
+    .map(|account| account.id().clone())
+    .collect::<Vec<_>>();
+
+    // Behaves as expected for limit = 0.
+    let actual = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 0, 0)
+        .await?;
+    assert!(actual.is_empty());
+
+    // Skip outside of the number of existing grantees.
+    let n = u64::try_from(grantee_ids.len()).unwrap();
+    let actual = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, n, 1)
+        .await?;
+    assert!(actual.is_empty());
+
+    // Retrieve grantees with step size 1.
+    for i in 0..3 {
+        let actual = setup
+            .contract
+            .acl_get_grantees(&setup.account, role, i, 1)
+            .await?;
+        let i = usize::try_from(i).unwrap();
+        let expected = grantee_ids[i..i + 1].to_vec();
+        assert_eq!(actual, expected, "Mismatch at position {}", i,);
+    }
+
+    // Retrieve grantees with step size 2.
+    let actual = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 0, 2)
+        .await?;
+    let expected = grantee_ids[0..2].to_vec();
+    assert_eq!(actual, expected);
+    let actual = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 2, 2)
+        .await?;
+    let expected = vec![grantee_ids[2].clone()];
+    assert_eq!(actual, expected);
+
+    // Retrieve all grantees at once.
+    let actual = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 0, 3)
+        .await?;
+    assert_eq!(actual, grantee_ids);
+
+    // Limit larger than the number of existing grantees.
+    let actual = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 0, 4)
+        .await?;
+    assert_eq!(actual, grantee_ids);
+
+    Ok(())
+}
+
+/// Revoking a grantee in the middle preserves the order of the remaining grantees, so paging
+/// yields each of them exactly once.
+#[tokio::test]
+async fn test_acl_get_grantees_after_revoke() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+
+    let mut grantee_ids = vec![];
+    for _ in 0..4 {
+        let account = setup.new_account_with_roles(&[role]).await?;
+        grantee_ids.push(account.id().clone());
+    }
+    let revoked = grantee_ids.remove(1);
+    let res = setup
+        .contract
+        .acl_revoke_role(setup.contract_account(), role, &revoked)
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // Unsorted pagination keeps the order in which roles were granted.
+    let mut paged = vec![];
+    for i in 0..4 {
+        paged.extend(
+            setup
+                .contract
+                .acl_get_grantees(&setup.account, role, i, 1)
+                .await?,
+        );
+    }
+    assert_eq!(paged, grantee_ids);
+
+    // Sorted pagination yields account ids in lexicographic order.
+    let mut paged = vec![];
+    for i in 0..2 {
+        paged.extend(
+            setup
+                .contract
+                .acl_get_grantees_sorted(&setup.account, role, i * 2, 2)
+                .await?,
+        );
+    }
+    let mut expected = grantee_ids.clone();
+    expected.sort();
+    assert_eq!(paged, expected);
+
+    Ok(())
+}
+
+/// Walks all grantees by passing the returned cursor to the next call, while a grantee that was
+/// already retrieved is revoked.
+#[tokio::test]
+async fn test_acl_get_grantees_page() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+
+    let mut grantee_ids = vec![];
+    for _ in 0..5 {
+        let account = setup.new_account_with_roles(&[role]).await?;
+        grantee_ids.push(as_sdk_account_id(account.id()));
+    }
+    grantee_ids.sort();
+
+    let mut retrieved = vec![];
+    let mut from: Option<near_sdk::AccountId> = None;
+    let mut num_pages = 0;
+    loop {
+        let page = setup
+            .contract
+            .acl_get_grantees_page(&setup.account, role, from.as_ref(), 2)
+            .await?;
+        num_pages += 1;
+        if num_pages == 1 {
+            // Revoking an account that was already retrieved doesn't affect the next pages.
+            let res = setup
+                .contract
+                .acl_revoke_role(
+                    setup.contract_account(),
+                    role,
+                    &page.accounts[0].as_str().parse::<AccountId>()?,
+                )
+                .await?;
+            assert_eq!(res, Some(true));
+        }
+        retrieved.extend(page.accounts);
+        match page.next {
+            Some(next) => from = Some(next),
+            None => break,
+        }
+    }
+
+    assert_eq!(num_pages, 3);
+    assert_eq!(retrieved, grantee_ids);
+
+    // A role without grantees yields an empty page.
+    let page = setup
+        .contract
+        .acl_get_grantees_page(&setup.account, "Resetter", None, 2)
+        .await?;
+    assert!(page.accounts.is_empty());
+    assert_eq!(page.next, None);
+
+    Ok(())
+}
+
+/// Walks a contract with hundreds of permissioned accounts to completion by passing the returned
+/// cursor to the next call.
+#[tokio::test]
+async fn test_acl_get_permissioned_accounts_chunk() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let caller = &setup.account;
+
+    // Without permissioned accounts a single empty chunk is returned.
+    let chunk = contract
+        .acl_get_permissioned_accounts_chunk(caller, None, 64)
+        .await?;
+    assert!(chunk.accounts.is_empty());
+    assert_eq!(chunk.next_cursor, None);
+
+    let mut expected = HashSet::new();
+    contract
+        .acl_add_super_admin_unchecked(setup.contract_account(), caller.id())
+        .await?
+        .into_result()?;
+    expected.insert((
+        as_sdk_account_id(caller.id()),
+        AclPermissionKind::SuperAdmin,
+        None,
+    ));
+    contract
+        .acl_add_admin_unchecked(setup.contract_account(), "Resetter", caller.id())
+        .await?
+        .into_result()?;
+    expected.insert((
+        as_sdk_account_id(caller.id()),
+        AclPermissionKind::Admin,
+        Some("Resetter".to_string()),
+    ));
+
+    // `ByMax3Increaser` is left without grantees, so chunks span empty sets of bearers.
+    for role in ["ByMax2Increaser", "Resetter"] {
+        let account_ids = (0..150)
+            .map(|i| format!("{}-{}.test.near", role.to_lowercase(), i).parse())
+            .collect::<Result<Vec<AccountId>, _>>()?;
+        // Batches are kept small to stay within the limits on logs per receipt.
+        for batch in account_ids.chunks(50) {
+            contract
+                .acl_grant_role_batch_unchecked(setup.contract_account(), role, batch)
+                .await?
+                .into_result()?;
+        }
+        for account_id in account_ids.iter() {
+            expected.insert((
+                as_sdk_account_id(account_id),
+                AclPermissionKind::Grantee,
+                Some(role.to_string()),
+            ));
+        }
+    }
+    assert_eq!(expected.len(), 302);
+
+    let mut retrieved = vec![];
+    let mut cursor: Option<AclCursor> = None;
+    let mut num_chunks = 0;
+    loop {
+        let chunk = contract
+            .acl_get_permissioned_accounts_chunk(caller, cursor.as_ref(), 64)
+            .await?;
+        num_chunks += 1;
+        if num_chunks == 1 {
+            // The serialization of the cursor is part of the interface.
+            assert_eq!(
+                near_sdk::serde_json::to_value(&chunk.next_cursor)?,
+                json!({
+                    "role_index": 0,
+                    "kind": "Grantee",
+                    "from": "bymax2increaser-20.test.near",
+                }),
+            );
+
+            // Revoking an account that was already retrieved neither causes accounts to be
+            // skipped nor to be returned twice.
+            contract
+                .acl_revoke_role_unchecked(
+                    setup.contract_account(),
+                    "ByMax2Increaser",
+                    &"bymax2increaser-0.test.near".parse()?,
+                )
+                .await?
+                .into_result()?;
+        }
+        retrieved.extend(
+            chunk
+                .accounts
+                .into_iter()
+                .map(|account| (account.account_id, account.kind, account.role)),
+        );
+        match chunk.next_cursor {
+            Some(next_cursor) => {
+                assert_eq!(retrieved.len(), num_chunks * 64);
+                cursor = Some(next_cursor);
+            }
+            None => break,
+        }
+    }
+
+    assert_eq!(num_chunks, 5);
+    assert_eq!(retrieved.len(), expected.len());
+    assert_eq!(retrieved.into_iter().collect::<HashSet<_>>(), expected);
+
+    // Retrieval fails for chunks without accounts and cursors pointing outside the roles.
+    for (args, err) in [
+        (
+            json!({ "cursor": null, "max_accounts": 0 }),
+            "ACL009: ACL: max_accounts must be positive",
+        ),
+        (
+            json!({
+                "cursor": { "role_index": 3, "kind": "Admin", "from": null },
+                "max_accounts": 64,
+            }),
+            "ACL008: ACL: cursor does not refer to a set of permissioned accounts",
+        ),
+    ] {
+        let res = caller
+            .call(
+                contract.contract().id(),
+                "acl_get_permissioned_accounts_chunk",
+            )
+            .args_json(args)
+            .max_gas()
+            .transact()
+            .await?;
+        assert_failure_with(res, err);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_bearers_count() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.contract;
+    let account = &setup.account;
+    let role = ALL_ROLES[1];
+
+    // Counts are zero if there are no permissioned accounts.
+    assert_eq!(contract.acl_get_super_admins_count(account).await?, 0);
+    assert_eq!(contract.acl_get_admins_count(account, role).await?, 0);
+    assert_eq!(contract.acl_get_grantees_count(account, role).await?, 0);
+
+    for _ in 0..2 {
+        setup.new_super_admin_account().await?;
+    }
+    for _ in 0..3 {
+        setup.new_account_as_admin(&[role]).await?;
+    }
+    let mut grantees = vec![];
+    for _ in 0..4 {
+        grantees.push(setup.new_account_with_roles(&[role]).await?);
+    }
+    // Permissions for other roles do not affect the counts for `role`.
+    setup.new_account_as_admin(&[ALL_ROLES[0]]).await?;
+    setup.new_account_with_roles(&[ALL_ROLES[2]]).await?;
+
+    assert_eq!(contract.acl_get_super_admins_count(account).await?, 2);
+    assert_eq!(contract.acl_get_admins_count(account, role).await?, 3);
+    assert_eq!(contract.acl_get_grantees_count(account, role).await?, 4);
+
+    // Revoking a role decrements the count.
+    let res = contract
+        .acl_revoke_role_unchecked(setup.contract_account(), role, grantees[0].id())
+        .await?;
+    assert_success_with(res, true);
+    assert_eq!(contract.acl_get_grantees_count(account, role).await?, 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_permissioned_accounts() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    // Verify returned `PermissionedAccounts` are empty when there are no outstanding permissions.
+    let permissioned_accounts = setup
+        .contract
+        .acl_get_permissioned_accounts(&setup.account)
+        .await?;
+    let mut expected = new_permissioned_accounts();
+    assert_permissioned_account_equivalence(&permissioned_accounts, &expected);
+
+    // Add a super admin to the contract's Acl.
+    let super_admin = setup.worker.dev_create_account().await?;
+    let res = setup
+        .contract
+        .acl_init_super_admin(setup.contract_account(), super_admin.id())
+        .await?;
+    assert_success_with(res, true);
+
+    // Add admins and grantees to the contract's Acl.
+    let admin_0 = setup.new_account_as_admin(&[ALL_ROLES[0]]).await?;
+    let admin_2 = setup.new_account_as_admin(&[ALL_ROLES[2]]).await?;
+    let grantee_1_a = setup.new_account_with_roles(&[ALL_ROLES[1]]).await?;
+    let grantee_1_b = setup.new_account_with_roles(&[ALL_ROLES[1]]).await?;
+
+    // Insert ids added to contract's Acl into `expected`.
+    expected
+        .super_admins
+        .push(as_sdk_account_id(super_admin.id()));
+    expected
+        .roles
+        .get_mut(ALL_ROLES[0])
+        .unwrap()
+        .admins
+        .push(as_sdk_account_id(admin_0.id()));
+    expected
+        .roles
+        .get_mut(ALL_ROLES[1])
         .unwrap()
         .grantees
         .extend([
@@ -1437,86 +2550,1472 @@ async fn test_acl_get_permissioned_accounts() -> anyhow::Result<()> {
         .get_mut(ALL_ROLES[2])
         .unwrap()
         .admins
-        .push(as_sdk_account_id(admin_2.id()));
+        .push(as_sdk_account_id(admin_2.id()));
+
+    // Verify returned `PermissionedAccounts` when there are outstanding permissions.
+    let permissioned_accounts = setup
+        .contract
+        .acl_get_permissioned_accounts(&setup.account)
+        .await?;
+    assert_permissioned_account_equivalence(&permissioned_accounts, &expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_permissions_snapshot_hash() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let super_admin = setup.new_super_admin_account().await?;
+    setup.new_account_as_admin(&[ALL_ROLES[0]]).await?;
+    setup.new_account_with_roles(&[ALL_ROLES[1]]).await?;
+
+    // The hash is stable across calls and matches the hash of the returned accounts.
+    let hash = setup
+        .contract
+        .acl_permissions_snapshot_hash(&setup.account)
+        .await?;
+    assert_eq!(
+        hash,
+        setup
+            .contract
+            .acl_permissions_snapshot_hash(&setup.account)
+            .await?
+    );
+    let permissioned_accounts = setup
+        .contract
+        .acl_get_permissioned_accounts(&setup.account)
+        .await?;
+    assert_eq!(hash, permissioned_accounts.snapshot_hash());
+
+    // Granting a role changes the hash.
+    let grantee = setup.worker.dev_create_account().await?;
+    let res = setup
+        .contract
+        .acl_grant_role(&super_admin, ALL_ROLES[2], grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let new_hash = setup
+        .contract
+        .acl_permissions_snapshot_hash(&setup.account)
+        .await?;
+    assert_ne!(hash, new_hash);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_add_super_admin_unchecked_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_add_super_admin_unchecked(&account, account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_add_super_admin_unchecked");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_super_admin_unchecked_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_revoke_super_admin_unchecked(&account, account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_revoke_super_admin_unchecked");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_add_admin_unchecked_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_add_admin_unchecked(&account, "ByMax2Increaser", account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_add_admin_unchecked");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_admin_unchecked_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_revoke_admin_unchecked(&account, "ByMax2Increaser", account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_revoke_admin_unchecked");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_unchecked_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_grant_role_unchecked(&account, "ByMax2Increaser", account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_grant_role_unchecked");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_role_unchecked_is_private() -> anyhow::Result<()> {
+    let Setup {
+        contract, account, ..
+    } = Setup::new().await?;
+    let res = contract
+        .acl_revoke_role_unchecked(&account, "ByMax2Increaser", account.id())
+        .await?;
+    assert_private_method_failure(res, "acl_revoke_role_unchecked");
+    Ok(())
+}
+
+/// Verifies that granting, revoking and paginating roles works when the contract passes a custom
+/// `storage_prefix` to `#[access_control]`.
+#[tokio::test]
+async fn test_acl_custom_storage_prefix() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_STORAGE_PREFIX),
+        "access_controllable_storage_prefix",
+    )
+    .await?;
+    let contract = AccessControllableContract::new(worker.dev_deploy(&wasm).await?);
+    contract
+        .contract()
+        .call("new")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let contract_account = contract.contract().as_account();
+    let role = "Increaser";
+
+    let mut grantee_ids = vec![];
+    for _ in 0..3 {
+        let account = worker.dev_create_account().await?;
+        let granted = contract
+            .acl_grant_role(contract_account, role, account.id())
+            .await?;
+        assert_eq!(granted, Some(true));
+        grantee_ids.push(account.id().clone());
+    }
+
+    let actual = contract
+        .acl_get_grantees(contract_account, role, 0, 2)
+        .await?;
+    assert_eq!(actual, grantee_ids[0..2].to_vec());
+    let actual = contract
+        .acl_get_grantees(contract_account, role, 2, 2)
+        .await?;
+    assert_eq!(actual, grantee_ids[2..3].to_vec());
+
+    let revoked = contract
+        .acl_revoke_role(contract_account, role, &grantee_ids[0])
+        .await?;
+    assert_eq!(revoked, Some(true));
+    contract
+        .assert_acl_has_role(false, role, &grantee_ids[0])
+        .await;
+    let actual = contract
+        .acl_get_grantees(contract_account, role, 0, 3)
+        .await?;
+    assert_eq!(actual.len(), 2);
+
+    // State is stored only under the custom prefix.
+    let state = contract.contract().view_state().prefix(b"__acl").await?;
+    assert!(state.is_empty());
+    let state = contract.contract().view_state().prefix(b"custom").await?;
+    assert!(!state.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_owner_is_super_admin() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_OWNER), "access_controllable_owner")
+            .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let owner = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let acl_contract = AccessControllableContract::new(contract.clone());
+    let ownable_contract = OwnableContract::new(contract);
+    let role = "Increaser";
+
+    // The owner is super-admin without having been added explicitly.
+    acl_contract
+        .assert_acl_is_super_admin(true, &owner, owner.id())
+        .await;
+    acl_contract
+        .assert_acl_is_admin(true, role, owner.id())
+        .await;
+    let super_admins = acl_contract.acl_get_super_admins(&owner, 0, 10).await?;
+    assert!(super_admins.is_empty());
+
+    // Hence the owner may grant roles.
+    let grantee = worker.dev_create_account().await?;
+    let granted = acl_contract
+        .acl_grant_role(&owner, role, grantee.id())
+        .await?;
+    assert_eq!(granted, Some(true));
+    acl_contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
+
+    // Other accounts are not super-admin.
+    acl_contract
+        .assert_acl_is_super_admin(false, &owner, grantee.id())
+        .await;
+    let granted = acl_contract
+        .acl_grant_role(&grantee, role, owner.id())
+        .await?;
+    assert_eq!(granted, None);
+
+    // Super-admin permissions follow ownership.
+    ownable_contract
+        .owner_set(&owner, Some(grantee.id().clone()))
+        .await?
+        .into_result()?;
+    acl_contract
+        .assert_acl_is_super_admin(false, &owner, owner.id())
+        .await;
+    acl_contract
+        .assert_acl_is_super_admin(true, &owner, grantee.id())
+        .await;
+    let granted = acl_contract
+        .acl_grant_role(&owner, role, owner.id())
+        .await?;
+    assert_eq!(granted, None);
+
+    Ok(())
+}
+
+/// Returns the data of the `role_granted` events emitted in `res`.
+fn role_granted_events(res: &ExecutionFinalResult) -> Vec<near_sdk::serde_json::Value> {
+    res.logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .map(|log| near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(log).unwrap())
+        .filter(|e| e["standard"] == "AccessControllable" && e["event"] == "role_granted")
+        .map(|e| e["data"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_acl_grant_role_with_memo() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.worker.dev_create_account().await?;
+    let memo = "Approved by proposal #42";
+
+    // Only admins may grant the role.
+    let res = setup
+        .contract
+        .acl_grant_role_with_memo(&setup.account, role, grantee.id(), memo)
+        .await?;
+    assert_success_with(res, None::<bool>);
+
+    // The memo is included in the event.
+    let res = setup
+        .contract
+        .acl_grant_role_with_memo(&admin, role, grantee.id(), memo)
+        .await?;
+    assert_eq!(
+        role_granted_events(&res),
+        vec![json!({
+            "role": role,
+            "to": grantee.id(),
+            "by": admin.id(),
+            "memo": memo,
+        })],
+    );
+    assert_success_with(res, Some(true));
+    setup
+        .contract
+        .assert_acl_has_role(true, role, grantee.id())
+        .await;
+
+    // Without memo, the field is absent.
+    let other_grantee = setup.worker.dev_create_account().await?;
+    let res = setup
+        .contract
+        .acl_grant_role_unchecked(setup.contract_account(), role, other_grantee.id())
+        .await?;
+    let events = role_granted_events(&res);
+    assert_eq!(events.len(), 1);
+    assert!(events[0].get("memo").is_none());
+
+    Ok(())
+}
+
+/// Verifies `acl_init_super_admin` emits `SuperAdminAdded` when called in the constructor.
+#[tokio::test]
+async fn test_acl_init_super_admin_emits_event() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH), "access_controllable").await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let admin = worker.dev_create_account().await?;
+
+    // Passing an admin makes the constructor call `acl_init_super_admin`.
+    let res = contract
+        .call("new")
+        .args_json(json!({
+            "admins": HashMap::from([("Resetter".to_string(), admin.id().clone())]),
+            "grantees": HashMap::<String, AccountId>::new(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    let events = res
+        .logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .map(near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>)
+        .collect::<Result<Vec<_>, _>>()?;
+    let super_admin_added = events
+        .iter()
+        .filter(|event| event["event"] == "super_admin_added")
+        .collect::<Vec<_>>();
+    assert_eq!(
+        super_admin_added,
+        vec![&json!({
+            "standard": "AccessControllable",
+            "version": "1.0.0",
+            "event": "super_admin_added",
+            "data": {
+                "account": contract.id(),
+                "by": contract.id(),
+            },
+        })],
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_permissions_raw() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let account = setup.worker.dev_create_account().await?;
+
+    let raw = setup
+        .contract
+        .acl_get_permissions_raw(&account, account.id())
+        .await?;
+    assert_eq!(raw, 0);
+
+    // `Resetter` is the third variant, see the module documentation of `AccessControlRole` for
+    // the layout of bitflags.
+    let resetter_permission = 1u128 << 5;
+    let resetter_admin_permission = 1u128 << 6;
+
+    setup
+        .contract
+        .acl_grant_role_unchecked(setup.contract_account(), "Resetter", account.id())
+        .await?
+        .into_result()?;
+    let raw = setup
+        .contract
+        .acl_get_permissions_raw(&account, account.id())
+        .await?;
+    assert_eq!(raw, resetter_permission);
+
+    setup
+        .contract
+        .acl_add_admin_unchecked(setup.contract_account(), "Resetter", account.id())
+        .await?
+        .into_result()?;
+    let raw = setup
+        .contract
+        .acl_get_permissions_raw(&account, account.id())
+        .await?;
+    assert_eq!(raw, resetter_permission | resetter_admin_permission);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_revoke_role_preview() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee = setup.new_account_with_roles(&[role]).await?;
+    let non_grantee = setup.worker.dev_create_account().await?;
+
+    // Authorized and the account holds the role.
+    let preview = setup
+        .contract
+        .acl_revoke_role_preview(&admin, role, grantee.id(), admin.id())
+        .await?;
+    assert_eq!(
+        preview,
+        RevokeRolePreview {
+            authorized: true,
+            would_change: true,
+        }
+    );
+
+    // Authorized but the account doesn't hold the role.
+    let preview = setup
+        .contract
+        .acl_revoke_role_preview(&admin, role, non_grantee.id(), admin.id())
+        .await?;
+    assert_eq!(
+        preview,
+        RevokeRolePreview {
+            authorized: true,
+            would_change: false,
+        }
+    );
+
+    // Unauthorized.
+    let preview = setup
+        .contract
+        .acl_revoke_role_preview(&admin, role, grantee.id(), non_grantee.id())
+        .await?;
+    assert_eq!(
+        preview,
+        RevokeRolePreview {
+            authorized: false,
+            would_change: true,
+        }
+    );
+
+    // The preview matches the outcome of actually revoking the role.
+    let res = setup
+        .contract
+        .acl_revoke_role(&non_grantee, role, grantee.id())
+        .await?;
+    assert_eq!(res, None);
+    let res = setup
+        .contract
+        .acl_revoke_role(&admin, role, grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let preview = setup
+        .contract
+        .acl_revoke_role_preview(&admin, role, grantee.id(), admin.id())
+        .await?;
+    assert!(!preview.would_change);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_rate_limit() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let raw_contract = setup.contract.contract();
+    let grantee = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
+    let other_grantee = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
+
+    // The limit is two calls per window of 20 seconds.
+    let res = call_rate_limited_increase(raw_contract, &grantee).await?;
+    assert_success_with(res, 1);
+    let res = call_rate_limited_increase(raw_contract, &grantee).await?;
+    assert_success_with(res, 2);
+    let res = call_rate_limited_increase(raw_contract, &grantee).await?;
+    assert_failure_with(res, "ACL004: Rate limit exceeded for rate_limited_increase");
+
+    // Calls are counted per account.
+    let res = call_rate_limited_increase(raw_contract, &other_grantee).await?;
+    assert_success_with(res, 3);
+
+    // Calls succeed again once the window has passed.
+    fast_forward_beyond(&setup.worker, sdk_duration_from_secs(20)).await;
+    let res = call_rate_limited_increase(raw_contract, &grantee).await?;
+    assert_success_with(res, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_grant_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let admin = setup
+        .new_account_as_admin(&["ByMax2Increaser", "Resetter"])
+        .await?;
+    let grantee = setup.new_account_with_roles(&["Resetter"]).await?;
+
+    // The caller is admin of `ByMax2Increaser` and `Resetter` but not of `ByMax3Increaser`, and
+    // `grantee` already has role `Resetter`.
+    let roles = ["ByMax2Increaser", "ByMax3Increaser", "Resetter"];
+    let res = setup
+        .contract
+        .acl_grant_roles(&admin, &roles, grantee.id())
+        .await?;
+    assert_eq!(res, vec![Some(true), None, Some(false)]);
+
+    setup
+        .contract
+        .assert_acl_has_role(true, "ByMax2Increaser", grantee.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(false, "ByMax3Increaser", grantee.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(true, "Resetter", grantee.id())
+        .await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_make_role_operator() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "Resetter";
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+    let operator = setup.worker.dev_create_account().await?;
+
+    // Only super-admins may make operators.
+    let res = setup
+        .contract
+        .acl_make_role_operator(&admin, role, operator.id())
+        .await?;
+    assert_eq!(res, None);
+    setup
+        .contract
+        .assert_acl_is_admin(false, role, operator.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(false, role, operator.id())
+        .await;
+
+    // A single call makes `operator` both admin and grantee of `role`.
+    let res = setup
+        .contract
+        .acl_make_role_operator(&super_admin, role, operator.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    setup
+        .contract
+        .assert_acl_is_admin(true, role, operator.id())
+        .await;
+    setup
+        .contract
+        .assert_acl_has_role(true, role, operator.id())
+        .await;
+
+    // Repeating the call changes nothing.
+    let res = setup
+        .contract
+        .acl_make_role_operator(&super_admin, role, operator.id())
+        .await?;
+    assert_eq!(res, Some(false));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_would_authorize() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let account_2 = setup.new_account_with_roles(&["ByMax2Increaser"]).await?;
+    let account_3 = setup.new_account_with_roles(&["ByMax3Increaser"]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+
+    // `increase_2` may be called by grantees of `ByMax2Increaser` or `ByMax3Increaser`.
+    for (account, expected) in [
+        (&account_2, true),
+        (&account_3, true),
+        (&super_admin, false),
+    ] {
+        let res = setup
+            .contract
+            .acl_would_authorize(&account_2, "increase_2", account.id())
+            .await?;
+        assert_eq!(res, expected);
+    }
+
+    // `increase_3` may be called only by grantees of `ByMax3Increaser`.
+    for (account, expected) in [
+        (&account_2, false),
+        (&account_3, true),
+        (&super_admin, false),
+    ] {
+        let res = setup
+            .contract
+            .acl_would_authorize(&account_2, "increase_3", account.id())
+            .await?;
+        assert_eq!(res, expected);
+    }
+
+    // `increase_4` passes `allow_super_admin`, hence super-admins are authorized as well.
+    for (account, expected) in [
+        (&account_2, false),
+        (&account_3, true),
+        (&super_admin, true),
+    ] {
+        let res = setup
+            .contract
+            .acl_would_authorize(&account_2, "increase_4", account.id())
+            .await?;
+        assert_eq!(res, expected);
+    }
+
+    // The simulation matches the outcome of actual calls.
+    let raw_contract = setup.contract.contract();
+    let res = call_increase_2(raw_contract, &account_2).await?;
+    assert_success_with(res, 2);
+    let res = call_increase_2(raw_contract, &super_admin).await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "increase_2",
+        vec!["ByMax2Increaser".to_string(), "ByMax3Increaser".to_string()],
+    );
+
+    // Methods which are not registered in `guarded_methods` lead to an error.
+    let err = setup
+        .contract
+        .acl_would_authorize(&account_2, "increase_5", account_2.id())
+        .await
+        .unwrap_err();
+    assert!(format!("{:?}", err)
+        .contains("Method increase_5 is not registered in guarded_methods of access_control"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_role_bit_mapping() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let caller = setup.contract_account();
+
+    // The permission of the role at position `i` in the enum is represented by bit `2 * i + 1`.
+    for (i, role) in ALL_ROLES.iter().enumerate() {
+        let bit = u8::try_from(2 * i + 1).unwrap();
+        let res = setup.contract.acl_bit_for_role_name(caller, role).await?;
+        assert_eq!(res, Some(bit));
+        let res = setup.contract.acl_role_name_for_bit(caller, bit).await?;
+        assert_eq!(res, Some(role.to_string()));
+
+        // Bits of admin permissions don't represent roles.
+        let res = setup
+            .contract
+            .acl_role_name_for_bit(caller, bit + 1)
+            .await?;
+        assert_eq!(res, None);
+    }
+
+    // Bits of the super-admin permission and beyond the last variant don't represent roles.
+    let unused_bit = u8::try_from(2 * ALL_ROLES.len() + 1).unwrap();
+    for bit in [0, unused_bit, u8::MAX] {
+        let res = setup.contract.acl_role_name_for_bit(caller, bit).await?;
+        assert_eq!(res, None);
+    }
+
+    let res = setup
+        .contract
+        .acl_bit_for_role_name(caller, "InvalidRole")
+        .await?;
+    assert_eq!(res, None);
+
+    // The mapping matches the permissions which are stored when granting a role.
+    let account = setup.new_account_with_roles(&["Resetter"]).await?;
+    let raw = setup
+        .contract
+        .acl_get_permissions_raw(caller, account.id())
+        .await?;
+    let bit = setup
+        .contract
+        .acl_bit_for_role_name(caller, "Resetter")
+        .await?
+        .unwrap();
+    assert_eq!(raw, 1u128 << bit);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_invalid_role_error_code() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let account = setup.worker.dev_create_account().await?;
+
+    let res = setup
+        .contract_account()
+        .call(setup.contract.contract().id(), "acl_grant_role")
+        .args_json(json!({
+            "role": "InvalidRole",
+            "account_id": account.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?;
+    let must_contain = format!(
+        "{}: Value does not correspond to a role",
+        PluginError::InvalidRole.code()
+    );
+    assert_failure_with(res, &must_contain);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_permissioned_accounts_for_role() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = ALL_ROLES[0];
+    let other_role = ALL_ROLES[1];
+
+    // Without any permissions, the result is empty.
+    let accounts = setup
+        .contract
+        .acl_get_permissioned_accounts_for_role(&setup.account, role)
+        .await?;
+    assert!(accounts.admins.is_empty());
+    assert!(accounts.grantees.is_empty());
+
+    let admin = setup.new_account_as_admin(&[role]).await?;
+    let grantee_a = setup.new_account_with_roles(&[role]).await?;
+    let grantee_b = setup.new_account_with_roles(&[role, other_role]).await?;
+    let other_admin = setup.new_account_as_admin(&[other_role]).await?;
+    let other_grantee = setup.new_account_with_roles(&[other_role]).await?;
+    let super_admin = setup.new_super_admin_account().await?;
+
+    // Only accounts with permissions for `role` are returned. Super-admins are not included.
+    let sorted = |accounts: &[&Account]| {
+        let mut ids: Vec<_> = accounts
+            .iter()
+            .map(|account| as_sdk_account_id(account.id()))
+            .collect();
+        ids.sort();
+        ids
+    };
+    let mut accounts = setup
+        .contract
+        .acl_get_permissioned_accounts_for_role(&setup.account, role)
+        .await?;
+    accounts.grantees.sort();
+    assert_eq!(accounts.admins, sorted(&[&admin]));
+    assert_eq!(accounts.grantees, sorted(&[&grantee_a, &grantee_b]));
+
+    let mut accounts = setup
+        .contract
+        .acl_get_permissioned_accounts_for_role(&setup.account, other_role)
+        .await?;
+    accounts.grantees.sort();
+    assert_eq!(accounts.admins, sorted(&[&other_admin]));
+    assert_eq!(accounts.grantees, sorted(&[&grantee_b, &other_grantee]));
+    assert!(!accounts
+        .admins
+        .contains(&as_sdk_account_id(super_admin.id())));
+
+    // The result equals the entry of `role` in `acl_get_permissioned_accounts`.
+    let all = setup
+        .contract
+        .acl_get_permissioned_accounts(&setup.account)
+        .await?;
+    let accounts = setup
+        .contract
+        .acl_get_permissioned_accounts_for_role(&setup.account, role)
+        .await?;
+    assert_eq!(all.roles[role].admins, accounts.admins);
+    assert_eq!(all.roles[role].grantees, accounts.grantees);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_role_members() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = "ByMax2Increaser";
+    for _ in 0..3 {
+        setup.new_account_as_admin(&[role]).await?;
+        setup.new_account_with_roles(&[role]).await?;
+    }
+
+    // Paging applies to admins and grantees separately.
+    for (skip, limit) in [(0, 0), (0, 2), (1, 2), (2, 5), (3, 1)] {
+        let members = setup
+            .contract
+            .acl_get_role_members(&setup.account, role, skip, limit)
+            .await?;
+        let admins = setup
+            .contract
+            .acl_get_admins(&setup.account, role, skip, limit)
+            .await?;
+        let grantees = setup
+            .contract
+            .acl_get_grantees(&setup.account, role, skip, limit)
+            .await?;
+        assert_eq!(members.admins, admins);
+        assert_eq!(members.grantees, grantees);
+    }
+
+    let members = setup
+        .contract
+        .acl_get_role_members(&setup.account, role, 0, 10)
+        .await?;
+    assert_eq!(members.admins.len(), 3);
+    assert_eq!(members.grantees.len(), 3);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_get_grantees_detailed() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let role = ALL_ROLES[0];
+    let grantee_a = setup.new_account_with_roles(&[role]).await?;
+    let grantee_b = setup
+        .new_account_with_roles(&[ALL_ROLES[2], role, ALL_ROLES[1]])
+        .await?;
+    // Not a grantee of `role`, hence not returned.
+    setup.new_account_with_roles(&[ALL_ROLES[1]]).await?;
+
+    // Every grantee is listed with all of its roles, in the order of role variants.
+    let detailed = setup
+        .contract
+        .acl_get_grantees_detailed(&setup.account, role, 0, 10)
+        .await?;
+    assert_eq!(
+        detailed,
+        vec![
+            (grantee_a.id().clone(), vec![role.to_string()]),
+            (
+                grantee_b.id().clone(),
+                ALL_ROLES.iter().map(|role| role.to_string()).collect(),
+            ),
+        ]
+    );
+
+    // Paging matches `acl_get_grantees`.
+    let detailed = setup
+        .contract
+        .acl_get_grantees_detailed(&setup.account, role, 1, 1)
+        .await?;
+    let grantees = setup
+        .contract
+        .acl_get_grantees(&setup.account, role, 1, 1)
+        .await?;
+    assert_eq!(
+        detailed
+            .into_iter()
+            .map(|(account_id, _)| account_id)
+            .collect::<Vec<_>>(),
+        grantees
+    );
+
+    // The maximum page size is stricter than for other paginated getters.
+    let res = setup
+        .account
+        .call(setup.contract.contract().id(), "acl_get_grantees_detailed")
+        .args_json(json!({ "role": role, "skip": 0, "limit": 51 }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "ACL005: ACL: limit must not exceed 50");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_acl_self_introspection() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let without_roles = setup.new_account_with_roles(&[]).await?;
+    let with_roles = setup
+        .new_account_with_roles(&["ByMax3Increaser", "ByMax2Increaser"])
+        .await?;
+    let super_admin = setup.new_super_admin_account().await?;
+
+    for account in [&without_roles, &with_roles, &super_admin] {
+        // The self-view matches explicit checks for the account.
+        let my_roles = setup.contract.acl_my_roles(account).await?;
+        let mut expected = vec![];
+        for role in ALL_ROLES {
+            if setup
+                .contract
+                .acl_has_role(account, role, account.id())
+                .await?
+            {
+                expected.push(role.to_string());
+            }
+        }
+        assert_eq!(my_roles, expected);
 
-    // Verify returned `PermissionedAccounts` when there are outstanding permissions.
-    let permissioned_accounts = setup
+        let am_i_super_admin = setup.contract.acl_am_i_super_admin(account).await?;
+        let is_super_admin = setup
+            .contract
+            .acl_is_super_admin(account, account.id())
+            .await?;
+        assert_eq!(am_i_super_admin, is_super_admin);
+    }
+
+    // Roles are returned in the order of variants.
+    assert_eq!(
+        setup.contract.acl_my_roles(&with_roles).await?,
+        vec!["ByMax2Increaser", "ByMax3Increaser"],
+    );
+    assert!(setup
         .contract
-        .acl_get_permissioned_accounts(&setup.account)
-        .await?;
-    assert_permissioned_account_equivalence(&permissioned_accounts, &expected);
+        .acl_my_roles(&without_roles)
+        .await?
+        .is_empty());
+    assert!(setup.contract.acl_am_i_super_admin(&super_admin).await?);
+    assert!(!setup.contract.acl_am_i_super_admin(&with_roles).await?);
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_add_super_admin_unchecked_is_private() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
+async fn test_acl_recent_events() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_AUDIT_LOG),
+        "access_controllable_audit_log",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let contract = AccessControllableContract::new(contract);
+    let alice = worker.dev_create_account().await?;
+    let bob = worker.dev_create_account().await?;
+    let role = "Increaser";
+
+    // Initializing the super-admin was recorded.
+    let events = contract.acl_recent_events(&alice, 0, 10).await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].action, AclAuditAction::SuperAdminAdded);
+    assert_eq!(events[0].role, None);
+    assert_eq!(events[0].account.as_str(), super_admin.id().as_str());
+
     let res = contract
-        .acl_add_super_admin_unchecked(&account, account.id())
+        .acl_grant_role(&super_admin, role, alice.id())
         .await?;
-    assert_private_method_failure(res, "acl_add_super_admin_unchecked");
+    assert_eq!(res, Some(true));
+    // Granting the role again doesn't change permissions, so nothing is recorded.
+    let res = contract
+        .acl_grant_role(&super_admin, role, alice.id())
+        .await?;
+    assert_eq!(res, Some(false));
+    let res = contract.acl_add_admin(&super_admin, role, bob.id()).await?;
+    assert_eq!(res, Some(true));
+    let res = contract.acl_revoke_role(&bob, role, alice.id()).await?;
+    assert_eq!(res, Some(true));
+
+    // With a capacity of 3, the oldest entry was overwritten. The most recent entry comes first.
+    let events = contract.acl_recent_events(&alice, 0, 10).await?;
+    let summary: Vec<_> = events
+        .iter()
+        .map(|e| {
+            (
+                e.action,
+                e.role.as_deref(),
+                e.account.as_str(),
+                e.by.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                AclAuditAction::RoleRevoked,
+                Some(role),
+                alice.id().as_str(),
+                bob.id().as_str(),
+            ),
+            (
+                AclAuditAction::AdminAdded,
+                Some(role),
+                bob.id().as_str(),
+                super_admin.id().as_str(),
+            ),
+            (
+                AclAuditAction::RoleGranted,
+                Some(role),
+                alice.id().as_str(),
+                super_admin.id().as_str(),
+            ),
+        ],
+    );
+    assert!(events[0].timestamp >= events[1].timestamp);
+    assert!(events[1].timestamp >= events[2].timestamp);
+
+    // Pagination.
+    let page = contract.acl_recent_events(&alice, 1, 1).await?;
+    assert_eq!(page, vec![events[1].clone()]);
+    let page = contract.acl_recent_events(&alice, 3, 10).await?;
+    assert!(page.is_empty());
+
     Ok(())
 }
 
 #[tokio::test]
-async fn test_acl_revoke_super_admin_unchecked_is_private() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
+async fn test_prevent_last_super_admin_removal() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_LAST_SUPER_ADMIN),
+        "access_controllable_last_super_admin",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let contract = AccessControllableContract::new(contract);
+    // Revoking the sole super-admin is blocked.
+    let res = super_admin
+        .call(contract.contract().id(), "acl_revoke_super_admin")
+        .args_json(json!({ "account_id": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "ACL007: ACL: Cannot revoke the last super-admin");
+    assert!(
+        contract
+            .acl_is_super_admin(&super_admin, super_admin.id())
+            .await?
+    );
+
+    // Revoking a super-admin which is not the last one works.
+    let other_super_admin = worker.dev_create_account().await?;
     let res = contract
-        .acl_revoke_super_admin_unchecked(&account, account.id())
+        .acl_add_super_admin(&super_admin, other_super_admin.id())
         .await?;
-    assert_private_method_failure(res, "acl_revoke_super_admin_unchecked");
+    assert_eq!(res, Some(true));
+    let res = contract
+        .acl_revoke_super_admin(&other_super_admin, super_admin.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // Now `other_super_admin` is the last super-admin.
+    let res = other_super_admin
+        .call(contract.contract().id(), "acl_revoke_super_admin")
+        .args_json(json!({ "account_id": other_super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "ACL007: ACL: Cannot revoke the last super-admin");
+    assert!(
+        contract
+            .acl_is_super_admin(&other_super_admin, other_super_admin.id())
+            .await?
+    );
+
     Ok(())
 }
 
+/// With `admins_manage_admins = false`, admins may grant and revoke their role but only
+/// super-admins may manage admins.
 #[tokio::test]
-async fn test_acl_add_admin_unchecked_is_private() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
+async fn test_admins_manage_admins_disabled() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_ADMINS_MANAGE_ADMINS),
+        "access_controllable_admins_manage_admins",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let contract = AccessControllableContract::new(contract);
+    let role = "Increaser";
+
+    // A super-admin may add admins.
+    let admin = worker.dev_create_account().await?;
     let res = contract
-        .acl_add_admin_unchecked(&account, "ByMax2Increaser", account.id())
+        .acl_add_admin(&super_admin, role, admin.id())
         .await?;
-    assert_private_method_failure(res, "acl_add_admin_unchecked");
+    assert_eq!(res, Some(true));
+
+    // The admin may grant and revoke the role.
+    let grantee = worker.dev_create_account().await?;
+    let res = contract.acl_grant_role(&admin, role, grantee.id()).await?;
+    assert_eq!(res, Some(true));
+    let res = contract.acl_revoke_role(&admin, role, grantee.id()).await?;
+    assert_eq!(res, Some(true));
+
+    // The admin may neither add, revoke nor transfer admins.
+    let co_admin = worker.dev_create_account().await?;
+    let res = contract.acl_add_admin(&admin, role, co_admin.id()).await?;
+    assert_eq!(res, None);
+    assert!(!contract.acl_is_admin(&admin, role, co_admin.id()).await?);
+
+    let res = contract
+        .acl_add_admin(&super_admin, role, co_admin.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = contract
+        .acl_revoke_admin(&admin, role, co_admin.id())
+        .await?;
+    assert_eq!(res, None);
+    assert!(contract.acl_is_admin(&admin, role, co_admin.id()).await?);
+
+    let res = contract
+        .acl_transfer_admin(&admin, role, grantee.id())
+        .await?;
+    assert_eq!(res, None);
+    assert!(contract.acl_is_admin(&admin, role, admin.id()).await?);
+
+    // The super-admin may revoke admins.
+    let res = contract
+        .acl_revoke_admin(&super_admin, role, co_admin.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
     Ok(())
 }
 
+/// Methods listed in `expose_unchecked` are generated as private contract methods.
 #[tokio::test]
-async fn test_acl_revoke_admin_unchecked_is_private() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
+async fn test_expose_unchecked() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_EXPOSE_UNCHECKED),
+        "access_controllable_expose_unchecked",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    contract
+        .call("new")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let contract = AccessControllableContract::new(contract);
+    let contract_account = contract.contract().as_account();
+    let account = worker.dev_create_account().await?;
+    let role = "Increaser";
+
+    // Other accounts may not call the generated methods.
+    for method in ["acl_grant_role_unchecked", "acl_add_admin_unchecked"] {
+        let res = account
+            .call(contract.contract().id(), method)
+            .args_json(json!({ "role": role, "account_id": account.id() }))
+            .max_gas()
+            .transact()
+            .await?;
+        assert_private_method_failure(res, method);
+    }
+    assert!(!contract.acl_has_role(&account, role, account.id()).await?);
+
+    // The contract itself may call them.
+    contract
+        .acl_grant_role_unchecked(contract_account, role, account.id())
+        .await?
+        .into_result()?;
+    assert!(contract.acl_has_role(&account, role, account.id()).await?);
+    contract
+        .acl_add_admin_unchecked(contract_account, role, account.id())
+        .await?
+        .into_result()?;
+    assert!(contract.acl_is_admin(&account, role, account.id()).await?);
     let res = contract
-        .acl_revoke_admin_unchecked(&account, "ByMax2Increaser", account.id())
+        .acl_revoke_role_unchecked(contract_account, role, account.id())
         .await?;
-    assert_private_method_failure(res, "acl_revoke_admin_unchecked");
+    assert_success_with(res, true);
+    assert!(!contract.acl_has_role(&account, role, account.id()).await?);
+
+    // Operations which are not listed are not exposed.
+    let res = contract_account
+        .call(contract.contract().id(), "acl_revoke_admin_unchecked")
+        .args_json(json!({ "role": role, "account_id": account.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_method_not_found_failure(res);
+
     Ok(())
 }
 
+/// Grantees of a role may grant and revoke the roles delegated to it via `delegated_roles`, but
+/// no other roles.
 #[tokio::test]
-async fn test_acl_grant_role_unchecked_is_private() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
+async fn test_delegated_roles() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_DELEGATED_ROLES),
+        "access_controllable_delegated_roles",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let contract = AccessControllableContract::new(contract);
+
+    let hr = worker.dev_create_account().await?;
+    let res = contract.acl_grant_role(&super_admin, "Hr", hr.id()).await?;
+    assert_eq!(res, Some(true));
+
+    // The grantee of `Hr` may grant and revoke the delegated roles.
+    let account = worker.dev_create_account().await?;
+    for role in ["Operator", "Viewer"] {
+        assert!(!contract.acl_is_admin(&hr, role, hr.id()).await?);
+        let res = contract.acl_grant_role(&hr, role, account.id()).await?;
+        assert_eq!(res, Some(true));
+        assert!(contract.acl_has_role(&hr, role, account.id()).await?);
+        let res = contract.acl_revoke_role(&hr, role, account.id()).await?;
+        assert_eq!(res, Some(true));
+        assert!(!contract.acl_has_role(&hr, role, account.id()).await?);
+    }
+
+    // Other roles may not be granted or revoked.
+    for role in ["Hr", "Auditor"] {
+        let res = contract.acl_grant_role(&hr, role, account.id()).await?;
+        assert_eq!(res, None);
+        assert!(!contract.acl_has_role(&hr, role, account.id()).await?);
+    }
     let res = contract
-        .acl_grant_role_unchecked(&account, "ByMax2Increaser", account.id())
+        .acl_grant_role(&super_admin, "Auditor", account.id())
         .await?;
-    assert_private_method_failure(res, "acl_grant_role_unchecked");
+    assert_eq!(res, Some(true));
+    let res = contract
+        .acl_revoke_role(&hr, "Auditor", account.id())
+        .await?;
+    assert_eq!(res, None);
+    assert!(contract.acl_has_role(&hr, "Auditor", account.id()).await?);
+
+    // Delegation does not allow managing admins.
+    let res = contract
+        .acl_add_admin(&hr, "Operator", account.id())
+        .await?;
+    assert_eq!(res, None);
+
+    // Accounts without `Hr` may not grant delegated roles.
+    let res = contract
+        .acl_grant_role(&account, "Operator", account.id())
+        .await?;
+    assert_eq!(res, None);
+
+    // After `Hr` is revoked, delegated roles may no longer be granted.
+    let res = contract
+        .acl_revoke_role(&super_admin, "Hr", hr.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = contract
+        .acl_grant_role(&hr, "Operator", account.id())
+        .await?;
+    assert_eq!(res, None);
+
     Ok(())
 }
 
+/// Events carry the `standard` and `version` passed to `#[access_control]`.
 #[tokio::test]
-async fn test_acl_revoke_role_unchecked_is_private() -> anyhow::Result<()> {
-    let Setup {
-        contract, account, ..
-    } = Setup::new().await?;
-    let res = contract
-        .acl_revoke_role_unchecked(&account, "ByMax2Increaser", account.id())
+async fn test_event_standard_and_version() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_EVENT_STANDARD),
+        "access_controllable_event_standard",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let grantee = worker.dev_create_account().await?;
+
+    let res = super_admin
+        .call(contract.id(), "acl_grant_role")
+        .args_json(json!({ "role": "Increaser", "account_id": grantee.id() }))
+        .max_gas()
+        .transact()
         .await?;
-    assert_private_method_failure(res, "acl_revoke_role_unchecked");
+    let expected = format!(
+        r#"EVENT_JSON:{{"standard":"my_acl","version":"2.0.0","event":"role_granted","data":{{"role":"Increaser","to":"{}","by":"{}"}}}}"#,
+        grantee.id(),
+        super_admin.id(),
+    );
+    assert_eq!(res.logs(), vec![expected.as_str()]);
+    assert_success_with(res, Some(true));
+
+    Ok(())
+}
+
+/// Roles deriving `AccessControlRole` outside of a contract, to verify conversions generated by
+/// the derive macro without deploying a contract.
+#[allow(dead_code)]
+mod role_parsing {
+    use near_plugins::AccessControlRole;
+    use std::convert::TryFrom;
+    use std::str::FromStr;
+
+    #[derive(AccessControlRole, Copy, Clone, Debug, PartialEq)]
+    pub enum Role {
+        Minter,
+        Burner,
+    }
+
+    #[test]
+    fn test_role_from_str() {
+        assert_eq!(Role::try_from("Minter"), Ok(Role::Minter));
+        assert_eq!(Role::try_from("Burner"), Ok(Role::Burner));
+        assert_eq!(Role::from_str("Minter"), Ok(Role::Minter));
+        assert_eq!("Burner".parse::<Role>(), Ok(Role::Burner));
+
+        let err = "Value does not correspond to a variant";
+        assert_eq!(Role::try_from("minter"), Err(err));
+        assert_eq!(Role::from_str(""), Err(err));
+        assert_eq!("Unknown".parse::<Role>(), Err(err));
+    }
+}
+
+/// Roles with explicitly assigned positions, which leave a gap at position 1.
+#[allow(dead_code)]
+mod role_explicit_bits {
+    use near_plugins::AccessControlRole;
+    use std::convert::TryFrom;
+
+    #[derive(AccessControlRole, Copy, Clone, Debug, PartialEq)]
+    pub enum Role {
+        #[acl_bit(2)]
+        Burner,
+        #[acl_bit(0)]
+        Minter,
+    }
+
+    #[test]
+    fn test_explicit_bits() {
+        assert_eq!(Role::Minter.acl_permission(), 1 << 1);
+        assert_eq!(Role::Minter.acl_admin_permission(), 1 << 2);
+        assert_eq!(Role::Burner.acl_permission(), 1 << 5);
+        assert_eq!(Role::Burner.acl_admin_permission(), 1 << 6);
+        assert_eq!(Role::acl_super_admin_permission(), 1);
+
+        assert_eq!(u8::from(Role::Burner), 2);
+        assert_eq!(Role::try_from(0u8), Ok(Role::Minter));
+        assert!(Role::try_from(1u8).is_err());
+
+        // Variant names keep the declaration order.
+        assert_eq!(Role::acl_role_variants(), vec!["Burner", "Minter"]);
+    }
+}
+
+/// Assigning the same position to two variants fails to compile.
+#[tokio::test]
+async fn test_role_bit_collision_fails_to_compile() -> anyhow::Result<()> {
+    let res = common::repo::compile_project(
+        Path::new(PROJECT_PATH_ROLE_BIT_COLLISION),
+        "access_control_role_bit_collision",
+    )
+    .await;
+    let err = res.expect_err("Compilation should fail").to_string();
+    assert!(
+        err.contains("`acl_bit(1)` is already assigned to variant `Minter`"),
+        "Unexpected error: {}",
+        err
+    );
+    Ok(())
+}
+
+/// With `caller_resolver`, the ACL guard checks permissions of the account returned by the
+/// resolver, here the user on whose behalf the contract relays a call.
+#[tokio::test]
+async fn test_caller_resolver() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_CALLER_RESOLVER),
+        "access_controllable_caller_resolver",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let super_admin = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({ "super_admin": super_admin.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let acl_contract = AccessControllableContract::new(contract.clone());
+
+    let user = worker.dev_create_account().await?;
+    let res = acl_contract
+        .acl_grant_role(&super_admin, "Increaser", user.id())
+        .await?;
+    assert_eq!(res, Some(true));
+
+    // The relayer, i.e. the contract itself, has no roles. Still the relayed call succeeds since
+    // it is attributed to `user`.
+    let res = user
+        .call(contract.id(), "relay_increase")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 1);
+
+    // Direct calls are still attributed to the predecessor.
+    let res = user
+        .call(contract.id(), "increase")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 2);
+
+    // Relayed calls on behalf of accounts without the role fail.
+    let unauth_account = worker.dev_create_account().await?;
+    let res = unauth_account
+        .call(contract.id(), "relay_increase")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_insufficient_acl_permissions(res, "increase", vec!["Increaser".to_string()]);
+
+    // Freezing `user` blocks calls relayed on its behalf, although the relayer is not frozen.
+    let res = acl_contract
+        .acl_grant_role(&super_admin, "FreezeManager", super_admin.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let res = super_admin
+        .call(contract.id(), "fr_freeze_account")
+        .args_json(json!({ "account_id": user.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, true);
+    let res = user
+        .call(contract.id(), "relay_increase")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(res, "FRZ001: Freezable: Account is frozen");
+
     Ok(())
 }
@@ -0,0 +1,121 @@
+// Using `pub` to avoid invalid `dead_code` warnings, see
+// https://users.rust-lang.org/t/invalid-dead-code-warning-for-submodule-in-integration-test/80259
+pub mod common;
+
+use common::capped_contract::CappedContract;
+use common::utils::{
+    assert_failure_with, assert_insufficient_acl_permissions, assert_success_with,
+};
+use near_plugins::PluginError;
+use near_sdk::json_types::U128;
+use near_sdk::serde_json::json;
+use near_workspaces::Account;
+use std::path::Path;
+
+const PROJECT_PATH: &str = "./tests/contracts/capped";
+
+/// Bundles resources required in tests.
+struct Setup {
+    /// Wrapper around the deployed contract that facilitates interacting with methods provided by
+    /// the `Capped` plugin.
+    capped_contract: CappedContract,
+    /// An account with permission to increase the counter.
+    minter: Account,
+    /// A newly created account without any `AccessControllable` permissions.
+    unauth_account: Account,
+}
+
+impl Setup {
+    /// Deploys the contract in [`PROJECT_PATH`] and initializes `Setup`.
+    async fn new() -> anyhow::Result<Self> {
+        let worker = near_workspaces::sandbox().await?;
+        let wasm = common::repo::compile_project(Path::new(PROJECT_PATH), "capped").await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+        let capped_contract = CappedContract::new(contract.clone());
+
+        let minter = worker.dev_create_account().await?;
+        contract
+            .call("new")
+            .args_json(json!({
+                "minter": minter.id(),
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?;
+
+        let unauth_account = worker.dev_create_account().await?;
+        Ok(Self {
+            capped_contract,
+            minter,
+            unauth_account,
+        })
+    }
+
+    async fn assert_total(&self, expected: u128) {
+        let total = self
+            .capped_contract
+            .capped_get_total(&self.unauth_account)
+            .await
+            .unwrap();
+        assert_eq!(total, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_capped_add_up_to_cap() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.capped_contract;
+    assert_eq!(contract.capped_get_cap(&setup.unauth_account).await?, 100);
+    setup.assert_total(0).await;
+
+    let res = contract.capped_add(&setup.minter, 60).await?;
+    assert_success_with(res, U128(60));
+
+    // Adding via a contract method that calls `capped_add`.
+    let res = setup
+        .minter
+        .call(contract.contract().id(), "mint")
+        .args_json(json!({ "amount": U128(40) }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, U128(100));
+    setup.assert_total(100).await;
+
+    // Exceeding the cap fails.
+    let res = contract.capped_add(&setup.minter, 1).await?;
+    assert_failure_with(
+        res,
+        &format!("{}: Capped: Cap exceeded", PluginError::CapExceeded.code()),
+    );
+    let res = contract.capped_add(&setup.minter, u128::MAX).await?;
+    assert_failure_with(res, "Capped: Cap exceeded");
+    setup.assert_total(100).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_capped_add_unauthorized() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    let res = setup
+        .capped_contract
+        .capped_add(&setup.unauth_account, 1)
+        .await?;
+    assert_insufficient_acl_permissions(res, "capped_add", vec!["Minter".to_string()]);
+
+    // Calling a method which calls `capped_add` doesn't bypass access control.
+    let res = setup
+        .unauth_account
+        .call(setup.capped_contract.contract().id(), "mint")
+        .args_json(json!({ "amount": U128(1) }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_insufficient_acl_permissions(res, "capped_add", vec!["Minter".to_string()]);
+    setup.assert_total(0).await;
+
+    Ok(())
+}
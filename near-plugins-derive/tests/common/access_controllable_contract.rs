@@ -1,6 +1,11 @@
-use near_plugins::access_controllable::PermissionedAccounts;
+use near_plugins::access_controllable::{
+    AclAuditEntry, AclCursor, AclGrantResult, GranteesPage, PermissionedAccounts,
+    PermissionedAccountsChunk, PermissionedAccountsPerRole, RevokeRolePreview,
+};
 
 use near_sdk::serde_json::json;
+use near_sdk::json_types::U64;
+use near_sdk::{CryptoHash, Timestamp};
 use near_workspaces::result::ExecutionFinalResult;
 use near_workspaces::{Account, AccountId, Contract};
 
@@ -168,6 +173,23 @@ impl AccessControllableContract {
         Ok(res.json::<bool>()?)
     }
 
+    pub async fn acl_is_role_admin_exact(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "acl_is_role_admin_exact")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
     pub async fn assert_acl_is_admin(&self, expected: bool, role: &str, account_id: &AccountId) {
         let is_admin = self
             .acl_is_admin(self.contract.as_account(), role, account_id)
@@ -196,6 +218,26 @@ impl AccessControllableContract {
         Ok(res)
     }
 
+    pub async fn acl_transfer_admin(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = caller
+            .call(self.contract.id(), "acl_transfer_admin")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
     pub async fn acl_add_admin_unchecked(
         &self,
         caller: &Account,
@@ -281,6 +323,85 @@ impl AccessControllableContract {
         Ok(res.json::<bool>()?)
     }
 
+    pub async fn acl_role_contains(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "acl_role_contains")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
+    pub async fn acl_would_authorize(
+        &self,
+        caller: &Account,
+        method: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "acl_would_authorize")
+            .args_json(json!({
+                "method": method,
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
+    pub async fn acl_get_permissions_raw(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> anyhow::Result<u128> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_permissions_raw")
+            .args_json(json!({
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<u128>()?)
+    }
+
+    pub async fn acl_role_name_for_bit(
+        &self,
+        caller: &Account,
+        bit_index: u8,
+    ) -> anyhow::Result<Option<String>> {
+        let res = caller
+            .call(self.contract.id(), "acl_role_name_for_bit")
+            .args_json(json!({
+                "bit_index": bit_index,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<String>>()?)
+    }
+
+    pub async fn acl_bit_for_role_name(
+        &self,
+        caller: &Account,
+        name: &str,
+    ) -> anyhow::Result<Option<u8>> {
+        let res = caller
+            .call(self.contract.id(), "acl_bit_for_role_name")
+            .args_json(json!({
+                "name": name,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<u8>>()?)
+    }
+
     pub async fn assert_acl_has_role(&self, expected: bool, role: &str, account_id: &AccountId) {
         let has_role = self
             .acl_has_role(self.contract.as_account(), role, account_id)
@@ -309,6 +430,187 @@ impl AccessControllableContract {
         Ok(res)
     }
 
+    pub async fn acl_grant_role_with_memo(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+        memo: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "acl_grant_role_with_memo")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+                "memo": memo,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_ensure_role(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "acl_ensure_role")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_clone_permissions(
+        &self,
+        caller: &Account,
+        from: &AccountId,
+        to: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "acl_clone_permissions")
+            .args_json(json!({
+                "from": from,
+                "to": to,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_try_grant_role(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<AclGrantResult> {
+        let res = caller
+            .call(self.contract.id(), "acl_try_grant_role")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<AclGrantResult>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_grant_role_until(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+        expires_at: Timestamp,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = caller
+            .call(self.contract.id(), "acl_grant_role_until")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+                "expires_at": U64(expires_at),
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_prune_expired_grants(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_ids: &[&AccountId],
+    ) -> anyhow::Result<u32> {
+        let res = caller
+            .call(self.contract.id(), "acl_prune_expired_grants")
+            .args_json(json!({
+                "role": role,
+                "account_ids": account_ids,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<u32>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_grant_roles(
+        &self,
+        caller: &Account,
+        roles: &[&str],
+        account_id: &AccountId,
+    ) -> anyhow::Result<Vec<Option<bool>>> {
+        let res = caller
+            .call(self.contract.id(), "acl_grant_roles")
+            .args_json(json!({
+                "roles": roles,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Vec<Option<bool>>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_make_role_operator(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+    ) -> anyhow::Result<Option<bool>> {
+        let res = caller
+            .call(self.contract.id(), "acl_make_role_operator")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Option<bool>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_grant_role_to_self(
+        &self,
+        caller: &Account,
+        role: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "acl_grant_role_to_self")
+            .args_json(json!({ "role": role }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn acl_revoke_role_from_self(
+        &self,
+        caller: &Account,
+        role: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "acl_revoke_role_from_self")
+            .args_json(json!({ "role": role }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn acl_grant_role_unchecked(
         &self,
         caller: &Account,
@@ -326,6 +628,23 @@ impl AccessControllableContract {
             .await
     }
 
+    pub async fn acl_grant_role_batch_unchecked(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_ids: &[AccountId],
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "acl_grant_role_batch_unchecked")
+            .args_json(json!({
+                "role": role,
+                "account_ids": account_ids,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn acl_revoke_role(
         &self,
         caller: &Account,
@@ -346,6 +665,25 @@ impl AccessControllableContract {
         Ok(res)
     }
 
+    pub async fn acl_revoke_role_preview(
+        &self,
+        caller: &Account,
+        role: &str,
+        account_id: &AccountId,
+        revoker: &AccountId,
+    ) -> anyhow::Result<RevokeRolePreview> {
+        let res = caller
+            .call(self.contract.id(), "acl_revoke_role_preview")
+            .args_json(json!({
+                "role": role,
+                "account_id": account_id,
+                "revoker": revoker,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<RevokeRolePreview>()?)
+    }
+
     pub async fn acl_renounce_role(&self, caller: &Account, role: &str) -> anyhow::Result<bool> {
         let res = caller
             .call(self.contract.id(), "acl_renounce_role")
@@ -360,6 +698,30 @@ impl AccessControllableContract {
         Ok(res)
     }
 
+    /// Calls `acl_my_roles` in a transaction since it depends on the predecessor.
+    pub async fn acl_my_roles(&self, caller: &Account) -> anyhow::Result<Vec<String>> {
+        let res = caller
+            .call(self.contract.id(), "acl_my_roles")
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<Vec<String>>()?;
+        Ok(res)
+    }
+
+    /// Calls `acl_am_i_super_admin` in a transaction since it depends on the predecessor.
+    pub async fn acl_am_i_super_admin(&self, caller: &Account) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "acl_am_i_super_admin")
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?
+            .json::<bool>()?;
+        Ok(res)
+    }
+
     pub async fn acl_revoke_role_unchecked(
         &self,
         caller: &Account,
@@ -441,6 +803,148 @@ impl AccessControllableContract {
         Ok(res)
     }
 
+    pub async fn acl_get_grantees_detailed(
+        &self,
+        caller: &Account,
+        role: &str,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<(AccountId, Vec<String>)>> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_grantees_detailed")
+            .args_json(json!({
+                "role": role,
+                "skip": skip,
+                "limit": limit,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<Vec<(AccountId, Vec<String>)>>()?)
+    }
+
+    pub async fn acl_get_role_members(
+        &self,
+        caller: &Account,
+        role: &str,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<PermissionedAccountsPerRole> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_role_members")
+            .args_json(json!({
+                "role": role,
+                "skip": skip,
+                "limit": limit,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<PermissionedAccountsPerRole>()?)
+    }
+
+    pub async fn acl_get_grantees_sorted(
+        &self,
+        caller: &Account,
+        role: &str,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<AccountId>> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_grantees_sorted")
+            .args_json(json!({
+                "role": role,
+                "skip": skip,
+                "limit": limit,
+            }))
+            .view()
+            .await?
+            .json::<Vec<AccountId>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_grantees_page(
+        &self,
+        caller: &Account,
+        role: &str,
+        from: Option<&near_sdk::AccountId>,
+        limit: u64,
+    ) -> anyhow::Result<GranteesPage> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_grantees_page")
+            .args_json(json!({
+                "role": role,
+                "from": from,
+                "limit": limit,
+            }))
+            .view()
+            .await?
+            .json::<GranteesPage>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_super_admins_sorted(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<Vec<AccountId>> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_super_admins_sorted")
+            .view()
+            .await?
+            .json::<Vec<AccountId>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_recent_events(
+        &self,
+        caller: &Account,
+        skip: u64,
+        limit: u64,
+    ) -> anyhow::Result<Vec<AclAuditEntry>> {
+        let res = caller
+            .call(self.contract.id(), "acl_recent_events")
+            .args_json(json!({
+                "skip": skip,
+                "limit": limit,
+            }))
+            .view()
+            .await?
+            .json::<Vec<AclAuditEntry>>()?;
+        Ok(res)
+    }
+
+    pub async fn acl_get_super_admins_count(&self, caller: &Account) -> anyhow::Result<u64> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_super_admins_count")
+            .view()
+            .await?;
+        Ok(res.json::<u64>()?)
+    }
+
+    pub async fn acl_get_admins_count(&self, caller: &Account, role: &str) -> anyhow::Result<u64> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_admins_count")
+            .args_json(json!({
+                "role": role,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<u64>()?)
+    }
+
+    pub async fn acl_get_grantees_count(
+        &self,
+        caller: &Account,
+        role: &str,
+    ) -> anyhow::Result<u64> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_grantees_count")
+            .args_json(json!({
+                "role": role,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<u64>()?)
+    }
+
     pub async fn acl_get_permissioned_accounts(
         &self,
         caller: &Account,
@@ -451,4 +955,45 @@ impl AccessControllableContract {
             .await?;
         Ok(res.json::<PermissionedAccounts>()?)
     }
+
+    pub async fn acl_permissions_snapshot_hash(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<CryptoHash> {
+        let res = caller
+            .call(self.contract.id(), "acl_permissions_snapshot_hash")
+            .view()
+            .await?;
+        Ok(res.json::<CryptoHash>()?)
+    }
+
+    pub async fn acl_get_permissioned_accounts_chunk(
+        &self,
+        caller: &Account,
+        cursor: Option<&AclCursor>,
+        max_accounts: u32,
+    ) -> anyhow::Result<PermissionedAccountsChunk> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_permissioned_accounts_chunk")
+            .args_json(json!({
+                "cursor": cursor,
+                "max_accounts": max_accounts,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<PermissionedAccountsChunk>()?)
+    }
+
+    pub async fn acl_get_permissioned_accounts_for_role(
+        &self,
+        caller: &Account,
+        role: &str,
+    ) -> anyhow::Result<PermissionedAccountsPerRole> {
+        let res = caller
+            .call(self.contract.id(), "acl_get_permissioned_accounts_for_role")
+            .args_json(json!({ "role": role }))
+            .view()
+            .await?;
+        Ok(res.json::<PermissionedAccountsPerRole>()?)
+    }
 }
@@ -0,0 +1,49 @@
+use near_sdk::json_types::U128;
+use near_sdk::serde_json::json;
+use near_workspaces::result::ExecutionFinalResult;
+use near_workspaces::{Account, Contract};
+
+/// Wrapper for a contract that is `#[derive(Capped)]`. It allows implementing helpers for calling
+/// contract methods.
+pub struct CappedContract {
+    contract: Contract,
+}
+
+impl CappedContract {
+    pub fn new(contract: Contract) -> Self {
+        Self { contract }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn capped_get_cap(&self, caller: &Account) -> anyhow::Result<u128> {
+        let res = caller
+            .call(self.contract.id(), "capped_get_cap")
+            .view()
+            .await?;
+        Ok(res.json::<U128>()?.0)
+    }
+
+    pub async fn capped_get_total(&self, caller: &Account) -> anyhow::Result<u128> {
+        let res = caller
+            .call(self.contract.id(), "capped_get_total")
+            .view()
+            .await?;
+        Ok(res.json::<U128>()?.0)
+    }
+
+    pub async fn capped_add(
+        &self,
+        caller: &Account,
+        amount: u128,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "capped_add")
+            .args_json(json!({ "amount": U128(amount) }))
+            .max_gas()
+            .transact()
+            .await
+    }
+}
@@ -0,0 +1,26 @@
+use near_plugins::contract_metadata::PluginStatus;
+use near_workspaces::{Account, Contract};
+
+/// Wrapper for a contract that is `#[derive(ContractMetadata)]`. It allows implementing helpers
+/// for calling contract methods.
+pub struct ContractMetadataContract {
+    contract: Contract,
+}
+
+impl ContractMetadataContract {
+    pub fn new(contract: Contract) -> Self {
+        Self { contract }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn plugin_status(&self, caller: &Account) -> anyhow::Result<PluginStatus> {
+        let res = caller
+            .call(self.contract.id(), "plugin_status")
+            .view()
+            .await?;
+        Ok(res.json::<PluginStatus>()?)
+    }
+}
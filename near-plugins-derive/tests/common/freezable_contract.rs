@@ -0,0 +1,60 @@
+use near_sdk::serde_json::json;
+use near_workspaces::result::ExecutionFinalResult;
+use near_workspaces::{Account, AccountId, Contract};
+
+/// Wrapper for a contract that is `#[derive(Freezable)]`. It allows implementing helpers for
+/// calling contract methods.
+pub struct FreezableContract {
+    contract: Contract,
+}
+
+impl FreezableContract {
+    pub fn new(contract: Contract) -> Self {
+        Self { contract }
+    }
+
+    pub fn contract(&self) -> &Contract {
+        &self.contract
+    }
+
+    pub async fn fr_is_frozen(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "fr_is_frozen")
+            .args_json(json!({
+                "account_id": account_id,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
+    pub async fn fr_freeze_account(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "fr_freeze_account")
+            .args_json(json!({ "account_id": account_id }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn fr_unfreeze_account(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "fr_unfreeze_account")
+            .args_json(json!({ "account_id": account_id }))
+            .max_gas()
+            .transact()
+            .await
+    }
+}
@@ -1,4 +1,7 @@
 pub mod access_controllable_contract;
+pub mod capped_contract;
+pub mod contract_metadata_contract;
+pub mod freezable_contract;
 pub mod key;
 pub mod ownable_contract;
 pub mod pausable_contract;
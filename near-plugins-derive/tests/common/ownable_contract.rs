@@ -17,11 +17,53 @@ impl OwnableContract {
         &self.contract
     }
 
+    pub async fn owner_storage_key_str(&self, caller: &Account) -> anyhow::Result<String> {
+        let res = caller
+            .call(self.contract.id(), "owner_storage_key_str")
+            .view()
+            .await?;
+        Ok(res.json::<String>()?)
+    }
+
     pub async fn owner_get(&self, caller: &Account) -> anyhow::Result<Option<AccountId>> {
         let res = caller.call(self.contract.id(), "owner_get").view().await?;
         Ok(res.json::<Option<AccountId>>()?)
     }
 
+    pub async fn owner_get_all(&self, caller: &Account) -> anyhow::Result<Vec<AccountId>> {
+        let res = caller
+            .call(self.contract.id(), "owner_get_all")
+            .view()
+            .await?;
+        Ok(res.json::<Vec<AccountId>>()?)
+    }
+
+    pub async fn owner_add(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "owner_add")
+            .args_json(json!({ "account_id": account_id }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn owner_remove(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "owner_remove")
+            .args_json(json!({ "account_id": account_id }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn owner_set(
         &self,
         caller: &Account,
@@ -35,6 +77,75 @@ impl OwnableContract {
             .await
     }
 
+    pub async fn owner_renounce(
+        &self,
+        caller: &Account,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "owner_renounce")
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn owner_propose(
+        &self,
+        caller: &Account,
+        new_owner: &AccountId,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "owner_propose")
+            .args_json(json!({ "new_owner": new_owner }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn owner_accept(
+        &self,
+        caller: &Account,
+        nonce: u64,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "owner_accept")
+            .args_json(json!({ "nonce": nonce }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn owner_cancel_proposal(
+        &self,
+        caller: &Account,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "owner_cancel_proposal")
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn owner_get_pending(&self, caller: &Account) -> anyhow::Result<Option<AccountId>> {
+        let res = caller
+            .call(self.contract.id(), "owner_get_pending")
+            .view()
+            .await?;
+        Ok(res.json::<Option<AccountId>>()?)
+    }
+
+    pub async fn owner_is_account(
+        &self,
+        caller: &Account,
+        account_id: &AccountId,
+    ) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "owner_is_account")
+            .args_json(json!({ "account_id": account_id }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
     pub async fn owner_is(&self, caller: &Account) -> anyhow::Result<bool> {
         let res = caller
             .call(self.contract.id(), "owner_is")
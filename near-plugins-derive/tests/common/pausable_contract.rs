@@ -1,3 +1,4 @@
+use near_plugins::pausable::FeatureStatus;
 use near_sdk::serde_json::json;
 use near_workspaces::result::ExecutionFinalResult;
 use near_workspaces::{Account, Contract};
@@ -18,6 +19,14 @@ impl PausableContract {
         &self.contract
     }
 
+    pub async fn pa_storage_key(&self, caller: &Account) -> anyhow::Result<Vec<u8>> {
+        let res = caller
+            .call(self.contract.id(), "pa_storage_key")
+            .view()
+            .await?;
+        Ok(res.json::<Vec<u8>>()?)
+    }
+
     pub async fn pa_is_paused(&self, caller: &Account, key: &str) -> anyhow::Result<bool> {
         let res = caller
             .call(self.contract.id(), "pa_is_paused")
@@ -29,6 +38,28 @@ impl PausableContract {
         Ok(res.json::<bool>()?)
     }
 
+    pub async fn pa_is_paused_any(&self, caller: &Account, keys: &[&str]) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "pa_is_paused_any")
+            .args_json(json!({
+                "keys": keys,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
+    pub async fn pa_is_paused_all(&self, caller: &Account, keys: &[&str]) -> anyhow::Result<bool> {
+        let res = caller
+            .call(self.contract.id(), "pa_is_paused_all")
+            .args_json(json!({
+                "keys": keys,
+            }))
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
     pub async fn pa_pause_feature(
         &self,
         caller: &Account,
@@ -42,6 +73,20 @@ impl PausableContract {
             .await
     }
 
+    pub async fn pa_pause_feature_with_reason(
+        &self,
+        caller: &Account,
+        key: &str,
+        reason: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "pa_pause_feature")
+            .args_json(json!({ "key": key, "reason": reason }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn pa_unpause_feature(
         &self,
         caller: &Account,
@@ -55,6 +100,57 @@ impl PausableContract {
             .await
     }
 
+    pub async fn pa_set_features(
+        &self,
+        caller: &Account,
+        pause: &[&str],
+        unpause: &[&str],
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "pa_set_features")
+            .args_json(json!({ "pause": pause, "unpause": unpause }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn pa_all_feature_names(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<Option<Vec<String>>> {
+        let res = caller
+            .call(self.contract.id(), "pa_all_feature_names")
+            .view()
+            .await?;
+        Ok(res.json::<Option<Vec<String>>>()?)
+    }
+
+    pub async fn pa_feature_exempt_roles(
+        &self,
+        caller: &Account,
+        key: &str,
+    ) -> anyhow::Result<Option<Vec<String>>> {
+        let res = caller
+            .call(self.contract.id(), "pa_feature_exempt_roles")
+            .args_json(json!({ "key": key }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<Vec<String>>>()?)
+    }
+
+    pub async fn pa_feature_status(
+        &self,
+        caller: &Account,
+        key: &str,
+    ) -> anyhow::Result<Option<FeatureStatus>> {
+        let res = caller
+            .call(self.contract.id(), "pa_feature_status")
+            .args_json(json!({ "key": key }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<FeatureStatus>>()?)
+    }
+
     pub async fn pa_all_paused(&self, caller: &Account) -> anyhow::Result<Option<HashSet<String>>> {
         let res = caller
             .call(self.contract.id(), "pa_all_paused")
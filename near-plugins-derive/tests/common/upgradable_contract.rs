@@ -1,10 +1,14 @@
-use near_plugins::upgradable::{FunctionCallArgs, UpgradableDurationStatus};
+use near_plugins::upgradable::{
+    FunctionCallArgs, UpgradableDurationStatus, UpgradableDurationStatusJson,
+};
 
+use near_sdk::json_types::{Base64VecU8, U64};
 use near_sdk::serde_json::json;
 use near_sdk::CryptoHash;
 use near_sdk::Duration;
+use near_sdk::Timestamp;
 use near_workspaces::result::ExecutionFinalResult;
-use near_workspaces::{Account, Contract};
+use near_workspaces::{Account, AccountId, Contract};
 
 /// Wrapper for a contract that derives `Upgradable`. It allows implementing helpers for calling
 /// contract methods provided by `Upgradable`.
@@ -32,6 +36,61 @@ impl UpgradableContract {
         Ok(res.json::<UpgradableDurationStatus>()?)
     }
 
+    pub async fn up_get_delay_status_json(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<UpgradableDurationStatusJson> {
+        let res = caller
+            .call(self.contract.id(), "up_get_delay_status_json")
+            .view()
+            .await?;
+        Ok(res.json::<UpgradableDurationStatusJson>()?)
+    }
+
+    pub async fn up_time_until_deployable(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<Option<Duration>> {
+        let res = caller
+            .call(self.contract.id(), "up_time_until_deployable")
+            .view()
+            .await?;
+        Ok(res.json::<Option<Duration>>()?)
+    }
+
+    pub async fn up_time_until_duration_update_applicable(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<Option<Duration>> {
+        let res = caller
+            .call(
+                self.contract.id(),
+                "up_time_until_duration_update_applicable",
+            )
+            .view()
+            .await?;
+        Ok(res.json::<Option<Duration>>()?)
+    }
+
+    pub async fn up_staging_duration_secs(&self, caller: &Account) -> anyhow::Result<Option<u64>> {
+        let res = caller
+            .call(self.contract.id(), "up_staging_duration_secs")
+            .view()
+            .await?;
+        Ok(res.json::<Option<u64>>()?)
+    }
+
+    pub async fn up_new_staging_duration_secs(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<Option<u64>> {
+        let res = caller
+            .call(self.contract.id(), "up_new_staging_duration_secs")
+            .view()
+            .await?;
+        Ok(res.json::<Option<u64>>()?)
+    }
+
     pub async fn up_stage_code(
         &self,
         caller: &Account,
@@ -45,6 +104,91 @@ impl UpgradableContract {
             .await
     }
 
+    pub async fn up_stage_code_at(
+        &self,
+        caller: &Account,
+        code: Vec<u8>,
+        deploy_timestamp: Timestamp,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_stage_code_at")
+            .args_borsh((code, deploy_timestamp))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_stage_code_json(
+        &self,
+        caller: &Account,
+        code: Vec<u8>,
+        deploy_timestamp: Option<Timestamp>,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_stage_code_json")
+            .args_json(json!({
+                "code": Base64VecU8::from(code),
+                "deploy_timestamp": deploy_timestamp.map(U64),
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_stage_code_chunk(
+        &self,
+        caller: &Account,
+        offset: u64,
+        chunk: Vec<u8>,
+        total_len: u64,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_stage_code_chunk")
+            .args_borsh((offset, chunk, total_len))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_finalize_staged_code(
+        &self,
+        caller: &Account,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_finalize_staged_code")
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_stage_code_from_hash(
+        &self,
+        caller: &Account,
+        source_account: &AccountId,
+        expected_hash: CryptoHash,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_stage_code_from_hash")
+            .args_json(json!({
+                "source_account": source_account,
+                "expected_hash": expected_hash,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_unstage_code(
+        &self,
+        caller: &Account,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_unstage_code")
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn up_staged_code(&self, caller: &Account) -> anyhow::Result<Option<Vec<u8>>> {
         let res = caller
             .call(self.contract.id(), "up_staged_code")
@@ -54,6 +198,14 @@ impl UpgradableContract {
         Ok(res.borsh::<Option<Vec<u8>>>()?)
     }
 
+    pub async fn up_staged_code_length(&self, caller: &Account) -> anyhow::Result<Option<u64>> {
+        let res = caller
+            .call(self.contract.id(), "up_staged_code_length")
+            .view()
+            .await?;
+        Ok(res.json::<Option<u64>>()?)
+    }
+
     pub async fn up_staged_code_hash(
         &self,
         caller: &Account,
@@ -66,6 +218,65 @@ impl UpgradableContract {
         Ok(res.json::<Option<CryptoHash>>()?)
     }
 
+    pub async fn up_stage_code_named(
+        &self,
+        caller: &Account,
+        slot: &str,
+        code: Vec<u8>,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_stage_code_named")
+            .args_borsh((slot.to_string(), code, Option::<Timestamp>::None))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_staged_code_named(
+        &self,
+        caller: &Account,
+        slot: &str,
+    ) -> anyhow::Result<Option<Vec<u8>>> {
+        let res = caller
+            .call(self.contract.id(), "up_staged_code_named")
+            .args_borsh(slot.to_string())
+            .max_gas()
+            .transact()
+            .await?;
+        Ok(res.borsh::<Option<Vec<u8>>>()?)
+    }
+
+    pub async fn up_staged_code_hash_named(
+        &self,
+        caller: &Account,
+        slot: &str,
+    ) -> anyhow::Result<Option<CryptoHash>> {
+        let res = caller
+            .call(self.contract.id(), "up_staged_code_hash_named")
+            .args_json(json!({ "slot": slot }))
+            .view()
+            .await?;
+        Ok(res.json::<Option<CryptoHash>>()?)
+    }
+
+    /// The `Promise` returned by trait method `up_deploy_code_named` is resolved in the
+    /// `near_workspaces` transaction.
+    pub async fn up_deploy_code_named(
+        &self,
+        caller: &Account,
+        slot: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_deploy_code_named")
+            .args_json(json!({
+                "slot": slot,
+                "function_call_args": Option::<FunctionCallArgs>::None,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     /// The `Promise` returned by trait method `up_deploy_code` is resolved in the `near_workspaces`
     /// transaction.
     pub async fn up_deploy_code(
@@ -83,6 +294,86 @@ impl UpgradableContract {
             .await
     }
 
+    pub async fn up_deploy_code_keep_staged(
+        &self,
+        caller: &Account,
+        function_call_args: Option<FunctionCallArgs>,
+        keep_staged: bool,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_deploy_code")
+            .args_json(json!({
+                "function_call_args": function_call_args,
+                "keep_staged": keep_staged,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_deploy_code_with_version(
+        &self,
+        caller: &Account,
+        function_call_args: Option<FunctionCallArgs>,
+        version: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_deploy_code")
+            .args_json(json!({
+                "function_call_args": function_call_args,
+                "version": version,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_get_code_deployer_roles(
+        &self,
+        caller: &Account,
+    ) -> anyhow::Result<Vec<String>> {
+        let res = caller
+            .call(self.contract.id(), "up_get_code_deployer_roles")
+            .view()
+            .await?;
+        Ok(res.json::<Vec<String>>()?)
+    }
+
+    pub async fn up_set_code_deployer_roles(
+        &self,
+        caller: &Account,
+        roles: Vec<&str>,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_set_code_deployer_roles")
+            .args_json(json!({ "roles": roles }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    pub async fn up_version(&self, caller: &Account) -> anyhow::Result<Option<String>> {
+        let res = caller.call(self.contract.id(), "up_version").view().await?;
+        Ok(res.json::<Option<String>>()?)
+    }
+
+    /// The `Promise` returned by trait method `up_emergency_deploy_code` is resolved in the
+    /// `near_workspaces` transaction.
+    pub async fn up_emergency_deploy_code(
+        &self,
+        caller: &Account,
+        function_call_args: Option<FunctionCallArgs>,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_emergency_deploy_code")
+            .args_json(json!({
+                "function_call_args": function_call_args,
+            }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn up_init_staging_duration(
         &self,
         caller: &Account,
@@ -96,6 +387,19 @@ impl UpgradableContract {
             .await
     }
 
+    pub async fn up_init_staging_duration_secs(
+        &self,
+        caller: &Account,
+        staging_duration_secs: u64,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_init_staging_duration_secs")
+            .args_json(json!({ "staging_duration_secs": staging_duration_secs }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn up_stage_update_staging_duration(
         &self,
         caller: &Account,
@@ -109,6 +413,19 @@ impl UpgradableContract {
             .await
     }
 
+    pub async fn up_stage_update_staging_duration_secs(
+        &self,
+        caller: &Account,
+        staging_duration_secs: u64,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), "up_stage_update_staging_duration_secs")
+            .args_json(json!({ "staging_duration_secs": staging_duration_secs }))
+            .max_gas()
+            .transact()
+            .await
+    }
+
     pub async fn up_apply_update_staging_duration(
         &self,
         caller: &Account,
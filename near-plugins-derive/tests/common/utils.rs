@@ -1,3 +1,4 @@
+use near_plugins::PluginError;
 use near_sdk::serde::de::DeserializeOwned;
 use near_sdk::Duration;
 use near_workspaces::network::Sandbox;
@@ -85,7 +86,8 @@ pub fn assert_insufficient_acl_permissions(
     // Using `format!` here it'll be: Requires one of these roles: ["LevelA", "LevelB"]
     // However, roles contained in `err` are escaped, i.e. [\"LevelA\", \"LevelB\"]
     let must_contain = format!(
-        "Insufficient permissions for method {} restricted by access control.",
+        "{}: Insufficient permissions for method {} restricted by access control.",
+        PluginError::InsufficientPermissions.code(),
         method,
     );
 
@@ -102,16 +104,27 @@ pub fn assert_method_is_paused(res: ExecutionFinalResult) {
         .into_result()
         .expect_err("Transaction should have failed");
     let err = format!("{}", err);
-    let must_contain = "Pausable: Method is paused";
+    let must_contain = format!("{}: Pausable: Method is paused", PluginError::Paused.code());
     assert!(
-        err.contains(must_contain),
+        err.contains(&must_contain),
         "Expected method to be paused, instead it failed with: {}",
         err
     );
 }
 
 pub fn assert_pausable_escape_hatch_is_closed(res: ExecutionFinalResult, feature: &str) {
-    let must_contain = format!("Pausable: {feature} must be paused to use this function");
+    let must_contain = format!(
+        "{}: Pausable: {feature} must be paused to use this function",
+        PluginError::NotPaused.code()
+    );
+    assert_failure_with(res, &must_contain);
+}
+
+pub fn assert_feature_must_not_be_paused(res: ExecutionFinalResult, feature: &str) {
+    let must_contain = format!(
+        "{}: Pausable: {feature} must not be paused to use this function",
+        PluginError::Paused.code()
+    );
     assert_failure_with(res, &must_contain);
 }
 
@@ -190,7 +203,7 @@ pub fn assert_access_key_not_found_error(
 }
 
 /// Returns the block timestamp in nanoseconds. Panics on failure.
-async fn block_timestamp(worker: &Worker<Sandbox>) -> u64 {
+pub async fn block_timestamp(worker: &Worker<Sandbox>) -> u64 {
     worker
         .view_block()
         .await
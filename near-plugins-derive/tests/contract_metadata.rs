@@ -0,0 +1,121 @@
+// Using `pub` to avoid invalid `dead_code` warnings, see
+// https://users.rust-lang.org/t/invalid-dead-code-warning-for-submodule-in-integration-test/80259
+pub mod common;
+
+use common::contract_metadata_contract::ContractMetadataContract;
+use common::pausable_contract::PausableContract;
+use common::upgradable_contract::UpgradableContract;
+use near_plugins::contract_metadata::{
+    AccessControllableStatus, OwnableStatus, PausableStatus, PluginStatus, UpgradableStatus,
+};
+use near_sdk::serde_json::json;
+use near_sdk::CryptoHash;
+use near_workspaces::Account;
+use std::path::Path;
+
+const PROJECT_PATH: &str = "./tests/contracts/contract_metadata";
+
+/// Bundles resources required in tests.
+struct Setup {
+    /// Wrapper around the deployed contract that facilitates calling `plugin_status`.
+    metadata_contract: ContractMetadataContract,
+    /// Wrapper around the deployed contract that facilitates calling `Pausable` methods.
+    pausable_contract: PausableContract,
+    /// Wrapper around the deployed contract that facilitates calling `Upgradable` methods.
+    upgradable_contract: UpgradableContract,
+    /// The owner of the contract.
+    owner: Account,
+    /// An account which was granted `Role::DAO`.
+    dao: Account,
+}
+
+impl Setup {
+    /// Deploys the contract in [`PROJECT_PATH`] and initializes `Setup`.
+    async fn new() -> anyhow::Result<Self> {
+        let worker = near_workspaces::sandbox().await?;
+        let wasm =
+            common::repo::compile_project(Path::new(PROJECT_PATH), "contract_metadata").await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+
+        let owner = worker.dev_create_account().await?;
+        let dao = worker.dev_create_account().await?;
+        contract
+            .call("new")
+            .args_json(json!({
+                "owner": owner.id(),
+                "dao": dao.id(),
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?;
+
+        Ok(Self {
+            metadata_contract: ContractMetadataContract::new(contract.clone()),
+            pausable_contract: PausableContract::new(contract.clone()),
+            upgradable_contract: UpgradableContract::new(contract),
+            owner,
+            dao,
+        })
+    }
+}
+
+/// Panics if the conversion fails.
+fn convert_code_to_crypto_hash(code: &[u8]) -> CryptoHash {
+    near_sdk::env::sha256(code)
+        .try_into()
+        .expect("Code should be converted to CryptoHash")
+}
+
+#[tokio::test]
+async fn test_plugin_status() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let owner = setup.owner.id().as_str().parse()?;
+
+    // The contract itself is the only super-admin and no features are paused or code staged.
+    let status = setup.metadata_contract.plugin_status(&setup.dao).await?;
+    assert_eq!(
+        status,
+        PluginStatus {
+            ownable: Some(OwnableStatus { owner: Some(owner) }),
+            pausable: Some(PausableStatus { paused: vec![] }),
+            access_controllable: Some(AccessControllableStatus {
+                super_admin_count: 1
+            }),
+            upgradable: Some(UpgradableStatus {
+                staged_code_hash: None
+            }),
+        }
+    );
+
+    // Changes of plugin state are reflected in the aggregate.
+    for key in ["transfer", "ALL"] {
+        let res = setup
+            .pausable_contract
+            .pa_pause_feature(&setup.dao, key)
+            .await?;
+        assert!(res.is_success());
+    }
+    let code = vec![1, 2, 3];
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&setup.dao, code.clone())
+        .await?;
+    assert!(res.is_success());
+
+    let status = setup.metadata_contract.plugin_status(&setup.dao).await?;
+    assert_eq!(
+        status.pausable,
+        Some(PausableStatus {
+            paused: vec!["ALL".to_string(), "transfer".to_string()]
+        })
+    );
+    assert_eq!(
+        status.upgradable,
+        Some(UpgradableStatus {
+            staged_code_hash: Some(convert_code_to_crypto_hash(&code))
+        })
+    );
+
+    Ok(())
+}
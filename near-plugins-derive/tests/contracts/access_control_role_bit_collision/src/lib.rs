@@ -0,0 +1,31 @@
+//! A contract which fails to compile since two roles are assigned the same position.
+
+use near_plugins::{access_control, AccessControlRole};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    #[acl_bit(1)]
+    Minter,
+    /// Collides with `Minter`.
+    #[acl_bit(1)]
+    Burner,
+}
+
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    #[init]
+    pub fn new() -> Self {
+        Self { counter: 0 }
+    }
+}
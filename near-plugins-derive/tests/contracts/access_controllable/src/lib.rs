@@ -1,4 +1,6 @@
-use near_plugins::{access_control, access_control_any, AccessControlRole, AccessControllable};
+use near_plugins::{
+    access_control, access_control_all, access_control_any, AccessControlRole, AccessControllable,
+};
 use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{env, near, AccountId, PanicOnDefault};
@@ -19,7 +21,16 @@ pub enum Role {
 }
 
 /// Pass `Role` to the `access_controllable` macro.
-#[access_control(role_type(Role))]
+///
+/// Registering guarded methods enables simulating their guards via `acl_would_authorize`. Once
+/// `guarded_methods` is set, it must list all `pub` methods guarded by `access_control_any`.
+///
+/// `expiring_roles` enables granting roles with an expiration via `acl_grant_role_until`.
+#[access_control(
+    role_type(Role),
+    guarded_methods(increase_2, increase_3, increase_4, rate_limited_increase, reset),
+    expiring_roles
+)]
 #[near(contract_state)]
 #[derive(PanicOnDefault)]
 pub struct Counter {
@@ -111,6 +122,51 @@ impl Counter {
         self.counter
     }
 
+    /// Increases the counter by five and returns its new value.
+    ///
+    /// This method shows how to use `access_control_all`. Only an account that was granted _both_
+    /// `Role::ByMax2Increaser` and `Role::ByMax3Increaser` may successfully call this method.
+    #[access_control_all(roles(Role::ByMax2Increaser, Role::ByMax3Increaser))]
+    pub fn increase_5(&mut self) -> u64 {
+        self.counter += 5;
+        self.counter
+    }
+
+    /// Increases the counter by four and returns its new value.
+    ///
+    /// Passing `allow_super_admin` permits super-admins to call this method in addition to
+    /// grantees of `Role::ByMax3Increaser`.
+    #[access_control_any(roles(Role::ByMax3Increaser), allow_super_admin)]
+    pub fn increase_4(&mut self) -> u64 {
+        self.counter += 4;
+        self.counter
+    }
+
+    /// Increases the counter by one and returns its new value.
+    ///
+    /// Only an account that was granted `Role::ByMax2Increaser` may successfully call this method,
+    /// at most twice within 20 seconds. The rate limit is enforced per grantee by
+    /// `acl_check_and_record_rate_limit`, which is generated by `#[access_control]` but not
+    /// exposed as a contract method.
+    #[access_control_any(roles(Role::ByMax2Increaser))]
+    pub fn rate_limited_increase(&mut self) -> u64 {
+        self.acl_check_and_record_rate_limit("rate_limited_increase", 2, 20_000_000_000);
+        self.counter += 1;
+        self.counter
+    }
+
+    /// Increases the counter by three on behalf of the contract itself and returns its new value.
+    ///
+    /// Like a constructor, this private method temporarily grants a role to the contract to call
+    /// a guarded method.
+    #[private]
+    pub fn increase_3_as_self(&mut self) -> u64 {
+        self.acl_grant_role_to_self(Role::ByMax3Increaser.into());
+        let counter = self.increase_3();
+        self.acl_revoke_role_from_self(Role::ByMax3Increaser.into());
+        counter
+    }
+
     /// Resets the counters value to zero.
     ///
     /// Only an account that was granted `Role:Resetter` may successfully call this method.
@@ -175,4 +231,14 @@ impl Counter {
         self.acl_get_or_init()
             .grant_role_unchecked(role, &account_id)
     }
+
+    /// Grants `role` to every account in `account_ids`, which allows tests to set up many
+    /// grantees with few transactions.
+    #[private]
+    pub fn acl_grant_role_batch_unchecked(&mut self, role: Role, account_ids: Vec<AccountId>) {
+        let mut acl = self.acl_get_or_init();
+        for account_id in account_ids.iter() {
+            acl.grant_role_unchecked(role, account_id);
+        }
+    }
 }
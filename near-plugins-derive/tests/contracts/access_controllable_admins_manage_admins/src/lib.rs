@@ -0,0 +1,40 @@
+//! A contract whose role admins may grant and revoke roles, but not manage other admins.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may call contract methods increasing the counter.
+    Increaser,
+}
+
+/// Adding, revoking and transferring admins requires super-admin permissions.
+#[access_control(role_type(Role), admins_manage_admins = false)]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes `super_admin` the only super-admin.
+    #[init]
+    pub fn new(super_admin: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        near_sdk::require!(
+            contract.acl_init_super_admin(super_admin),
+            "Failed to initialize super admin",
+        );
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
@@ -0,0 +1,40 @@
+//! A contract which stores recent permission changes in an audit log.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may call contract methods increasing the counter.
+    Increaser,
+}
+
+/// With a capacity of 3, the fourth permission change overwrites the first one.
+#[access_control(role_type(Role), audit_log(capacity = 3))]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes `super_admin` a super-admin, which is recorded in the audit log.
+    #[init]
+    pub fn new(super_admin: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        near_sdk::require!(
+            contract.acl_init_super_admin(super_admin),
+            "Failed to initialize super admin",
+        );
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
@@ -0,0 +1,75 @@
+//! A contract that relays calls to itself and checks permissions of the account on whose behalf
+//! a call is relayed.
+
+use near_plugins::{
+    access_control, access_control_any, frozen_guard, AccessControlRole, AccessControllable,
+    Freezable,
+};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, Gas, NearToken, PanicOnDefault, Promise};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    Increaser,
+    FreezeManager,
+}
+
+/// Attributes calls relayed by the contract itself to the signer of the transaction, i.e. the
+/// user who called `relay_increase`. Other calls are attributed to the predecessor.
+fn effective_caller() -> AccountId {
+    let predecessor = env::predecessor_account_id();
+    if predecessor == env::current_account_id() {
+        env::signer_account_id()
+    } else {
+        predecessor
+    }
+}
+
+#[access_control(role_type(Role), caller_resolver = "effective_caller")]
+#[near(contract_state)]
+#[derive(Freezable, PanicOnDefault)]
+#[freezable(manager_roles(Role::FreezeManager))]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes `super_admin` a super-admin.
+    #[init]
+    pub fn new(super_admin: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        near_sdk::require!(
+            contract.acl_init_super_admin(super_admin),
+            "Failed to initialize super admin",
+        );
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// May be called by grantees of `Role::Increaser`, directly or via `relay_increase`, unless
+    /// the account on whose behalf it is called is frozen.
+    #[access_control_any(roles(Role::Increaser))]
+    #[frozen_guard]
+    pub fn increase(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    /// Acts as a relayer which calls `increase`, making the contract itself the predecessor of
+    /// that call.
+    pub fn relay_increase(&self) -> Promise {
+        Promise::new(env::current_account_id()).function_call(
+            "increase".to_string(),
+            Vec::new(),
+            NearToken::from_yoctonear(0),
+            Gas::from_tgas(20),
+        )
+    }
+}
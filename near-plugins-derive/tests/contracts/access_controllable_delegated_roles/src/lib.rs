@@ -0,0 +1,45 @@
+//! A contract whose `Hr` role may grant and revoke a subset of other roles.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may grant and revoke `Operator` and `Viewer`.
+    Hr,
+    Operator,
+    Viewer,
+    /// Not delegated, hence only admins may grant and revoke it.
+    Auditor,
+}
+
+/// Grantees of `Role::Hr` may grant and revoke `Role::Operator` and `Role::Viewer` without being
+/// admins for these roles.
+#[access_control(role_type(Role), delegated_roles(Hr(Role::Operator, Role::Viewer)))]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes `super_admin` a super-admin.
+    #[init]
+    pub fn new(super_admin: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        near_sdk::require!(
+            contract.acl_init_super_admin(super_admin),
+            "Failed to initialize super admin",
+        );
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
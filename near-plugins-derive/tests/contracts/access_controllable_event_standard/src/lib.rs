@@ -0,0 +1,40 @@
+//! A contract which customizes the `standard` and `version` of emitted events.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may call contract methods increasing the counter.
+    Increaser,
+}
+
+/// Events are emitted with `"standard":"my_acl"` and `"version":"2.0.0"`.
+#[access_control(role_type(Role), event_standard = "my_acl", event_version = "2.0.0")]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes `super_admin` a super-admin.
+    #[init]
+    pub fn new(super_admin: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        near_sdk::require!(
+            contract.acl_init_super_admin(super_admin),
+            "Failed to initialize super admin",
+        );
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
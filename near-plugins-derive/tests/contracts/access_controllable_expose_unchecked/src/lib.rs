@@ -0,0 +1,36 @@
+//! A contract which lets the macro generate `#[private]` wrappers of `*_unchecked` methods.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may call contract methods increasing the counter.
+    Increaser,
+}
+
+/// Generates the contract methods `acl_grant_role_unchecked`, `acl_revoke_role_unchecked` and
+/// `acl_add_admin_unchecked`, which may only be called by the contract itself.
+#[access_control(role_type(Role), expose_unchecked(grant_role, revoke_role, add_admin))]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Initializes the contract without any permissions.
+    #[init]
+    pub fn new() -> Self {
+        Self { counter: 0 }
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
@@ -0,0 +1,38 @@
+//! A contract whose owner is treated as super-admin of `AccessControllable`.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable, Ownable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may call contract methods increasing the counter.
+    Increaser,
+}
+
+/// With `owner_is_super_admin`, the owner passes super-admin checks without being added as
+/// super-admin explicitly.
+#[access_control(role_type(Role), owner_is_super_admin)]
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Sets `owner` as the owner of the contract. No super-admin is added.
+    #[init]
+    pub fn new(owner: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        contract.owner_set(Some(owner));
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
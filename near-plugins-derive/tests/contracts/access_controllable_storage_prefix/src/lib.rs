@@ -0,0 +1,45 @@
+//! A contract which stores the state of `AccessControllable` under a custom storage prefix.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Grantees may call contract methods increasing the counter.
+    Increaser,
+    /// Grantees of this role may call the contract method `reset`.
+    Resetter,
+}
+
+/// All access control state is stored under keys starting with the value passed as
+/// `storage_prefix`, instead of the default prefix `__acl`.
+#[access_control(role_type(Role), storage_prefix = "custom")]
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes the contract itself super admin.
+    #[init]
+    pub fn new() -> Self {
+        let mut contract = Self { counter: 0 };
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
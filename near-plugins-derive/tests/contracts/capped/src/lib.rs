@@ -0,0 +1,45 @@
+//! A contract whose minted supply may not exceed a cap.
+
+use near_plugins::{access_control, AccessControlRole, AccessControllable, Capped};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::json_types::U128;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May increase the supply.
+    Minter,
+}
+
+/// The supply is tracked by the `Capped` plugin and may not exceed 100.
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Capped, PanicOnDefault)]
+#[capped(cap = "100", adder_roles(Role::Minter))]
+pub struct Token;
+
+#[near]
+impl Token {
+    /// Makes the contract itself super admin and grants `Role::Minter` to `minter`.
+    #[init]
+    pub fn new(minter: AccountId) -> Self {
+        let mut contract = Self;
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let result = contract.acl_grant_role(Role::Minter.into(), minter);
+        near_sdk::require!(Some(true) == result, "Failed to grant role");
+
+        contract
+    }
+
+    /// Mints `amount` and returns the new supply. It fails if the caller is not a grantee of
+    /// `Role::Minter` or the supply would exceed the cap.
+    pub fn mint(&mut self, amount: U128) -> U128 {
+        self.capped_add(amount)
+    }
+}
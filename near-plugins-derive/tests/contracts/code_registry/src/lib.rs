@@ -0,0 +1,27 @@
+//! A mock of a code registry from which `Upgradable::up_stage_code_from_hash` requests code.
+
+use near_plugins::upgradable::CodeRegistry;
+use near_sdk::{env, near, CryptoHash};
+
+/// Stores code under its sha256 hash.
+#[near(contract_state)]
+#[derive(Default)]
+pub struct Registry;
+
+#[near]
+impl Registry {
+    /// Stores `code` and returns its hash.
+    pub fn store_code(&mut self, #[serializer(borsh)] code: Vec<u8>) -> CryptoHash {
+        let code_hash: CryptoHash = env::sha256_array(&code);
+        env::storage_write(&code_hash, &code);
+        code_hash
+    }
+}
+
+#[near]
+impl CodeRegistry for Registry {
+    #[result_serializer(borsh)]
+    fn get_code(&self, #[serializer(borsh)] code_hash: CryptoHash) -> Option<Vec<u8>> {
+        env::storage_read(&code_hash)
+    }
+}
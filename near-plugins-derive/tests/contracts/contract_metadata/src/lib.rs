@@ -0,0 +1,53 @@
+use near_plugins::{
+    access_control, AccessControlRole, AccessControllable, ContractMetadata, Ownable, Pausable,
+    Upgradable,
+};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::env;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// Defines roles for access control of protected methods provided by the `Pausable` and
+/// `Upgradable` plugins.
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May pause and unpause features and call any of the protected `Upgradable` methods.
+    DAO,
+}
+
+/// `ContractMetadata::plugin_status` aggregates the state of all plugins passed to
+/// `contract_metadata`.
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Ownable, Pausable, Upgradable, ContractMetadata, PanicOnDefault)]
+#[pausable(manager_roles(Role::DAO))]
+#[upgradable(access_control_roles(
+    code_stagers(Role::DAO),
+    code_deployers(Role::DAO),
+    duration_initializers(Role::DAO),
+    duration_update_stagers(Role::DAO),
+    duration_update_appliers(Role::DAO),
+))]
+#[contract_metadata(ownable, pausable, access_controllable, upgradable)]
+pub struct Contract;
+
+#[near]
+impl Contract {
+    /// Sets `owner` as owner, makes the contract itself `AccessControllable` super admin and
+    /// grants `Role::DAO` to `dao`.
+    #[init]
+    pub fn new(owner: AccountId, dao: AccountId) -> Self {
+        let mut contract = Self;
+
+        contract.owner_set(Some(owner));
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let res = contract.acl_grant_role(Role::DAO.into(), dao);
+        assert_eq!(Some(true), res, "Failed to grant role");
+
+        contract
+    }
+}
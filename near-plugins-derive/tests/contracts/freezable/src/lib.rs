@@ -0,0 +1,58 @@
+//! A contract whose accounts may be frozen individually.
+
+use near_plugins::{
+    access_control, frozen_guard, AccessControlRole, AccessControllable, Freezable,
+};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May freeze and unfreeze accounts.
+    FreezeManager,
+}
+
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Freezable, PanicOnDefault)]
+#[freezable(manager_roles(Role::FreezeManager))]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes the contract itself super admin and grants `Role::FreezeManager` to
+    /// `freeze_manager`.
+    #[init]
+    pub fn new(freeze_manager: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let result = contract.acl_grant_role(Role::FreezeManager.into(), freeze_manager);
+        near_sdk::require!(Some(true) == result, "Failed to grant role");
+
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Fails if called by a frozen account.
+    #[frozen_guard]
+    pub fn increase(&mut self) {
+        self.counter += 1;
+    }
+
+    /// May be called by any account, including frozen ones.
+    pub fn increase_unguarded(&mut self) {
+        self.counter += 1;
+    }
+}
@@ -31,6 +31,16 @@ impl Counter {
         self.counter
     }
 
+    /// Anyone may increase the counter by one. Increasing it by more than one is restricted to the
+    /// owner, which is checked in the method body.
+    pub fn increase_by(&mut self, value: u64) -> u64 {
+        if value > 1 {
+            self.assert_owner();
+        }
+        self.counter += value;
+        self.counter
+    }
+
     /// _Only_ the owner or the contract itself may call this method successfully. It panics if
     /// anyone else calls it.
     #[only(self, owner)]
@@ -55,4 +65,29 @@ impl Counter {
         self.counter += 4;
         self.counter
     }
+
+    /// _Only_ the owner may call this view-like method successfully, which takes `&self`.
+    #[only(owner)]
+    pub fn get_counter_only_owner(&self) -> u64 {
+        self.counter
+    }
+
+    /// _Only_ the owner may call this method successfully, which takes no receiver.
+    #[only(owner)]
+    pub fn no_receiver_only_owner() -> bool {
+        true
+    }
+
+    /// _Only_ the contract itself may call this method successfully, which takes no receiver.
+    #[only(self)]
+    pub fn no_receiver_only_self() -> bool {
+        true
+    }
+
+    /// _Only_ the owner or the contract itself may call this method successfully, which takes no
+    /// receiver.
+    #[only(self, owner)]
+    pub fn no_receiver_only_self_owner() -> bool {
+        true
+    }
 }
@@ -0,0 +1,42 @@
+//! A contract co-owned by several accounts, any of which may act as owner.
+
+use near_plugins::{only, Ownable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// With `multi`, co-owners are managed via `owner_add` and `owner_remove`.
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+#[ownable(multi)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Adds `owner` as the first co-owner of the contract.
+    #[init]
+    pub fn new(owner: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        contract.owner_add(owner);
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// _Only_ co-owners may call this method successfully.
+    #[only(owner)]
+    pub fn increase(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    /// _Only_ co-owners may call this method successfully, which takes no receiver.
+    #[only(owner)]
+    pub fn no_receiver_only_owner() -> bool {
+        true
+    }
+}
@@ -0,0 +1,29 @@
+//! A contract whose owner may be transferred but not removed.
+
+use near_plugins::Ownable;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// With `allow_renounce = false`, calling `owner_set(None)` panics.
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+#[ownable(allow_renounce = false)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Sets `owner` as the owner of the contract.
+    #[init]
+    pub fn new(owner: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        contract.owner_set(Some(owner));
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
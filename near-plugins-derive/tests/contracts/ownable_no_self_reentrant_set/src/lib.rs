@@ -0,0 +1,41 @@
+//! A contract which must not set its owner in callbacks scheduled by itself.
+
+use near_plugins::Ownable;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{env, near, AccountId, PanicOnDefault, Promise};
+
+/// With `no_self_reentrant_set`, `owner_set` panics if the contract calls it in a callback.
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+#[ownable(no_self_reentrant_set)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Initializes the contract without owner, so the owner may be set by the contract itself.
+    #[init]
+    pub fn new() -> Self {
+        Self { counter: 0 }
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Schedules a cross-contract call to the contract itself with a callback that attempts to
+    /// set `owner`.
+    pub fn set_owner_in_callback(&mut self, owner: AccountId) -> Promise {
+        Self::ext(env::current_account_id())
+            .get_counter()
+            .then(Self::ext(env::current_account_id()).set_owner_callback(owner))
+    }
+
+    /// Sets `owner` via `owner_set`, which fails since it is called in a callback.
+    #[private]
+    pub fn set_owner_callback(&mut self, owner: AccountId) {
+        self.owner_set(Some(owner));
+    }
+}
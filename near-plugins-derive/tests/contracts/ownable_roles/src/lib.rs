@@ -0,0 +1,62 @@
+//! A contract which combines conditions of `Ownable` and `AccessControllable` in `#[only(...)]`.
+
+use near_plugins::{access_control, only, AccessControlRole, AccessControllable, Ownable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, Gas, NearToken, PanicOnDefault, Promise};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May call `increase`.
+    Increaser,
+}
+
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Sets `owner` as the owner of the contract, makes the contract itself super-admin and grants
+    /// `Role::Increaser` to `increaser`.
+    #[init]
+    pub fn new(owner: AccountId, increaser: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        contract.owner_set(Some(owner));
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let result = contract.acl_grant_role(Role::Increaser.into(), increaser);
+        near_sdk::require!(Some(true) == result, "Failed to grant role");
+
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// May be called by the owner, the contract itself or grantees of `Role::Increaser`.
+    #[only(owner, self, any_of_roles(Role::Increaser))]
+    pub fn increase(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    /// Makes the contract call `increase` on itself, so that the contract is the predecessor.
+    pub fn increase_from_self(&mut self) -> Promise {
+        Promise::new(env::current_account_id()).function_call(
+            "increase".to_string(),
+            vec![],
+            NearToken::from_yoctonear(0),
+            Gas::from_tgas(10),
+        )
+    }
+}
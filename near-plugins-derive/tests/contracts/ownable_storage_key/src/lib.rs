@@ -0,0 +1,24 @@
+//! A contract which stores its owner under a custom storage key.
+
+use near_plugins::Ownable;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// The owner is stored under key `b"CUSTOM_OWNER"` instead of the default `b"__OWNER__"`.
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+#[ownable(owner_storage_key = "CUSTOM_OWNER")]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Sets `owner` as the owner of the contract.
+    #[init]
+    pub fn new(owner: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+        contract.owner_set(Some(owner));
+        contract
+    }
+}
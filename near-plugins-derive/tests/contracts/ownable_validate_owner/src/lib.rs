@@ -0,0 +1,40 @@
+//! A contract whose owner must not be an implicit account.
+
+use near_plugins::Ownable;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// With `validate_owner`, `owner_set` and `owner_propose` panic if `is_named_account` returns
+/// `false` for the new owner.
+#[near(contract_state)]
+#[derive(Ownable, PanicOnDefault)]
+#[ownable(validate_owner = "is_named_account")]
+pub struct Counter {
+    counter: u64,
+}
+
+/// Rejects implicit account ids, i.e. ids consisting of 64 hex characters. Removing the owner is
+/// allowed.
+fn is_named_account(_contract: &Counter, owner: &Option<AccountId>) -> bool {
+    match owner {
+        Some(owner) => {
+            let id = owner.as_str();
+            !(id.len() == 64 && id.chars().all(|c| c.is_ascii_hexdigit()))
+        }
+        None => true,
+    }
+}
+
+#[near]
+impl Counter {
+    /// Initializes the contract without owner, so the owner may be set by the contract itself.
+    #[init]
+    pub fn new() -> Self {
+        Self { counter: 0 }
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+}
@@ -1,9 +1,10 @@
+use near_plugins::pausable::PauseSnapshot;
 use near_plugins::{
-    access_control, if_paused, pause, AccessControlRole, AccessControllable, Pausable,
+    access_control, if_paused, pause, unpaused, AccessControlRole, AccessControllable, Pausable,
 };
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::{env, near, AccountId, PanicOnDefault};
+use near_sdk::{env, near, AccountId, Gas, NearToken, PanicOnDefault, Promise};
 
 /// Define roles for access control of `Pausable` features. Accounts which are
 /// granted a role are authorized to execute the corresponding action.
@@ -23,7 +24,28 @@ pub enum Role {
 #[access_control(role_type(Role))]
 #[near(contract_state)]
 #[derive(Pausable, PanicOnDefault)]
-#[pausable(manager_roles(Role::PauseManager))]
+// The features must include the keys and groups of all methods annotated with `#[pause]`. Feature
+// "increase_1" is listed twice to verify duplicates are removed. The features are also available
+// as variants of the generated enum `Feature`, e.g. `Feature::IncreaseByTwo`. The roles returned
+// for the methods in `exempt_roles` are taken from their `except(roles(...))`. With
+// `track_history`, the most recent change of each feature is returned by `pa_feature_status`.
+#[pausable(
+    manager_roles(Role::PauseManager),
+    features(
+        "increase_1",
+        "Increase by two",
+        "increase_4",
+        "increase_big",
+        "trading",
+        "get_counter_pausable",
+        "trade_1",
+        "trade_2",
+        "increase_1"
+    ),
+    feature_enum = "Feature",
+    exempt_roles(increase_4),
+    track_history
+)]
 pub struct Counter {
     counter: u64,
 }
@@ -38,9 +60,7 @@ impl Counter {
     /// For a general overview of access control, please refer to the `AccessControllable` plugin.
     #[init]
     pub fn new(pause_manager: AccountId) -> Self {
-        let mut contract = Self {
-            counter: 0,
-        };
+        let mut contract = Self { counter: 0 };
 
         // Make the contract itself super admin. This allows us to grant any role in the
         // constructor.
@@ -61,6 +81,13 @@ impl Counter {
         self.counter
     }
 
+    /// Returns the value of the counter. Can be paused using feature name "get_counter_pausable" or
+    /// "ALL", which demonstrates that `#[pause]` works on `&self` methods called as views.
+    #[pause]
+    pub fn get_counter_pausable(&self) -> u64 {
+        self.counter
+    }
+
     /// Function can be paused using feature name "increase_1" or "ALL" like:
     /// `contract.pa_pause_feature("increase_1")` or `contract.pa_pause_feature("ALL")`
     ///
@@ -81,6 +108,16 @@ impl Counter {
         self.counter += 2;
     }
 
+    /// Belongs to feature "Increase by two" like `increase_2`, but panics with a custom message
+    /// if the feature is paused.
+    #[pause(
+        name = "Increase by two",
+        message = "Increasing by two is temporarily halted"
+    )]
+    pub fn increase_2_with_message(&mut self) {
+        self.counter += 2;
+    }
+
     /// Similar to `#[pause]` but roles passed as argument may still successfully call this method
     /// even when the corresponding feature is paused.
     #[pause(except(roles(Role::Unrestricted4Increaser, Role::Unrestricted4Modifier)))]
@@ -103,6 +140,44 @@ impl Counter {
         self.counter -= 4;
     }
 
+    /// This method can only be called when "increase_1" is _not_ paused. It states explicitly which
+    /// feature it depends on, even though its own name differs. Note that if "ALL" is specified the
+    /// "increase_1" is considered to be paused.
+    #[unpaused(name = "increase_1")]
+    pub fn double_increase_1(&mut self) {
+        self.counter += 2;
+    }
+
+    /// Increases the counter in a callback, which decides based on the pause state of "increase_1"
+    /// captured when entering this method.
+    ///
+    /// To simulate the feature being paused while the promise is in flight, the contract calls
+    /// `pa_pause_feature("increase_1")` on itself before the callback is executed. That call
+    /// succeeds only if the contract was granted `Role::PauseManager`.
+    #[pause(name = "increase_1")]
+    pub fn increase_1_in_callback(&mut self) -> Promise {
+        let snapshot = self.pa_snapshot("increase_1".to_string());
+        let args = near_sdk::serde_json::json!({ "key": "increase_1" });
+        Promise::new(env::current_account_id())
+            .function_call(
+                "pa_pause_feature".to_string(),
+                args.to_string().into_bytes(),
+                NearToken::from_yoctonear(0),
+                Gas::from_tgas(20),
+            )
+            .then(Self::ext(env::current_account_id()).increase_1_callback(snapshot))
+    }
+
+    /// Increases the counter if "increase_1" was not paused when the call was initiated, even if
+    /// the feature is paused by now. Returns whether the feature is paused now.
+    #[private]
+    pub fn increase_1_callback(&mut self, snapshot: PauseSnapshot) -> bool {
+        if !snapshot.was_paused_at_entry() {
+            self.counter += 1;
+        }
+        self.pa_is_paused(snapshot.key)
+    }
+
     /// Custom use of pause features. Only allow increasing the counter using `careful_increase` if
     /// it is below 3.
     pub fn careful_increase(&mut self) {
@@ -115,4 +190,50 @@ impl Counter {
 
         self.counter += 1;
     }
+
+    /// Behaves like `careful_increase`, though the pause logic is declared via the `when` argument
+    /// of `#[pause]` instead of being mixed into the method body. If feature "increase_big" is
+    /// paused, the method fails once `Self::is_counter_big` returns `true`.
+    #[pause(name = "increase_big", when = "Self::is_counter_big")]
+    pub fn declarative_careful_increase(&mut self) {
+        self.counter += 1;
+    }
+
+    /// Increases the counter by one. The method belongs to group "trading", hence it is paused if
+    /// either "trade_1" or "trading" is paused.
+    #[pause(group = "trading")]
+    pub fn trade_1(&mut self) {
+        self.counter += 1;
+    }
+
+    /// Increases the counter by two. Like `trade_1`, it is paused if "trading" is paused.
+    #[pause(group = "trading")]
+    pub fn trade_2(&mut self) {
+        self.counter += 2;
+    }
+
+    /// Pauses feature "Increase by two" via the typed API. Like `pa_pause_feature`, it succeeds
+    /// only if the predecessor is a grantee of `Role::PauseManager`.
+    pub fn typed_pause_increase_2(&mut self) -> bool {
+        self.pa_pause(Feature::IncreaseByTwo, None)
+    }
+
+    /// Unpauses feature "Increase by two" via the typed API.
+    pub fn typed_unpause_increase_2(&mut self) -> bool {
+        self.pa_unpause(Feature::IncreaseByTwo)
+    }
+
+    /// Returns whether feature "Increase by two" is paused via the typed API.
+    pub fn typed_is_increase_2_paused(&self) -> bool {
+        self.pa_is_feature_paused(Feature::IncreaseByTwo)
+    }
+}
+
+/// Helpers which are not exposed as contract methods.
+impl Counter {
+    /// Predicate passed to `#[pause(when = ...)]`. Such a predicate must have the signature
+    /// `fn(&Self) -> bool`.
+    fn is_counter_big(&self) -> bool {
+        self.counter >= 3
+    }
 }
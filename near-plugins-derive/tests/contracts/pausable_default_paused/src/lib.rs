@@ -0,0 +1,58 @@
+//! A contract which launches with some of its features paused.
+
+use near_plugins::{access_control, pause, AccessControlRole, AccessControllable, Pausable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May pause and unpause features.
+    PauseManager,
+}
+
+/// Feature "increase_1" is paused until it is unpaused for the first time, while "increase_2" is
+/// unpaused by default.
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Pausable, PanicOnDefault)]
+#[pausable(manager_roles(Role::PauseManager), default_paused("increase_1"))]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes the contract itself super admin and grants `Role::PauseManager` to `pause_manager`.
+    #[init]
+    pub fn new(pause_manager: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let result = contract.acl_grant_role(Role::PauseManager.into(), pause_manager);
+        near_sdk::require!(Some(true) == result, "Failed to grant role");
+
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Can be paused using feature name "increase_1", which is paused by default.
+    #[pause]
+    pub fn increase_1(&mut self) {
+        self.counter += 1;
+    }
+
+    /// Can be paused using feature name "increase_2".
+    #[pause]
+    pub fn increase_2(&mut self) {
+        self.counter += 2;
+    }
+}
@@ -0,0 +1,58 @@
+//! A contract with a method which may be called while paused by the owner, the contract itself
+//! and grantees of a role.
+
+use near_plugins::{
+    access_control, pause, AccessControlRole, AccessControllable, Ownable, Pausable,
+};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May pause and unpause features.
+    PauseManager,
+    /// May call `increase` even when it is paused.
+    UnrestrictedIncreaser,
+}
+
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Ownable, Pausable, PanicOnDefault)]
+#[pausable(manager_roles(Role::PauseManager))]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Sets `owner` as owner, makes the contract itself super admin and grants
+    /// `Role::PauseManager` to `pause_manager`.
+    #[init]
+    pub fn new(owner: AccountId, pause_manager: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+
+        contract.owner_set(Some(owner));
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let result = contract.acl_grant_role(Role::PauseManager.into(), pause_manager);
+        near_sdk::require!(Some(true) == result, "Failed to grant role");
+
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// While paused, it may be called by the owner, the contract itself and grantees of
+    /// `Role::UnrestrictedIncreaser`.
+    #[pause(except(roles(Role::UnrestrictedIncreaser), owner, self))]
+    pub fn increase(&mut self) {
+        self.counter += 1;
+    }
+}
@@ -0,0 +1,52 @@
+//! A contract which stores the state of `Pausable` under a custom storage prefix.
+
+use near_plugins::{access_control, pause, AccessControlRole, AccessControllable, Pausable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near, AccountId, PanicOnDefault};
+
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May pause and unpause features.
+    PauseManager,
+}
+
+/// The set of paused features is stored under the key passed as `storage_prefix`, instead of the
+/// default key.
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Pausable, PanicOnDefault)]
+#[pausable(manager_roles(Role::PauseManager), storage_prefix = "__custom_pause")]
+pub struct Counter {
+    counter: u64,
+}
+
+#[near]
+impl Counter {
+    /// Makes the contract itself super admin and grants `Role::PauseManager` to `pause_manager`.
+    #[init]
+    pub fn new(pause_manager: AccountId) -> Self {
+        let mut contract = Self { counter: 0 };
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let result = contract.acl_grant_role(Role::PauseManager.into(), pause_manager);
+        near_sdk::require!(Some(true) == result, "Failed to grant role");
+
+        contract
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// Can be paused using feature name "increase_1".
+    #[pause]
+    pub fn increase_1(&mut self) {
+        self.counter += 1;
+    }
+}
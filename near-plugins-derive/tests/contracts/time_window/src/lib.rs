@@ -0,0 +1,64 @@
+//! A contract with methods that may be called only during time windows.
+
+use near_plugins::only_during;
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::{near, PanicOnDefault, Timestamp};
+
+#[near(contract_state)]
+#[derive(PanicOnDefault)]
+pub struct Counter {
+    counter: u64,
+    /// Start of the time window, in nanoseconds.
+    start: Timestamp,
+    /// End of the time window, in nanoseconds.
+    end: Timestamp,
+}
+
+#[near]
+impl Counter {
+    /// Stores the time window `[start, end)`.
+    #[init]
+    pub fn new(start: Timestamp, end: Timestamp) -> Self {
+        Self {
+            counter: 0,
+            start,
+            end,
+        }
+    }
+
+    /// Returns the value of the counter.
+    pub fn get_counter(&self) -> u64 {
+        self.counter
+    }
+
+    /// May be called only within `[start, end)`.
+    #[only_during(start = "Self::window_start", end = "Self::window_end")]
+    pub fn increase(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    /// May be called only from `start` onwards.
+    #[only_during(start = "Self::window_start")]
+    pub fn increase_after_start(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+
+    /// May be called only before `end`.
+    #[only_during(end = "Self::window_end")]
+    pub fn increase_before_end(&mut self) -> u64 {
+        self.counter += 1;
+        self.counter
+    }
+}
+
+impl Counter {
+    fn window_start(&self) -> Timestamp {
+        self.start
+    }
+
+    fn window_end(&self) -> Timestamp {
+        self.end
+    }
+}
@@ -32,6 +32,9 @@ pub enum Role {
     /// Using this pattern grantees of a single role are authorized to call multiple (but not all)
     /// protected `Upgradable` methods.
     DurationManager,
+    /// May successfully call `Upgradable::up_emergency_deploy_code`, which deploys staged code
+    /// without waiting for the staging duration to pass. It is intentionally not granted to `DAO`.
+    EmergencyDeployer,
 }
 
 /// Deriving `Upgradable` requires the contract to be `AccessControllable`.
@@ -43,13 +46,17 @@ pub enum Role {
 #[access_control(role_type(Role))]
 #[near(contract_state)]
 #[derive(Upgradable, PanicOnDefault)]
-#[upgradable(access_control_roles(
-    code_stagers(Role::CodeStager, Role::DAO),
-    code_deployers(Role::CodeDeployer, Role::DAO),
-    duration_initializers(Role::DurationManager, Role::DAO),
-    duration_update_stagers(Role::DurationManager, Role::DAO),
-    duration_update_appliers(Role::DurationManager, Role::DAO),
-))]
+#[upgradable(
+    named_slots,
+    access_control_roles(
+        code_stagers(Role::CodeStager, Role::DAO),
+        code_deployers(Role::CodeDeployer, Role::DAO),
+        duration_initializers(Role::DurationManager, Role::DAO),
+        duration_update_stagers(Role::DurationManager, Role::DAO),
+        duration_update_appliers(Role::DurationManager, Role::DAO),
+        emergency_code_deployers(Role::EmergencyDeployer),
+    )
+)]
 pub struct Contract;
 
 #[near]
@@ -85,11 +92,10 @@ impl Contract {
         // Optionally initialize the staging duration.
         if let Some(staging_duration) = staging_duration {
             // Temporarily grant `Role::DurationManager` to the contract to authorize it for
-            // initializing the staging duration. Granting and revoking the role is possible since
-            // the contract was made super admin above.
-            contract.acl_grant_role(Role::DurationManager.into(), env::current_account_id());
+            // initializing the staging duration.
+            contract.acl_grant_role_to_self(Role::DurationManager.into());
             contract.up_init_staging_duration(staging_duration);
-            contract.acl_revoke_role(Role::DurationManager.into(), env::current_account_id());
+            contract.acl_revoke_role_from_self(Role::DurationManager.into());
         }
 
         contract
@@ -0,0 +1,67 @@
+use near_plugins::{access_control, AccessControlRole, AccessControllable, Upgradable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::env;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// Defines roles for access control of protected methods provided by the `Upgradable` plugin.
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May successfully call any of the protected `Upgradable` methods.
+    DAO,
+}
+
+/// Passing a method name to the `on_deploy` attribute makes `Upgradable` call the method after
+/// every successful deployment.
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Upgradable, PanicOnDefault)]
+#[upgradable(
+    on_deploy = "on_deploy",
+    access_control_roles(
+        code_stagers(Role::DAO),
+        code_deployers(Role::DAO),
+        duration_initializers(Role::DAO),
+        duration_update_stagers(Role::DAO),
+        duration_update_appliers(Role::DAO),
+    )
+)]
+pub struct Contract {
+    deploy_count: u64,
+}
+
+#[near]
+impl Contract {
+    /// Makes the contract itself `AccessControllable` super admin and grants `Role::DAO` to `dao`.
+    #[init]
+    pub fn new(dao: AccountId) -> Self {
+        let mut contract = Self { deploy_count: 0 };
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let res = contract.acl_grant_role(Role::DAO.into(), dao);
+        assert_eq!(Some(true), res, "Failed to grant role");
+
+        contract
+    }
+
+    /// Returns the number of times the hook was called.
+    pub fn get_deploy_count(&self) -> u64 {
+        self.deploy_count
+    }
+
+    /// Can be attached to a deployment to make it fail.
+    pub fn fail_on_purpose(&mut self) {
+        env::panic_str("Failing on purpose");
+    }
+}
+
+impl Contract {
+    /// The hook passed to the `on_deploy` attribute. It is not exposed as a contract method.
+    fn on_deploy(&mut self) {
+        self.deploy_count += 1;
+    }
+}
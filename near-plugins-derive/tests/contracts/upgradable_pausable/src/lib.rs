@@ -0,0 +1,50 @@
+use near_plugins::{access_control, AccessControlRole, AccessControllable, Pausable, Upgradable};
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
+use near_sdk::env;
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{near, AccountId, PanicOnDefault};
+
+/// Defines roles for access control of protected methods provided by the `Pausable` and
+/// `Upgradable` plugins.
+#[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// May pause and unpause features and call any of the protected `Upgradable` methods.
+    DAO,
+}
+
+/// With `deploy_requires_paused`, staged code can be deployed only while "ALL" features are
+/// paused.
+#[access_control(role_type(Role))]
+#[near(contract_state)]
+#[derive(Pausable, Upgradable, PanicOnDefault)]
+#[pausable(manager_roles(Role::DAO))]
+#[upgradable(
+    deploy_requires_paused,
+    access_control_roles(
+        code_stagers(Role::DAO),
+        code_deployers(Role::DAO),
+        duration_initializers(Role::DAO),
+        duration_update_stagers(Role::DAO),
+        duration_update_appliers(Role::DAO),
+    )
+)]
+pub struct Contract;
+
+#[near]
+impl Contract {
+    /// Makes the contract itself `AccessControllable` super admin and grants `Role::DAO` to `dao`.
+    #[init]
+    pub fn new(dao: AccountId) -> Self {
+        let mut contract = Self;
+
+        near_sdk::require!(
+            contract.acl_init_super_admin(env::current_account_id()),
+            "Failed to initialize super admin",
+        );
+        let res = contract.acl_grant_role(Role::DAO.into(), dao);
+        assert_eq!(Some(true), res, "Failed to grant role");
+
+        contract
+    }
+}
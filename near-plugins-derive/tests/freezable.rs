@@ -0,0 +1,196 @@
+// Using `pub` to avoid invalid `dead_code` warnings, see
+// https://users.rust-lang.org/t/invalid-dead-code-warning-for-submodule-in-integration-test/80259
+pub mod common;
+
+use common::freezable_contract::FreezableContract;
+use common::utils::{
+    assert_failure_with, assert_insufficient_acl_permissions, assert_success_with,
+    assert_success_with_unit_return,
+};
+use near_sdk::serde_json::json;
+use near_workspaces::result::ExecutionFinalResult;
+use near_workspaces::{Account, Contract};
+use std::path::Path;
+
+const PROJECT_PATH: &str = "./tests/contracts/freezable";
+
+/// Bundles resources required in tests.
+struct Setup {
+    /// Instance of the deployed contract.
+    contract: Contract,
+    /// Wrapper around the deployed contract that facilitates interacting with
+    /// methods provided by the `Freezable` plugin.
+    freezable_contract: FreezableContract,
+    /// An account with permission to freeze and unfreeze accounts.
+    freeze_manager: Account,
+    /// A newly created account without any `AccessControllable` permissions.
+    unauth_account: Account,
+}
+
+impl Setup {
+    /// Deploys the contract in [`PROJECT_PATH`] and initializes `Setup`.
+    async fn new() -> anyhow::Result<Self> {
+        let worker = near_workspaces::sandbox().await?;
+        let wasm = common::repo::compile_project(Path::new(PROJECT_PATH), "freezable").await?;
+        let contract = worker.dev_deploy(&wasm).await?;
+        let freezable_contract = FreezableContract::new(contract.clone());
+
+        let freeze_manager = worker.dev_create_account().await?;
+        contract
+            .call("new")
+            .args_json(json!({
+                "freeze_manager": freeze_manager.id(),
+            }))
+            .max_gas()
+            .transact()
+            .await?
+            .into_result()?;
+
+        let unauth_account = worker.dev_create_account().await?;
+        Ok(Self {
+            contract,
+            freezable_contract,
+            freeze_manager,
+            unauth_account,
+        })
+    }
+
+    async fn get_counter(&self) -> anyhow::Result<u64> {
+        let res = self
+            .unauth_account
+            .call(self.contract.id(), "get_counter")
+            .view()
+            .await?;
+        Ok(res.json::<u64>()?)
+    }
+
+    async fn call_counter_modifier(
+        &self,
+        caller: &Account,
+        method_name: &str,
+    ) -> near_workspaces::Result<ExecutionFinalResult> {
+        caller
+            .call(self.contract.id(), method_name)
+            .max_gas()
+            .transact()
+            .await
+    }
+
+    async fn assert_is_frozen(&self, expected: bool, account: &Account) {
+        let is_frozen = self
+            .freezable_contract
+            .fr_is_frozen(&self.unauth_account, account.id())
+            .await
+            .unwrap();
+        assert_eq!(is_frozen, expected);
+    }
+}
+
+#[tokio::test]
+async fn test_freeze_and_unfreeze() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let account = &setup.unauth_account;
+    setup.assert_is_frozen(false, account).await;
+
+    let res = setup.call_counter_modifier(account, "increase").await?;
+    assert_success_with_unit_return(res);
+
+    // Freeze the account.
+    let res = setup
+        .freezable_contract
+        .fr_freeze_account(&setup.freeze_manager, account.id())
+        .await?;
+    let expected_event = format!(
+        r#"EVENT_JSON:{{"standard":"Freezable","version":"1.0.0","event":"freeze","data":{{"by":"{}","account":"{}"}}}}"#,
+        setup.freeze_manager.id(),
+        account.id(),
+    );
+    assert_eq!(res.logs(), vec![expected_event.as_str()]);
+    assert_success_with(res, true);
+    setup.assert_is_frozen(true, account).await;
+
+    // Freezing it again has no effect.
+    let res = setup
+        .freezable_contract
+        .fr_freeze_account(&setup.freeze_manager, account.id())
+        .await?;
+    assert!(res.logs().is_empty());
+    assert_success_with(res, false);
+
+    // Guarded methods fail for the frozen account, others still succeed.
+    let res = setup.call_counter_modifier(account, "increase").await?;
+    assert_failure_with(res, "FRZ001: Freezable: Account is frozen");
+    let res = setup
+        .call_counter_modifier(account, "increase_unguarded")
+        .await?;
+    assert_success_with_unit_return(res);
+
+    // Other accounts are not affected.
+    let res = setup
+        .call_counter_modifier(&setup.freeze_manager, "increase")
+        .await?;
+    assert_success_with_unit_return(res);
+
+    // Unfreeze the account.
+    let res = setup
+        .freezable_contract
+        .fr_unfreeze_account(&setup.freeze_manager, account.id())
+        .await?;
+    let expected_event = format!(
+        r#"EVENT_JSON:{{"standard":"Freezable","version":"1.0.0","event":"unfreeze","data":{{"by":"{}","account":"{}"}}}}"#,
+        setup.freeze_manager.id(),
+        account.id(),
+    );
+    assert_eq!(res.logs(), vec![expected_event.as_str()]);
+    assert_success_with(res, true);
+    setup.assert_is_frozen(false, account).await;
+
+    let res = setup.call_counter_modifier(account, "increase").await?;
+    assert_success_with_unit_return(res);
+    assert_eq!(setup.get_counter().await?, 4);
+
+    // Unfreezing an account which isn't frozen has no effect.
+    let res = setup
+        .freezable_contract
+        .fr_unfreeze_account(&setup.freeze_manager, account.id())
+        .await?;
+    assert_success_with(res, false);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_freeze_and_unfreeze_fail() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let account = &setup.unauth_account;
+
+    let res = setup
+        .freezable_contract
+        .fr_freeze_account(account, account.id())
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "fr_freeze_account",
+        vec!["FreezeManager".to_string()],
+    );
+    setup.assert_is_frozen(false, account).await;
+
+    let res = setup
+        .freezable_contract
+        .fr_freeze_account(&setup.freeze_manager, account.id())
+        .await?;
+    assert_success_with(res, true);
+
+    let res = setup
+        .freezable_contract
+        .fr_unfreeze_account(account, account.id())
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "fr_unfreeze_account",
+        vec!["FreezeManager".to_string()],
+    );
+    setup.assert_is_frozen(true, account).await;
+
+    Ok(())
+}
@@ -6,7 +6,7 @@ use anyhow::Ok;
 use common::key::{delete_access_key, get_access_key_infos};
 use common::ownable_contract::OwnableContract;
 use common::utils::{
-    assert_access_key_not_found_error, assert_only_owner_permission_failure,
+    assert_access_key_not_found_error, assert_failure_with, assert_only_owner_permission_failure,
     assert_ownable_permission_failure, assert_owner_update_failure, assert_success_with,
 };
 use near_sdk::serde_json::json;
@@ -16,6 +16,13 @@ use near_workspaces::{Account, AccountId, Contract, Worker};
 use std::path::Path;
 
 const PROJECT_PATH: &str = "./tests/contracts/ownable";
+const PROJECT_PATH_NO_RENOUNCE: &str = "./tests/contracts/ownable_no_renounce";
+const PROJECT_PATH_VALIDATE_OWNER: &str = "./tests/contracts/ownable_validate_owner";
+const PROJECT_PATH_NO_SELF_REENTRANT_SET: &str = "./tests/contracts/ownable_no_self_reentrant_set";
+const PROJECT_PATH_STORAGE_KEY: &str = "./tests/contracts/ownable_storage_key";
+const PROJECT_PATH_ROLES: &str = "./tests/contracts/ownable_roles";
+const PROJECT_PATH_MINIMAL_EVENTS: &str = "./tests/contracts/ownable_minimal_events";
+const PROJECT_PATH_MULTI: &str = "./tests/contracts/ownable_multi";
 
 /// Allows spinning up a setup for testing the contract in [`PROJECT_PATH`] and bundles related
 /// resources.
@@ -134,6 +141,47 @@ async fn test_owner_is() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_owner_is_account() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+    let caller = &setup.unauth_account;
+
+    // Returns true for the owner.
+    assert!(
+        setup
+            .ownable_contract
+            .owner_is_account(caller, owner.id())
+            .await?
+    );
+
+    // Returns false for an account that isn't owner.
+    assert!(
+        !setup
+            .ownable_contract
+            .owner_is_account(caller, setup.unauth_account.id())
+            .await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_is_account_without_owner() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let setup = Setup::new(worker, None).await?;
+
+    assert!(
+        !setup
+            .ownable_contract
+            .owner_is_account(&setup.unauth_account, setup.contract.id())
+            .await?
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_set_owner_ok() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -189,6 +237,426 @@ async fn test_remove_owner() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_owner_renounce() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    let res = setup.ownable_contract.owner_renounce(&owner).await?;
+    let expected_event = format!(
+        r#"EVENT_JSON:{{"standard":"Ownable","version":"1.0.0","event":"ownership_renounced","data":{{"previous_owner":"{}","by":"{}"}}}}"#,
+        owner.id(),
+        owner.id(),
+    );
+    assert_eq!(res.logs(), vec![expected_event.as_str()]);
+    res.into_result()?;
+    setup.assert_owner_is(None).await;
+
+    Ok(())
+}
+
+/// With feature `minimal-events`, the envelope of events is formatted without `serde`, which
+/// reduces the size of the wasm binary. The logged events are the same as without the feature,
+/// including their `data`.
+#[tokio::test]
+async fn test_minimal_events() -> anyhow::Result<()> {
+    let wasm = common::repo::compile_project(Path::new(PROJECT_PATH), "ownable").await?;
+    let wasm_minimal = common::repo::compile_project(
+        Path::new(PROJECT_PATH_MINIMAL_EVENTS),
+        "ownable_minimal_events",
+    )
+    .await?;
+    assert!(
+        wasm_minimal.len() < wasm.len(),
+        "wasm size: {} bytes, with `minimal-events`: {} bytes",
+        wasm.len(),
+        wasm_minimal.len(),
+    );
+
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let contract = worker.dev_deploy(&wasm_minimal).await?;
+    contract
+        .call("new")
+        .args_json(json!({ "owner": owner.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let ownable_contract = OwnableContract::new(contract);
+
+    let res = ownable_contract.owner_renounce(&owner).await?;
+    let expected_event = format!(
+        r#"EVENT_JSON:{{"standard":"Ownable","version":"1.0.0","event":"ownership_renounced","data":{{"previous_owner":"{}","by":"{}"}}}}"#,
+        owner.id(),
+        owner.id(),
+    );
+    assert_eq!(res.logs(), vec![expected_event.as_str()]);
+    res.into_result()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_renounce_fail() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    let res = setup
+        .ownable_contract
+        .owner_renounce(&setup.unauth_account)
+        .await?;
+    assert_failure_with(res, "Ownable: Only owner or self can renounce ownership");
+    setup.assert_owner_is(Some(owner.id())).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_propose_and_accept() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let new_owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    let res = setup
+        .ownable_contract
+        .owner_propose(&owner, new_owner.id())
+        .await?;
+    let expected_event = format!(
+        r#"EVENT_JSON:{{"standard":"Ownable","version":"1.0.0","event":"ownership_proposed","data":{{"owner":"{}","proposed_owner":"{}","nonce":1}}}}"#,
+        owner.id(),
+        new_owner.id(),
+    );
+    assert_eq!(res.logs(), vec![expected_event.as_str()]);
+    let nonce = res.into_result()?.json::<u64>()?;
+    assert_eq!(nonce, 1);
+    let pending = setup
+        .ownable_contract
+        .owner_get_pending(&setup.unauth_account)
+        .await?;
+    assert_eq!(pending.as_ref(), Some(new_owner.id()));
+
+    // Ownership is transferred only once the proposed owner accepts.
+    setup.assert_owner_is(Some(owner.id())).await;
+    let res = setup
+        .ownable_contract
+        .owner_accept(&setup.unauth_account, nonce)
+        .await?;
+    assert_failure_with(res, "Ownable: Only the proposed owner can accept ownership");
+    let res = setup
+        .ownable_contract
+        .owner_accept(&new_owner, nonce)
+        .await?;
+    res.into_result()?;
+    setup.assert_owner_is(Some(new_owner.id())).await;
+    let pending = setup
+        .ownable_contract
+        .owner_get_pending(&setup.unauth_account)
+        .await?;
+    assert_eq!(pending, None);
+
+    Ok(())
+}
+
+/// Re-proposing invalidates the nonce of a previous proposal.
+#[tokio::test]
+async fn test_owner_accept_stale_nonce() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let new_owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    let res = setup
+        .ownable_contract
+        .owner_propose(&setup.unauth_account, new_owner.id())
+        .await?;
+    assert_failure_with(res, "Ownable: Method must be called from owner");
+
+    let old_nonce = setup
+        .ownable_contract
+        .owner_propose(&owner, new_owner.id())
+        .await?
+        .into_result()?
+        .json::<u64>()?;
+    setup
+        .ownable_contract
+        .owner_cancel_proposal(&owner)
+        .await?
+        .into_result()?;
+    let res = setup
+        .ownable_contract
+        .owner_accept(&new_owner, old_nonce)
+        .await?;
+    assert_failure_with(res, "Ownable: No pending owner");
+
+    let nonce = setup
+        .ownable_contract
+        .owner_propose(&owner, new_owner.id())
+        .await?
+        .into_result()?
+        .json::<u64>()?;
+    assert_ne!(nonce, old_nonce);
+    let res = setup
+        .ownable_contract
+        .owner_accept(&new_owner, old_nonce)
+        .await?;
+    assert_failure_with(res, "Ownable: Invalid nonce");
+    setup.assert_owner_is(Some(owner.id())).await;
+
+    let res = setup
+        .ownable_contract
+        .owner_accept(&new_owner, nonce)
+        .await?;
+    res.into_result()?;
+    setup.assert_owner_is(Some(new_owner.id())).await;
+
+    Ok(())
+}
+
+/// With `#[ownable(allow_renounce = false)]` the owner may be transferred but not removed.
+#[tokio::test]
+async fn test_renounce_disallowed() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_NO_RENOUNCE), "ownable_no_renounce")
+            .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let ownable_contract = OwnableContract::new(contract.clone());
+    let owner = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Removing the owner fails.
+    let res = ownable_contract.owner_set(&owner, None).await?;
+    assert_failure_with(res, "Ownable: Renouncing ownership is not allowed");
+    assert_eq!(
+        ownable_contract.owner_get(&owner).await?.as_ref(),
+        Some(owner.id())
+    );
+
+    let res = ownable_contract.owner_renounce(&owner).await?;
+    assert_failure_with(res, "Ownable: Renouncing ownership is not allowed");
+
+    // Transferring ownership succeeds.
+    let new_owner = worker.dev_create_account().await?;
+    ownable_contract
+        .owner_set(&owner, Some(new_owner.id().clone()))
+        .await?
+        .into_result()?;
+    assert_eq!(
+        ownable_contract.owner_get(&owner).await?.as_ref(),
+        Some(new_owner.id())
+    );
+
+    Ok(())
+}
+
+/// The validator of the contract rejects implicit account ids and allows named accounts.
+#[tokio::test]
+async fn test_validate_owner() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_VALIDATE_OWNER),
+        "ownable_validate_owner",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let ownable_contract = OwnableContract::new(contract.clone());
+    contract
+        .call("new")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Setting an implicit account as owner fails.
+    let implicit_account_id: AccountId = "a".repeat(64).parse()?;
+    let res = ownable_contract
+        .owner_set(contract.as_account(), Some(implicit_account_id.clone()))
+        .await?;
+    assert_failure_with(res, "Ownable: Owner rejected by validator");
+    assert_eq!(
+        ownable_contract.owner_get(contract.as_account()).await?,
+        None
+    );
+
+    // Setting a named account as owner succeeds.
+    let owner = worker.dev_create_account().await?;
+    ownable_contract
+        .owner_set(contract.as_account(), Some(owner.id().clone()))
+        .await?
+        .into_result()?;
+    assert_eq!(
+        ownable_contract.owner_get(&owner).await?.as_ref(),
+        Some(owner.id())
+    );
+
+    // Proposing an implicit account as owner fails as well.
+    let res = ownable_contract
+        .owner_propose(&owner, &implicit_account_id)
+        .await?;
+    assert_failure_with(res, "Ownable: Owner rejected by validator");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_self_reentrant_set() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_NO_SELF_REENTRANT_SET),
+        "ownable_no_self_reentrant_set",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let ownable_contract = OwnableContract::new(contract.clone());
+    contract
+        .call("new")
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Setting the owner in a callback of the contract itself fails, though the owner is not set.
+    let owner = worker.dev_create_account().await?;
+    let res = owner
+        .call(contract.id(), "set_owner_in_callback")
+        .args_json(json!({ "owner": owner.id() }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_failure_with(
+        res,
+        "Ownable: Owner can't be set by the contract itself in a callback",
+    );
+    assert_eq!(ownable_contract.owner_get(&owner).await?, None);
+
+    // Outside of callbacks, the contract itself may still set the owner.
+    ownable_contract
+        .owner_set(contract.as_account(), Some(owner.id().clone()))
+        .await?
+        .into_result()?;
+    assert_eq!(
+        ownable_contract.owner_get(&owner).await?.as_ref(),
+        Some(owner.id())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_owner_storage_key_str() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(owner.id().clone())).await?;
+    assert_eq!(
+        setup
+            .ownable_contract
+            .owner_storage_key_str(&setup.unauth_account)
+            .await?,
+        "__OWNER__"
+    );
+
+    // A custom key is returned if it is set via `owner_storage_key`.
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_STORAGE_KEY), "ownable_storage_key")
+            .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let ownable_contract = OwnableContract::new(contract.clone());
+    contract
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let key = ownable_contract.owner_storage_key_str(&owner).await?;
+    assert_eq!(key, "CUSTOM_OWNER");
+
+    // The owner is stored under the key as UTF-8 bytes, as documented.
+    let state = contract.view_state().await?;
+    assert_eq!(
+        state.get(key.as_bytes()).map(Vec::as_slice),
+        Some(owner.id().as_str().as_bytes())
+    );
+
+    Ok(())
+}
+
+/// Calls `increase` of the contract in [`PROJECT_PATH_ROLES`], which is protected by
+/// `#[only(owner, self, any_of_roles(Role::Increaser))]`, and returns the result.
+async fn call_increase(
+    contract: &Contract,
+    caller: &Account,
+) -> near_workspaces::Result<ExecutionFinalResult> {
+    caller
+        .call(contract.id(), "increase")
+        .max_gas()
+        .transact()
+        .await
+}
+
+/// Exercises every kind of caller against a method that combines all conditions of `only`.
+#[tokio::test]
+async fn test_only_owner_self_any_of_roles() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_ROLES), "ownable_roles").await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let owner = worker.dev_create_account().await?;
+    let increaser = worker.dev_create_account().await?;
+    let unauth_account = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "increaser": increaser.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // The owner may call the method.
+    let res = call_increase(&contract, &owner).await?;
+    assert_success_with(res, 1);
+
+    // The contract itself may call the method.
+    let res = contract
+        .call("increase_from_self")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 2);
+
+    // A grantee of a listed role may call the method.
+    let res = call_increase(&contract, &increaser).await?;
+    assert_success_with(res, 3);
+
+    // Any other account may not call the method.
+    let res = call_increase(&contract, &unauth_account).await?;
+    assert_failure_with(
+        res,
+        "OWN001: Only: Method must be called by self or owner or a grantee of any of the roles",
+    );
+    let res = contract.view("get_counter").await?;
+    assert_eq!(res.json::<u64>()?, 3);
+
+    Ok(())
+}
+
 /// Contract itself may successfully call a method protected by `#[only(self)]`.
 #[tokio::test]
 async fn test_only_self_ok() -> anyhow::Result<()> {
@@ -275,6 +743,108 @@ async fn test_only_owner_fail() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `#[only(owner)]` works on methods taking `&self`.
+#[tokio::test]
+async fn test_only_owner_ref_self() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    let res = setup
+        .call_counter_increaser(&owner, "get_counter_only_owner")
+        .await?;
+    assert_success_with(res, 0);
+
+    let res = setup
+        .call_counter_increaser(&setup.unauth_account, "get_counter_only_owner")
+        .await?;
+    assert_only_owner_permission_failure(res);
+
+    Ok(())
+}
+
+/// `#[only(...)]` works on methods without receiver.
+#[tokio::test]
+async fn test_only_no_receiver() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+    let contract_account = setup.contract.as_account();
+
+    // `#[only(owner)]`
+    let res = setup
+        .call_counter_increaser(&owner, "no_receiver_only_owner")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .call_counter_increaser(contract_account, "no_receiver_only_owner")
+        .await?;
+    assert_only_owner_permission_failure(res);
+
+    // `#[only(self)]`
+    let res = setup
+        .call_counter_increaser(contract_account, "no_receiver_only_self")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .call_counter_increaser(&owner, "no_receiver_only_self")
+        .await?;
+    assert_ownable_permission_failure(res);
+
+    // `#[only(self, owner)]`
+    for caller in [&owner, contract_account] {
+        let res = setup
+            .call_counter_increaser(caller, "no_receiver_only_self_owner")
+            .await?;
+        assert_success_with(res, true);
+    }
+    let res = setup
+        .call_counter_increaser(&setup.unauth_account, "no_receiver_only_self_owner")
+        .await?;
+    assert_ownable_permission_failure(res);
+
+    Ok(())
+}
+
+/// `Ownable::assert_owner` guards only the section of `increase_by` that requires the owner.
+#[tokio::test]
+async fn test_assert_owner_in_method_body() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    // Anyone may increase by one.
+    let res = setup
+        .unauth_account
+        .call(setup.contract.id(), "increase_by")
+        .args_json(json!({ "value": 1 }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 1);
+
+    // Increasing by more requires the owner.
+    let res = setup
+        .unauth_account
+        .call(setup.contract.id(), "increase_by")
+        .args_json(json!({ "value": 5 }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_only_owner_permission_failure(res);
+    assert_eq!(setup.get_counter().await?, 1);
+
+    let res = owner
+        .call(setup.contract.id(), "increase_by")
+        .args_json(json!({ "value": 5 }))
+        .max_gas()
+        .transact()
+        .await?;
+    assert_success_with(res, 6);
+
+    Ok(())
+}
+
 /// Calling a method protected by `#[only(self, owner)]` succeeds if called by the contract itself
 /// or by the owner.
 #[tokio::test]
@@ -355,3 +925,131 @@ async fn test_removing_contract_keys_freezes_owner() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+/// With `#[ownable(multi)]`, any co-owner may call owner-gated methods and co-owners are managed
+/// via `owner_add` and `owner_remove`.
+#[tokio::test]
+async fn test_multi_owners() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_MULTI), "ownable_multi").await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let ownable_contract = OwnableContract::new(contract.clone());
+    let owner_1 = worker.dev_create_account().await?;
+    let owner_2 = worker.dev_create_account().await?;
+    let unauth_account = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({
+            "owner": owner_1.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Only co-owners may add co-owners.
+    let res = ownable_contract
+        .owner_add(&unauth_account, unauth_account.id())
+        .await?;
+    assert_only_owner_permission_failure(res);
+    let res = ownable_contract.owner_add(&owner_1, owner_2.id()).await?;
+    let expected_event = format!(
+        r#"EVENT_JSON:{{"standard":"Ownable","version":"1.0.0","event":"owner_added","data":{{"account":"{}","by":"{}"}}}}"#,
+        owner_2.id(),
+        owner_1.id(),
+    );
+    assert_eq!(res.logs(), vec![expected_event.as_str()]);
+    assert_success_with(res, true);
+    let res = ownable_contract.owner_add(&owner_2, owner_2.id()).await?;
+    assert_success_with(res, false);
+    assert_eq!(
+        ownable_contract.owner_get_all(&unauth_account).await?,
+        vec![owner_1.id().clone(), owner_2.id().clone()],
+    );
+    assert_eq!(
+        ownable_contract.owner_get(&unauth_account).await?,
+        Some(owner_1.id().clone()),
+    );
+
+    // Each co-owner may call owner-gated methods.
+    for (owner, expected_counter) in [(&owner_1, 1_u64), (&owner_2, 2)] {
+        let res = owner
+            .call(contract.id(), "increase")
+            .max_gas()
+            .transact()
+            .await?;
+        assert_success_with(res, expected_counter);
+        let res = owner
+            .call(contract.id(), "no_receiver_only_owner")
+            .max_gas()
+            .transact()
+            .await?;
+        assert_success_with(res, true);
+        assert!(
+            ownable_contract
+                .owner_is_account(&unauth_account, owner.id())
+                .await?
+        );
+    }
+    let res = unauth_account
+        .call(contract.id(), "increase")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_only_owner_permission_failure(res);
+
+    // A removed co-owner may no longer call owner-gated methods.
+    let res = ownable_contract
+        .owner_remove(&owner_1, owner_2.id())
+        .await?;
+    assert_success_with(res, true);
+    let res = ownable_contract
+        .owner_remove(&owner_1, owner_2.id())
+        .await?;
+    assert_success_with(res, false);
+    assert_eq!(
+        ownable_contract.owner_get_all(&unauth_account).await?,
+        vec![owner_1.id().clone()],
+    );
+    let res = owner_2
+        .call(contract.id(), "increase")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_only_owner_permission_failure(res);
+    let res = ownable_contract
+        .owner_remove(&owner_2, owner_1.id())
+        .await?;
+    assert_only_owner_permission_failure(res);
+
+    Ok(())
+}
+
+/// Without `#[ownable(multi)]`, co-owners can't be managed.
+#[tokio::test]
+async fn test_multi_owners_disabled() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let owner = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(owner.id().clone())).await?;
+
+    let res = setup
+        .ownable_contract
+        .owner_add(&owner, setup.unauth_account.id())
+        .await?;
+    assert_failure_with(res, "Ownable: Co-owners are disabled");
+    let res = setup
+        .ownable_contract
+        .owner_remove(&owner, owner.id())
+        .await?;
+    assert_failure_with(res, "Ownable: Co-owners are disabled");
+    assert_eq!(
+        setup
+            .ownable_contract
+            .owner_get_all(&setup.unauth_account)
+            .await?,
+        vec![owner.id().clone()],
+    );
+
+    Ok(())
+}
@@ -5,17 +5,23 @@ pub mod common;
 use common::access_controllable_contract::AccessControllableContract;
 use common::pausable_contract::PausableContract;
 use common::utils::{
-    assert_failure_with, assert_insufficient_acl_permissions, assert_method_is_paused,
-    assert_pausable_escape_hatch_is_closed, assert_success_with, assert_success_with_unit_return,
+    assert_failure_with, assert_feature_must_not_be_paused, assert_insufficient_acl_permissions,
+    assert_method_is_paused, assert_pausable_escape_hatch_is_closed, assert_success_with,
+    assert_success_with_unit_return, get_transaction_block,
 };
+use near_plugins::pausable::FeatureStatus;
+use near_plugins::PluginError;
 use near_sdk::serde_json::json;
 use near_workspaces::network::Sandbox;
-use near_workspaces::result::ExecutionFinalResult;
+use near_workspaces::result::{ExecutionFinalResult, ViewResultDetails};
 use near_workspaces::{Account, AccountId, Contract, Worker};
 use std::collections::HashSet;
 use std::path::Path;
 
 const PROJECT_PATH: &str = "./tests/contracts/pausable";
+const PROJECT_PATH_STORAGE_PREFIX: &str = "./tests/contracts/pausable_storage_prefix";
+const PROJECT_PATH_DEFAULT_PAUSED: &str = "./tests/contracts/pausable_default_paused";
+const PROJECT_PATH_EXCEPT: &str = "./tests/contracts/pausable_except";
 
 /// Bundles resources required in tests.
 struct Setup {
@@ -90,6 +96,30 @@ impl Setup {
         Ok(res.json::<u64>()?)
     }
 
+    /// Calls the view `get_counter_pausable`, which is guarded by `#[pause]`.
+    async fn get_counter_pausable(&self) -> near_workspaces::Result<ViewResultDetails> {
+        self.unauth_account
+            .call(
+                self.pausable_contract.contract().id(),
+                "get_counter_pausable",
+            )
+            .view()
+            .await
+    }
+
+    /// Calls the view `typed_is_increase_2_paused`, which uses the typed API of `Pausable`.
+    async fn typed_is_increase_2_paused(&self) -> anyhow::Result<bool> {
+        let res = self
+            .unauth_account
+            .call(
+                self.pausable_contract.contract().id(),
+                "typed_is_increase_2_paused",
+            )
+            .view()
+            .await?;
+        Ok(res.json::<bool>()?)
+    }
+
     /// Calls one of the methods that increases or decreases the counter with signature:
     ///
     /// ```ignore
@@ -208,6 +238,200 @@ async fn test_pause_not_allowed_from_self() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Returns the data of the events with name `event` emitted by the `Pausable` plugin.
+fn pausable_events(res: &ExecutionFinalResult, event: &str) -> Vec<near_sdk::serde_json::Value> {
+    res.logs()
+        .into_iter()
+        .filter_map(|log| log.strip_prefix("EVENT_JSON:"))
+        .map(|log| near_sdk::serde_json::from_str::<near_sdk::serde_json::Value>(log).unwrap())
+        .filter(|e| e["standard"] == "Pausable" && e["event"] == event)
+        .map(|e| e["data"].clone())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_pause_feature_with_reason() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature_with_reason(&setup.pause_manager, "increase_1", "incident #42")
+        .await?;
+    let events = pausable_events(&res, "pause");
+    assert_eq!(
+        events,
+        vec![json!({
+            "by": setup.pause_manager.id(),
+            "key": "increase_1",
+            "reason": "incident #42",
+        })]
+    );
+    assert_success_with(res, true);
+
+    // Without a reason, the field is omitted from the event.
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "increase_2")
+        .await?;
+    let events = pausable_events(&res, "pause");
+    assert_eq!(
+        events,
+        vec![json!({
+            "by": setup.pause_manager.id(),
+            "key": "increase_2",
+        })]
+    );
+
+    let res = setup
+        .pausable_contract
+        .pa_unpause_feature(&setup.pause_manager, "increase_1")
+        .await?;
+    let events = pausable_events(&res, "unpause");
+    assert_eq!(
+        events,
+        vec![json!({
+            "by": setup.pause_manager.id(),
+            "key": "increase_1",
+        })]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_all_feature_names() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let names = setup
+        .pausable_contract
+        .pa_all_feature_names(&setup.unauth_account)
+        .await?;
+    assert_eq!(
+        names,
+        Some(
+            [
+                "increase_1",
+                "Increase by two",
+                "increase_4",
+                "increase_big",
+                "trading",
+                "get_counter_pausable",
+                "trade_1",
+                "trade_2"
+            ]
+            .map(String::from)
+            .to_vec()
+        )
+    );
+    Ok(())
+}
+
+/// Returns the timestamp of the block in which the first receipt of `res` was executed.
+async fn receipt_block_timestamp(worker: &Worker<Sandbox>, res: &ExecutionFinalResult) -> u64 {
+    let receipt = res
+        .receipt_outcomes()
+        .first()
+        .expect("There should be at least one receipt outcome");
+    get_transaction_block(worker, receipt)
+        .await
+        .expect("Should retrieve the transaction's block")
+        .timestamp()
+}
+
+#[tokio::test]
+async fn test_feature_status() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let key = "increase_1";
+
+    // The feature was never paused.
+    let status = setup
+        .pausable_contract
+        .pa_feature_status(&setup.unauth_account, key)
+        .await?;
+    assert_eq!(status, None);
+
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, key)
+        .await?;
+    let paused_at = receipt_block_timestamp(&setup.worker, &res).await;
+    assert_success_with(res, true);
+    let status = setup
+        .pausable_contract
+        .pa_feature_status(&setup.unauth_account, key)
+        .await?;
+    assert_eq!(
+        status,
+        Some(FeatureStatus {
+            paused: true,
+            last_changed: paused_at,
+            by: setup.pause_manager.id().clone(),
+        })
+    );
+
+    // Unpausing is recorded as well.
+    let res = setup
+        .pausable_contract
+        .pa_unpause_feature(&setup.pause_manager, key)
+        .await?;
+    let unpaused_at = receipt_block_timestamp(&setup.worker, &res).await;
+    assert_success_with(res, true);
+    assert!(unpaused_at > paused_at);
+    let status = setup
+        .pausable_contract
+        .pa_feature_status(&setup.unauth_account, key)
+        .await?;
+    assert_eq!(
+        status,
+        Some(FeatureStatus {
+            paused: false,
+            last_changed: unpaused_at,
+            by: setup.pause_manager.id().clone(),
+        })
+    );
+
+    // Calls which don't change the pause state are not recorded.
+    let res = setup
+        .pausable_contract
+        .pa_unpause_feature(&setup.pause_manager, key)
+        .await?;
+    assert_success_with(res, false);
+    let status = setup
+        .pausable_contract
+        .pa_feature_status(&setup.unauth_account, key)
+        .await?;
+    assert_eq!(status.map(|status| status.last_changed), Some(unpaused_at));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_feature_exempt_roles() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    // Matches `except(roles(...))` of `increase_4`.
+    let roles = setup
+        .pausable_contract
+        .pa_feature_exempt_roles(&setup.unauth_account, "increase_4")
+        .await?;
+    assert_eq!(
+        roles,
+        Some(vec![
+            "Unrestricted4Increaser".to_string(),
+            "Unrestricted4Modifier".to_string()
+        ])
+    );
+
+    // Features without exempt roles.
+    for key in ["increase_1", "unknown"] {
+        let roles = setup
+            .pausable_contract
+            .pa_feature_exempt_roles(&setup.unauth_account, key)
+            .await?;
+        assert_eq!(roles, Some(vec![]));
+    }
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_unpause_feature() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -300,6 +524,110 @@ async fn test_pause_with_all() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `#[pause]` can be applied to `&self` methods to gate view calls.
+#[tokio::test]
+async fn test_pause_view() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let res = setup.get_counter_pausable().await?;
+    assert_eq!(res.json::<u64>()?, 0);
+
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "get_counter_pausable")
+        .await?;
+    assert_success_with(res, true);
+    let err = setup
+        .get_counter_pausable()
+        .await
+        .expect_err("View of a paused method should fail");
+    assert!(
+        PluginError::Paused.is_contained_in(&err.to_string()),
+        "Unexpected error: {}",
+        err
+    );
+
+    // Views which are not guarded are not affected.
+    assert_eq!(setup.get_counter().await?, 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pause_group() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    // Pausing the group blocks all of its methods.
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "trading")
+        .await?;
+    assert_success_with(res, true);
+    for method in ["trade_1", "trade_2"] {
+        let res = setup
+            .call_counter_modifier(&setup.unauth_account, method)
+            .await?;
+        assert_method_is_paused(res);
+    }
+    assert_eq!(setup.get_counter().await?, 0);
+
+    // Methods of the group can be paused individually, too.
+    let res = setup
+        .pausable_contract
+        .pa_unpause_feature(&setup.pause_manager, "trading")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "trade_2")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "trade_1")
+        .await?;
+    assert_success_with_unit_return(res);
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "trade_2")
+        .await?;
+    assert_method_is_paused(res);
+    assert_eq!(setup.get_counter().await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pause_with_custom_message() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "increase_2_with_message")
+        .await?;
+    assert_success_with_unit_return(res);
+    assert_eq!(setup.get_counter().await?, 2);
+
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "Increase by two")
+        .await?;
+    assert_success_with(res, true);
+
+    // The method panics with its custom message while other methods keep the default message.
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "increase_2_with_message")
+        .await?;
+    // Custom messages are prefixed with the error code, too.
+    let must_contain = format!(
+        "{}: Increasing by two is temporarily halted",
+        PluginError::Paused.code()
+    );
+    assert_failure_with(res, &must_contain);
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "increase_2")
+        .await?;
+    assert_method_is_paused(res);
+    assert_eq!(setup.get_counter().await?, 2);
+
+    Ok(())
+}
+
 /// Verify `except` escape hatch works when the feature is paused via `ALL`.
 #[tokio::test]
 async fn test_pause_with_all_allows_except() -> anyhow::Result<()> {
@@ -323,6 +651,91 @@ async fn test_pause_with_all_allows_except() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_unpaused() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    // Succeeds while "increase_1" is not paused.
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "double_increase_1")
+        .await?;
+    assert_success_with_unit_return(res);
+    assert_eq!(setup.get_counter().await?, 2);
+
+    // Fails once "increase_1" is paused.
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "increase_1")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "double_increase_1")
+        .await?;
+    assert_feature_must_not_be_paused(res, "increase_1");
+
+    // Succeeds again after unpausing.
+    let res = setup
+        .pausable_contract
+        .pa_unpause_feature(&setup.pause_manager, "increase_1")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "double_increase_1")
+        .await?;
+    assert_success_with_unit_return(res);
+    assert_eq!(setup.get_counter().await?, 4);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unpaused_with_all() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "ALL")
+        .await?;
+    assert_success_with(res, true);
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "double_increase_1")
+        .await?;
+    assert_feature_must_not_be_paused(res, "increase_1");
+    assert_eq!(setup.get_counter().await?, 0);
+    Ok(())
+}
+
+/// The feature is paused after `increase_1_in_callback` is entered and before its callback is
+/// executed. The callback acts on the pause state captured on entry.
+#[tokio::test]
+async fn test_pause_snapshot_in_callback() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    setup
+        .must_grant_acl_role("PauseManager", setup.contract.id())
+        .await;
+
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "increase_1_in_callback")
+        .await?;
+    // The callback returns whether the feature is paused when it is executed.
+    assert_success_with(res, true);
+    assert_eq!(setup.get_counter().await?, 1);
+    assert!(
+        setup
+            .pausable_contract
+            .pa_is_paused(&setup.unauth_account, "increase_1")
+            .await?
+    );
+
+    // Now that the feature is paused, the method can't be entered.
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "increase_1_in_callback")
+        .await?;
+    assert_method_is_paused(res);
+    assert_eq!(setup.get_counter().await?, 1);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_not_paused_with_different_key() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -428,6 +841,94 @@ async fn test_paused_list() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_set_features() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.pausable_contract;
+
+    let res = contract
+        .pa_set_features(&setup.pause_manager, &["feature_c"], &[])
+        .await?;
+    assert_success_with(res, true);
+
+    // Pause two features and unpause another one in a single transaction.
+    let res = contract
+        .pa_set_features(
+            &setup.pause_manager,
+            &["feature_a", "feature_b"],
+            &["feature_c"],
+        )
+        .await?;
+    // Events are emitted in the order of the passed features.
+    assert_eq!(
+        pausable_events(&res, "pause"),
+        vec![
+            json!({ "by": setup.pause_manager.id(), "key": "feature_a" }),
+            json!({ "by": setup.pause_manager.id(), "key": "feature_b" }),
+        ]
+    );
+    assert_eq!(
+        pausable_events(&res, "unpause"),
+        vec![json!({ "by": setup.pause_manager.id(), "key": "feature_c" })]
+    );
+    assert_success_with(res, true);
+    assert_paused_list(
+        Some(HashSet::from([
+            "feature_a".to_string(),
+            "feature_b".to_string(),
+        ])),
+        contract,
+        &setup.unauth_account,
+    )
+    .await;
+
+    // Without changes of the pause state, no events are emitted.
+    let res = contract
+        .pa_set_features(&setup.pause_manager, &["feature_a"], &["feature_c"])
+        .await?;
+    assert!(res.logs().is_empty());
+    assert_success_with(res, false);
+
+    // A feature must not be paused and unpaused at once.
+    let res = contract
+        .pa_set_features(
+            &setup.pause_manager,
+            &["feature_c", "feature_d"],
+            &["feature_a", "feature_d"],
+        )
+        .await?;
+    assert_failure_with(
+        res,
+        "Pausable: A feature must not be both paused and unpaused",
+    );
+
+    // Changes require a manager role.
+    let res = contract
+        .pa_set_features(&setup.unauth_account, &["feature_c"], &[])
+        .await?;
+    assert_insufficient_acl_permissions(res, "pa_set_features", vec!["PauseManager".to_string()]);
+
+    // Failed calls did not modify state.
+    assert_paused_list(
+        Some(HashSet::from([
+            "feature_a".to_string(),
+            "feature_b".to_string(),
+        ])),
+        contract,
+        &setup.unauth_account,
+    )
+    .await;
+
+    // Unpausing the remaining features leaves no paused features.
+    let res = contract
+        .pa_set_features(&setup.pause_manager, &[], &["feature_a", "feature_b"])
+        .await?;
+    assert_success_with(res, true);
+    assert_paused_list(None, contract, &setup.unauth_account).await;
+
+    Ok(())
+}
+
 async fn assert_is_paused(expected: bool, key: &str, contract: &PausableContract, caller: Account) {
     let is_paused = contract.pa_is_paused(&caller, key).await.unwrap();
     assert_eq!(is_paused, expected);
@@ -474,6 +975,40 @@ async fn test_is_paused() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_is_paused_any_and_all() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    let contract = &setup.pausable_contract;
+    let viewer = &setup.unauth_account;
+
+    // Nothing is paused.
+    assert!(!contract.pa_is_paused_any(viewer, &["a", "b"]).await?);
+    assert!(!contract.pa_is_paused_all(viewer, &["a", "b"]).await?);
+
+    // Mixed set of paused and unpaused keys.
+    let res = contract.pa_pause_feature(&setup.pause_manager, "a").await?;
+    assert_success_with(res, true);
+    assert!(contract.pa_is_paused_any(viewer, &["a", "b"]).await?);
+    assert!(!contract.pa_is_paused_all(viewer, &["a", "b"]).await?);
+    assert!(contract.pa_is_paused_all(viewer, &["a"]).await?);
+    assert!(!contract.pa_is_paused_any(viewer, &["b", "c"]).await?);
+
+    // Empty sets.
+    assert!(!contract.pa_is_paused_any(viewer, &[]).await?);
+    assert!(contract.pa_is_paused_all(viewer, &[]).await?);
+
+    // With "ALL" paused, every key is considered paused.
+    let res = contract
+        .pa_pause_feature(&setup.pause_manager, "ALL")
+        .await?;
+    assert_success_with(res, true);
+    assert!(contract.pa_is_paused_any(viewer, &["b", "c"]).await?);
+    assert!(contract.pa_is_paused_all(viewer, &["a", "b", "c"]).await?);
+    assert!(!contract.pa_is_paused_any(viewer, &[]).await?);
+
+    Ok(())
+}
+
 /// Pausing method name has no effect if the method has a custom feature name.
 #[tokio::test]
 async fn test_pause_custom_name_ok() -> anyhow::Result<()> {
@@ -512,6 +1047,59 @@ async fn test_pause_custom_name_fail() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// The typed API generated via `feature_enum` operates on the same keys as the string-based API.
+#[tokio::test]
+async fn test_pause_typed_feature() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    assert!(!setup.typed_is_increase_2_paused().await?);
+
+    // Like `pa_pause_feature`, the typed API requires permissions.
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "typed_pause_increase_2")
+        .await?;
+    assert_insufficient_acl_permissions(res, "pa_pause_feature", vec!["PauseManager".to_string()]);
+
+    // Pausing via the typed API is visible to the string-based API and vice versa.
+    let res = setup
+        .call_counter_modifier(&setup.pause_manager, "typed_pause_increase_2")
+        .await?;
+    assert_success_with(res, true);
+    assert!(setup.typed_is_increase_2_paused().await?);
+    assert_is_paused(
+        true,
+        "Increase by two",
+        &setup.pausable_contract,
+        setup.unauth_account.clone(),
+    )
+    .await;
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "increase_2")
+        .await?;
+    assert_method_is_paused(res);
+
+    let res = setup
+        .pausable_contract
+        .pa_unpause_feature(&setup.pause_manager, "Increase by two")
+        .await?;
+    assert_success_with(res, true);
+    assert!(!setup.typed_is_increase_2_paused().await?);
+
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "Increase by two")
+        .await?;
+    assert_success_with(res, true);
+    assert!(setup.typed_is_increase_2_paused().await?);
+
+    let res = setup
+        .call_counter_modifier(&setup.pause_manager, "typed_unpause_increase_2")
+        .await?;
+    assert_success_with(res, true);
+    assert!(!setup.typed_is_increase_2_paused().await?);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_pause_except_ok() -> anyhow::Result<()> {
     let setup = Setup::new().await?;
@@ -609,6 +1197,48 @@ async fn test_custom_big_fail() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_pause_when_ok() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+    for _ in 0..5 {
+        let res = setup
+            .call_counter_modifier(&setup.unauth_account, "declarative_careful_increase")
+            .await?;
+        assert_success_with_unit_return(res);
+    }
+    assert_eq!(setup.get_counter().await?, 5);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_pause_when_fail() -> anyhow::Result<()> {
+    let setup = Setup::new().await?;
+
+    // Pause feature.
+    let res = setup
+        .pausable_contract
+        .pa_pause_feature(&setup.pause_manager, "increase_big")
+        .await?;
+    assert_success_with(res, true);
+
+    // Counter can still be increased while the predicate returns `false`.
+    for _ in 0..3 {
+        let res = setup
+            .call_counter_modifier(&setup.unauth_account, "declarative_careful_increase")
+            .await?;
+        assert_success_with_unit_return(res);
+    }
+
+    // Once the predicate returns `true` the method is paused.
+    let res = setup
+        .call_counter_modifier(&setup.unauth_account, "declarative_careful_increase")
+        .await?;
+    assert_method_is_paused(res);
+    assert_eq!(setup.get_counter().await?, 3);
+
+    Ok(())
+}
+
 /// Calling the method succeeds if the corresponding feature is paused.
 #[tokio::test]
 async fn test_escape_hatch_ok() -> anyhow::Result<()> {
@@ -648,3 +1278,191 @@ async fn test_escape_hatch_fail() -> anyhow::Result<()> {
     assert_pausable_escape_hatch_is_closed(res, "increase_1");
     Ok(())
 }
+
+/// Pausing works as expected if the contract sets a custom `storage_prefix`, which is returned by
+/// `pa_storage_key`.
+#[tokio::test]
+async fn test_custom_storage_prefix() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_STORAGE_PREFIX),
+        "pausable_storage_prefix",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let pausable_contract = PausableContract::new(contract.clone());
+
+    let pause_manager = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({
+            "pause_manager": pause_manager.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let unauth_account = worker.dev_create_account().await?;
+
+    let storage_key = pausable_contract.pa_storage_key(&unauth_account).await?;
+    assert_eq!(storage_key, b"__custom_pause".to_vec());
+
+    let res = pausable_contract
+        .pa_pause_feature(&pause_manager, "increase_1")
+        .await?;
+    assert_success_with(res, true);
+    let res = unauth_account
+        .call(contract.id(), "increase_1")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_method_is_paused(res);
+
+    // The set of paused features is stored under the custom key.
+    let state = contract.view_state().prefix(b"__custom_pause").await?;
+    assert_eq!(state.len(), 1);
+
+    Ok(())
+}
+
+/// With `except(roles(...), owner, self)`, each exempted caller may call a paused method.
+#[tokio::test]
+async fn test_pause_except_roles_owner_self() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_EXCEPT), "pausable_except").await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let pausable_contract = PausableContract::new(contract.clone());
+    let acl_contract = AccessControllableContract::new(contract.clone());
+
+    let owner = worker.dev_create_account().await?;
+    let pause_manager = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({
+            "owner": owner.id(),
+            "pause_manager": pause_manager.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let grantee = worker.dev_create_account().await?;
+    let res = acl_contract
+        .acl_grant_role(contract.as_account(), "UnrestrictedIncreaser", grantee.id())
+        .await?;
+    assert_eq!(res, Some(true));
+    let unauth_account = worker.dev_create_account().await?;
+
+    let res = pausable_contract
+        .pa_pause_feature(&pause_manager, "increase")
+        .await?;
+    assert_success_with(res, true);
+
+    // Accounts without exemption can't call the paused method, while each exempted caller may.
+    for (caller, is_exempted) in [
+        (&unauth_account, false),
+        (&pause_manager, false),
+        (&owner, true),
+        (contract.as_account(), true),
+        (&grantee, true),
+    ] {
+        let res = caller
+            .call(contract.id(), "increase")
+            .max_gas()
+            .transact()
+            .await?;
+        if is_exempted {
+            assert_success_with_unit_return(res);
+        } else {
+            assert_method_is_paused(res);
+        }
+    }
+    let counter = unauth_account
+        .call(contract.id(), "get_counter")
+        .view()
+        .await?
+        .json::<u64>()?;
+    assert_eq!(counter, 3);
+
+    // Without `features`, the features of the contract are unknown.
+    let names = pausable_contract
+        .pa_all_feature_names(&unauth_account)
+        .await?;
+    assert_eq!(names, None);
+
+    // Without `exempt_roles`, the roles exempted from pausing are unknown.
+    let roles = pausable_contract
+        .pa_feature_exempt_roles(&unauth_account, "increase")
+        .await?;
+    assert_eq!(roles, None);
+
+    Ok(())
+}
+
+/// Features listed in `default_paused` are paused right after initialization, until they are
+/// unpaused for the first time.
+#[tokio::test]
+async fn test_default_paused() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_DEFAULT_PAUSED),
+        "pausable_default_paused",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let pausable_contract = PausableContract::new(contract.clone());
+
+    let pause_manager = worker.dev_create_account().await?;
+    contract
+        .call("new")
+        .args_json(json!({
+            "pause_manager": pause_manager.id(),
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let unauth_account = worker.dev_create_account().await?;
+    let call = |method_name: &'static str| {
+        unauth_account
+            .call(contract.id(), method_name)
+            .max_gas()
+            .transact()
+    };
+
+    assert!(
+        pausable_contract
+            .pa_is_paused(&unauth_account, "increase_1")
+            .await?
+    );
+    assert!(
+        !pausable_contract
+            .pa_is_paused(&unauth_account, "increase_2")
+            .await?
+    );
+    assert_eq!(
+        pausable_contract.pa_all_paused(&unauth_account).await?,
+        Some(HashSet::from(["increase_1".to_string()])),
+    );
+    assert_method_is_paused(call("increase_1").await?);
+    assert_success_with_unit_return(call("increase_2").await?);
+
+    // Once unpaused, the feature remains unpaused.
+    let res = pausable_contract
+        .pa_unpause_feature(&pause_manager, "increase_1")
+        .await?;
+    assert_success_with(res, true);
+    assert!(
+        !pausable_contract
+            .pa_is_paused(&unauth_account, "increase_1")
+            .await?
+    );
+    assert_eq!(
+        pausable_contract.pa_all_paused(&unauth_account).await?,
+        Some(HashSet::new()),
+    );
+    assert_success_with_unit_return(call("increase_1").await?);
+
+    Ok(())
+}
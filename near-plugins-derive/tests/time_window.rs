@@ -0,0 +1,90 @@
+// Using `pub` to avoid invalid `dead_code` warnings, see
+// https://users.rust-lang.org/t/invalid-dead-code-warning-for-submodule-in-integration-test/80259
+pub mod common;
+
+use common::utils::{
+    assert_failure_with, assert_success_with, block_timestamp, fast_forward_beyond,
+    sdk_duration_from_secs,
+};
+use near_plugins::PluginError;
+use near_sdk::serde_json::json;
+use near_workspaces::result::ExecutionFinalResult;
+use near_workspaces::{Account, Contract};
+use std::path::Path;
+
+const PROJECT_PATH: &str = "./tests/contracts/time_window";
+
+const ERR_MSG_NOT_STARTED: &str = "Time window: Method may not be called yet";
+const ERR_MSG_ENDED: &str = "Time window: Method may no longer be called";
+
+async fn call_counter_increaser(
+    contract: &Contract,
+    caller: &Account,
+    method_name: &str,
+) -> near_workspaces::Result<ExecutionFinalResult> {
+    caller
+        .call(contract.id(), method_name)
+        .max_gas()
+        .transact()
+        .await
+}
+
+/// Asserts a call failed because it was outside of the time window.
+fn assert_outside_window(res: ExecutionFinalResult, message: &str) {
+    assert_failure_with(
+        res,
+        &format!("{}: {}", PluginError::OutsideTimeWindow.code(), message),
+    );
+}
+
+/// Calls methods before, during and after the time window `[start, end)`, including methods with
+/// open-ended windows.
+#[tokio::test]
+async fn test_only_during() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm = common::repo::compile_project(Path::new(PROJECT_PATH), "time_window").await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    let account = worker.dev_create_account().await?;
+
+    let window_offset = sdk_duration_from_secs(60);
+    let start = block_timestamp(&worker).await + window_offset;
+    let end = start + window_offset;
+    contract
+        .call("new")
+        .args_json(json!({
+            "start": start,
+            "end": end,
+        }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // Before the window.
+    let res = call_counter_increaser(&contract, &account, "increase").await?;
+    assert_outside_window(res, ERR_MSG_NOT_STARTED);
+    let res = call_counter_increaser(&contract, &account, "increase_after_start").await?;
+    assert_outside_window(res, ERR_MSG_NOT_STARTED);
+    let res = call_counter_increaser(&contract, &account, "increase_before_end").await?;
+    assert_success_with(res, 1);
+
+    // Inside the window.
+    fast_forward_beyond(&worker, window_offset).await;
+    let res = call_counter_increaser(&contract, &account, "increase").await?;
+    assert_success_with(res, 2);
+    let res = call_counter_increaser(&contract, &account, "increase_after_start").await?;
+    assert_success_with(res, 3);
+    let res = call_counter_increaser(&contract, &account, "increase_before_end").await?;
+    assert_success_with(res, 4);
+
+    // After the window.
+    fast_forward_beyond(&worker, window_offset).await;
+    let res = call_counter_increaser(&contract, &account, "increase").await?;
+    assert_outside_window(res, ERR_MSG_ENDED);
+    let res = call_counter_increaser(&contract, &account, "increase_after_start").await?;
+    assert_success_with(res, 5);
+    let res = call_counter_increaser(&contract, &account, "increase_before_end").await?;
+    assert_outside_window(res, ERR_MSG_ENDED);
+
+    Ok(())
+}
@@ -4,13 +4,14 @@ pub mod common;
 
 use anyhow::Ok;
 use common::access_controllable_contract::AccessControllableContract;
+use common::pausable_contract::PausableContract;
 use common::upgradable_contract::UpgradableContract;
 use common::utils::{
     assert_failure_with, assert_insufficient_acl_permissions, assert_method_not_found_failure,
-    assert_success_with, assert_success_with_unit_return, fast_forward_beyond,
-    get_transaction_block, sdk_duration_from_secs,
+    assert_private_method_failure, assert_success_with, assert_success_with_unit_return,
+    block_timestamp, fast_forward_beyond, get_transaction_block, sdk_duration_from_secs,
 };
-use near_plugins::upgradable::FunctionCallArgs;
+use near_plugins::upgradable::{FunctionCallArgs, UpgradableDurationStatusJson};
 use near_sdk::serde_json::json;
 use near_sdk::{CryptoHash, Duration, Gas, NearToken, Timestamp};
 use near_workspaces::network::Sandbox;
@@ -21,11 +22,23 @@ use std::path::Path;
 const PROJECT_PATH: &str = "./tests/contracts/upgradable";
 const PROJECT_PATH_2: &str = "./tests/contracts/upgradable_2";
 const PROJECT_PATH_STATE_MIGRATION: &str = "./tests/contracts/upgradable_state_migration";
+const PROJECT_PATH_ON_DEPLOY: &str = "./tests/contracts/upgradable_on_deploy";
+const PROJECT_PATH_PAUSABLE: &str = "./tests/contracts/upgradable_pausable";
+const PROJECT_PATH_NO_OVERWRITE_STAGED: &str = "./tests/contracts/upgradable_no_overwrite_staged";
+const PROJECT_PATH_CODE_REGISTRY: &str = "./tests/contracts/code_registry";
 
 const ERR_MSG_NO_STAGING_TS: &str = "Upgradable: staging timestamp isn't set";
-const ERR_MSG_DEPLOY_CODE_TOO_EARLY: &str = "Upgradable: Deploy code too early: staging ends on";
+const ERR_MSG_DEPLOY_CODE_TOO_EARLY: &str =
+    "UPG001: Upgradable: Deploy code too early: staging ends on";
 const ERR_MSG_UPDATE_DURATION_TOO_EARLY: &str =
-    "Upgradable: Update duration too early: staging ends on";
+    "UPG003: Upgradable: Update duration too early: staging ends on";
+const ERR_MSG_DEPLOY_REQUIRES_PAUSED: &str =
+    "UPG002: Upgradable: Deployment requires the contract to be paused";
+const ERR_MSG_NOT_WASM: &str = "Upgradable: Staged code is not a wasm module";
+const ERR_MSG_ALREADY_STAGED: &str = "Upgradable: Code is already staged";
+
+/// The smallest well-formed wasm module, consisting only of the magic number and the version.
+const MINIMAL_WASM: &[u8] = b"\0asm\x01\x00\x00\x00";
 
 /// Allows spinning up a setup for testing the contract in [`PROJECT_PATH`] and bundles related
 /// resources.
@@ -203,6 +216,12 @@ impl Setup {
     }
 }
 
+/// Asserts staging succeeded and returned the hash of `code`, or `None` if `code` is empty.
+fn assert_staged_with_hash(res: ExecutionFinalResult, code: &[u8]) {
+    let expected = (!code.is_empty()).then(|| convert_code_to_crypto_hash(code));
+    assert_success_with(res, expected);
+}
+
 /// Panics if the conversion fails.
 fn convert_code_to_crypto_hash(code: &[u8]) -> CryptoHash {
     near_sdk::env::sha256(code)
@@ -261,7 +280,7 @@ async fn test_stage_code_without_delay() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res.clone());
+    assert_staged_with_hash(res.clone(), &code);
 
     // Verify code was staged.
     let staged = setup
@@ -293,7 +312,7 @@ async fn test_stage_code_with_delay() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res.clone());
+    assert_staged_with_hash(res.clone(), &code);
 
     // Verify code was staged.
     let staged = setup
@@ -314,6 +333,82 @@ async fn test_stage_code_with_delay() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_stage_code_with_deploy_timestamp() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let staging_duration = sdk_duration_from_secs(2);
+    let setup = Setup::new(
+        worker.clone(),
+        Some(dao.id().clone()),
+        Some(staging_duration),
+    )
+    .await?;
+
+    // Stage code with a deploy timestamp that is later than the end of the staging duration.
+    let delay = sdk_duration_from_secs(10);
+    let deploy_timestamp = block_timestamp(&worker).await + delay;
+    let code = MINIMAL_WASM.to_vec();
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_at(&dao, code.clone(), deploy_timestamp)
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // The explicit deploy timestamp wins.
+    setup.assert_staging_timestamp(Some(deploy_timestamp)).await;
+
+    // Passing the staging duration is not sufficient for deployment.
+    fast_forward_beyond(&worker, staging_duration).await;
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_DEPLOY_CODE_TOO_EARLY);
+
+    // Deployment succeeds once the deploy timestamp is reached.
+    fast_forward_beyond(&worker, delay - staging_duration).await;
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stage_code_with_deploy_timestamp_before_staging_duration() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let staging_duration = sdk_duration_from_secs(42);
+    let setup = Setup::new(
+        worker.clone(),
+        Some(dao.id().clone()),
+        Some(staging_duration),
+    )
+    .await?;
+
+    // Stage code with a deploy timestamp that is earlier than the end of the staging duration.
+    let deploy_timestamp = block_timestamp(&worker).await;
+    let code = vec![1, 2, 3];
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_at(&dao, code.clone(), deploy_timestamp)
+        .await?;
+    assert_staged_with_hash(res.clone(), &code);
+
+    // The staging duration wins.
+    let staging_timestamp = setup
+        .expected_staging_timestamp(res, staging_duration)
+        .await;
+    assert!(staging_timestamp > deploy_timestamp);
+    setup
+        .assert_staging_timestamp(Some(staging_timestamp))
+        .await;
+
+    // Deployment fails before the end of the staging duration.
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_DEPLOY_CODE_TOO_EARLY);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_staging_empty_code_clears_storage() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -331,7 +426,7 @@ async fn test_staging_empty_code_clears_storage() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
     setup.assert_staged_code(Some(code)).await;
 
     // Verify staging empty code removes it.
@@ -339,7 +434,7 @@ async fn test_staging_empty_code_clears_storage() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, vec![])
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &[]);
     setup.assert_staged_code(None).await;
 
     // Verify the staging timestamp was removed along with the staged code.
@@ -348,6 +443,70 @@ async fn test_staging_empty_code_clears_storage() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Code can be staged by requesting it from a registry, which is mocked by the contract in
+/// [`PROJECT_PATH_CODE_REGISTRY`].
+#[tokio::test]
+async fn test_stage_code_from_hash() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let registry_wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_CODE_REGISTRY), "code_registry")
+            .await?;
+    let registry = worker.dev_deploy(&registry_wasm).await?;
+    let setup = Setup::new(worker, Some(dao.id().clone()), None).await?;
+
+    // Store code in the registry.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let code_hash = registry
+        .call("store_code")
+        .args_borsh(code.clone())
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?
+        .json::<CryptoHash>()?;
+    assert_eq!(code_hash, convert_code_to_crypto_hash(&code));
+
+    // Only the roles passed as `code_stagers` may successfully call this method.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_from_hash(&setup.unauth_account, registry.id(), code_hash)
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_stage_code_from_hash",
+        vec!["CodeStager".to_string(), "DAO".to_string()],
+    );
+    setup.assert_staged_code(None).await;
+
+    // Staging fails if the registry does not store code for the hash.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_from_hash(&dao, registry.id(), [0; 32])
+        .await?;
+    assert_failure_with(res, "Upgradable: Code not found in registry");
+    setup.assert_staged_code(None).await;
+
+    // Stage the code provided by the registry.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_from_hash(&dao, registry.id(), code_hash)
+        .await?;
+    assert_success_with_unit_return(res);
+    setup.assert_staged_code(Some(code)).await;
+
+    // The callback is private.
+    let res = dao
+        .call(setup.contract.id(), "up_stage_code_from_hash_callback")
+        .args_borsh(code_hash)
+        .max_gas()
+        .transact()
+        .await?;
+    assert_private_method_failure(res, "up_stage_code_from_hash_callback");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_staged_code() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -372,7 +531,7 @@ async fn test_staged_code() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
 
     // Some code is staged.
     let staged = setup
@@ -385,6 +544,48 @@ async fn test_staged_code() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_staged_code_length() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(dao.id().clone()), None).await?;
+
+    // No code staged.
+    let length = setup
+        .upgradable_contract
+        .up_staged_code_length(&setup.unauth_account)
+        .await?;
+    assert_eq!(length, None);
+
+    // Stage code.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+
+    let length = setup
+        .upgradable_contract
+        .up_staged_code_length(&setup.unauth_account)
+        .await?;
+    assert_eq!(length, Some(code.len() as u64));
+
+    // Unstage code.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, vec![])
+        .await?;
+    assert_staged_with_hash(res, &[]);
+    let length = setup
+        .upgradable_contract
+        .up_staged_code_length(&setup.unauth_account)
+        .await?;
+    assert_eq!(length, None);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_staged_code_hash() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -409,7 +610,7 @@ async fn test_staged_code_hash() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
 
     // Some code is staged.
     let staged_hash = setup
@@ -423,6 +624,47 @@ async fn test_staged_code_hash() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// `up_stage_code` and `up_stage_code_json` return the hash of staged code.
+#[tokio::test]
+async fn test_stage_code_returns_hash() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(dao.id().clone()), None).await?;
+
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, vec![1, 2, 3])
+        .await?;
+    let returned_hash = res.into_result()?.json::<Option<CryptoHash>>()?;
+    let staged_hash = setup
+        .upgradable_contract
+        .up_staged_code_hash(&setup.unauth_account)
+        .await?;
+    assert!(returned_hash.is_some());
+    assert_eq!(returned_hash, staged_hash);
+
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_json(&dao, vec![4, 5, 6], None)
+        .await?;
+    let returned_hash = res.into_result()?.json::<Option<CryptoHash>>()?;
+    let staged_hash = setup
+        .upgradable_contract
+        .up_staged_code_hash(&setup.unauth_account)
+        .await?;
+    assert!(returned_hash.is_some());
+    assert_eq!(returned_hash, staged_hash);
+
+    // Removing staged code returns `None`.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, vec![])
+        .await?;
+    assert_success_with(res, None::<CryptoHash>);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_deploy_code_without_delay() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -430,12 +672,12 @@ async fn test_deploy_code_without_delay() -> anyhow::Result<()> {
     let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
 
     // Stage some code.
-    let code = vec![1, 2, 3];
+    let code = MINIMAL_WASM.to_vec();
     let res = setup
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
     setup.assert_staged_code(Some(code)).await;
 
     // Deploy staged code.
@@ -445,131 +687,690 @@ async fn test_deploy_code_without_delay() -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Verifies the upgrade was successful by calling a method that's available only on the upgraded
-/// contract. Ensures the new contract can be deployed and state remains valid without
-/// explicit state migration.
+/// By default, `up_deploy_code` removes staged code, which prevents deploying it a second time.
 #[tokio::test]
-async fn test_deploy_code_and_call_method() -> anyhow::Result<()> {
+async fn test_deploy_code_clears_staged_code() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
     let dao = worker.dev_create_account().await?;
     let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
 
-    // Verify function `is_upgraded` is not defined in the initial contract.
-    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
-    assert_method_not_found_failure(res);
-
-    // Compile the other version of the contract and stage its code.
     let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
     let res = setup
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
-    setup.assert_staged_code(Some(code)).await;
+    assert_staged_with_hash(res, &code);
 
-    // Deploy staged code.
     let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
     assert_success_with_unit_return(res);
-
-    // The newly deployed contract defines the function `is_upgraded`. Calling it successfully
-    // verifies the staged contract is deployed and there are no issues with state migration.
     let res = setup.call_is_upgraded(&setup.unauth_account).await?;
     assert_success_with(res, true);
 
+    // Staged code and the staging timestamp were removed.
+    setup.assert_staged_code(None).await;
+    setup.assert_staging_timestamp(None).await;
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_NO_STAGING_TS);
+
     Ok(())
 }
 
-/// Deploys a new version of the contract that requires state migration and verifies the migration
-/// succeeded.
+/// With `keep_staged = true`, `up_deploy_code` keeps staged code, which can be deployed again.
 #[tokio::test]
-async fn test_deploy_code_with_migration() -> anyhow::Result<()> {
+async fn test_deploy_code_keep_staged() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
     let dao = worker.dev_create_account().await?;
     let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
 
-    // Verify function `is_migrated` is not defined in the initial contract.
-    let res = setup.call_is_migrated(&setup.unauth_account).await?;
-    assert_method_not_found_failure(res);
-
-    // Compile the other version of the contract and stage its code.
-    let code = common::repo::compile_project(
-        Path::new(PROJECT_PATH_STATE_MIGRATION),
-        "upgradable_state_migration",
-    )
-    .await?;
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
     let res = setup
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
+    assert_staged_with_hash(res, &code);
+    let staging_timestamp = setup
+        .upgradable_contract
+        .up_get_delay_status(&dao)
+        .await?
+        .staging_timestamp;
+
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_keep_staged(&dao, None, true)
+        .await?;
     assert_success_with_unit_return(res);
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    // Staged code and the staging timestamp are still available.
     setup.assert_staged_code(Some(code)).await;
+    setup.assert_staging_timestamp(staging_timestamp).await;
 
-    // Deploy staged code and call the new contract's `migrate` method.
-    let function_call_args = FunctionCallArgs {
-        function_name: "migrate".to_string(),
-        arguments: Vec::new(),
-        amount: NearToken::from_yoctonear(0),
-        gas: Gas::from_tgas(1),
-    };
+    // The same code can be deployed again, this time removing it from staging.
     let res = setup
         .upgradable_contract
-        .up_deploy_code(&dao, Some(function_call_args))
+        .up_deploy_code_keep_staged(&dao, None, false)
         .await?;
     assert_success_with_unit_return(res);
-
-    // The newly deployed contract defines the function `is_migrated`. Calling it successfully
-    // verifies the staged contract is deployed and state migration succeeded.
-    let res = setup.call_is_migrated(&setup.unauth_account).await?;
-    assert_success_with(res, true);
+    setup.assert_staged_code(None).await;
 
     Ok(())
 }
 
-/// Deploys a new version of the contract and, batched with the `DeployContractAction`, calls a
-/// migration method that fails. Verifies the failure rolls back the deployment, i.e. the initial
-/// code remains active.
+/// Super-admins may replace the roles authorized to deploy code at runtime.
 #[tokio::test]
-async fn test_deploy_code_with_migration_failure_rollback() -> anyhow::Result<()> {
+async fn test_set_code_deployer_roles() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
     let dao = worker.dev_create_account().await?;
     let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+    let super_admin = setup.contract.as_account();
 
-    // Compile the other version of the contract and stage its code.
-    let code = common::repo::compile_project(
-        Path::new(PROJECT_PATH_STATE_MIGRATION),
-        "upgradable_state_migration",
-    )
-    .await?;
+    // Initially, the roles passed to `code_deployers` are authorized.
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_get_code_deployer_roles(&setup.unauth_account)
+            .await?,
+        vec!["CodeDeployer".to_string(), "DAO".to_string()],
+    );
+
+    // Only super-admins may set roles, which must be known and non-empty.
     let res = setup
         .upgradable_contract
-        .up_stage_code(&dao, code.clone())
+        .up_set_code_deployer_roles(&dao, vec!["CodeStager"])
         .await?;
-    assert_success_with_unit_return(res);
-    setup.assert_staged_code(Some(code)).await;
+    assert_failure_with(res, "Upgradable: Only super-admins may set roles");
+    let res = setup
+        .upgradable_contract
+        .up_set_code_deployer_roles(super_admin, vec!["Unknown"])
+        .await?;
+    assert_failure_with(res, "Upgradable: Unknown role");
+    let res = setup
+        .upgradable_contract
+        .up_set_code_deployer_roles(super_admin, vec![])
+        .await?;
+    assert_failure_with(res, "Upgradable: Specify at least one role");
 
-    // Deploy staged code and call the new contract's `migrate_with_failure` method.
-    let function_call_args = FunctionCallArgs {
-        function_name: "migrate_with_failure".to_string(),
-        arguments: Vec::new(),
-        amount: NearToken::from_yoctonear(0),
-        gas: Gas::from_tgas(1),
-    };
     let res = setup
         .upgradable_contract
-        .up_deploy_code(&dao, Some(function_call_args))
+        .up_set_code_deployer_roles(super_admin, vec!["CodeStager"])
         .await?;
-    assert_failure_with(res, "Failing migration on purpose");
+    assert_success_with_unit_return(res);
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_get_code_deployer_roles(&setup.unauth_account)
+            .await?,
+        vec!["CodeStager".to_string()],
+    );
 
-    // Verify `code` wasn't deployed by calling a function that is defined only in the initial
-    // contract but not in the contract corresponding to the `code`.
-    setup.assert_is_set_up(&setup.unauth_account).await;
+    // `DAO` may still stage code, but no longer deploy it.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_insufficient_acl_permissions(res, "up_deploy_code", vec!["CodeStager".to_string()]);
+    setup.assert_is_set_up(&setup.unauth_account).await;
+
+    // Grantees of the new role may deploy code.
+    let deployer = worker.dev_create_account().await?;
+    let granted = setup
+        .acl_contract
+        .acl_grant_role(super_admin, "CodeStager", deployer.id())
+        .await?;
+    assert_eq!(granted, Some(true));
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code(&deployer, None)
+        .await?;
+    assert_success_with_unit_return(res);
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+/// `up_deploy_code` rejects staged code which is obviously not a wasm module.
+#[tokio::test]
+async fn test_deploy_code_rejects_invalid_wasm() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    // Stage garbage which is rejected on deployment, including the magic number without version.
+    for code in [b"not a contract".to_vec(), b"\0asm".to_vec()] {
+        let res = setup
+            .upgradable_contract
+            .up_stage_code(&dao, code.clone())
+            .await?;
+        assert_staged_with_hash(res, &code);
+        setup.assert_staged_code(Some(code)).await;
+
+        let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+        assert_failure_with(res, ERR_MSG_NOT_WASM);
+        setup.assert_is_set_up(&setup.unauth_account).await;
+    }
+
+    // Stage and deploy a real contract.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+/// Stages the same code via `up_stage_code` (borsh) and `up_stage_code_json` (JSON) and deploys
+/// it.
+#[tokio::test]
+async fn test_stage_code_json_and_deploy() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+
+    // Only the roles passed as `code_stagers` may successfully call this method.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_json(&setup.unauth_account, code.clone(), None)
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_stage_code_json",
+        vec!["CodeStager".to_string(), "DAO".to_string()],
+    );
+    setup.assert_staged_code(None).await;
+
+    // Staging via borsh and JSON results in the same staged code.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code.clone())).await;
+
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_json(&dao, vec![], None)
+        .await?;
+    assert_staged_with_hash(res, &[]);
+    setup.assert_staged_code(None).await;
+
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_json(&dao, code.clone(), None)
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Deploy the code staged via JSON.
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+/// The `deploy_timestamp` of `up_stage_code_json` is passed as string.
+#[tokio::test]
+async fn test_stage_code_json_with_deploy_timestamp() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    let deploy_timestamp = block_timestamp(&worker).await + sdk_duration_from_secs(10);
+    let code = MINIMAL_WASM.to_vec();
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_json(&dao, code.clone(), Some(deploy_timestamp))
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup
+        .assert_staging_timestamp(Some(deploy_timestamp))
+        .await;
+
+    Ok(())
+}
+
+/// Stages code in two named slots and deploys the code of one of them.
+#[tokio::test]
+async fn test_stage_and_deploy_named_slots() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let invalid_code = vec![1, 2, 3];
+
+    // Only the roles passed as `code_stagers` may successfully call this method.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_named(&setup.unauth_account, "a", code.clone())
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_stage_code_named",
+        vec!["CodeStager".to_string(), "DAO".to_string()],
+    );
+
+    // Stage different code in two slots.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_named(&dao, "a", invalid_code.clone())
+        .await?;
+    assert_success_with(res, Some(convert_code_to_crypto_hash(&invalid_code)));
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_named(&dao, "b", code.clone())
+        .await?;
+    assert_success_with(res, Some(convert_code_to_crypto_hash(&code)));
+
+    // Slots are independent of each other and of the default slot.
+    let viewer = &setup.unauth_account;
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staged_code_named(viewer, "a")
+            .await?,
+        Some(invalid_code.clone()),
+    );
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staged_code_named(viewer, "b")
+            .await?,
+        Some(code.clone()),
+    );
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staged_code_hash_named(viewer, "b")
+            .await?,
+        Some(convert_code_to_crypto_hash(&code)),
+    );
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staged_code_named(viewer, "c")
+            .await?,
+        None,
+    );
+    setup.assert_staged_code(None).await;
+
+    // Only the roles passed as `code_deployers` may successfully call this method.
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_named(&setup.unauth_account, "b")
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_deploy_code_named",
+        vec!["CodeDeployer".to_string(), "DAO".to_string()],
+    );
+
+    // Deploying an empty slot fails.
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_named(&dao, "c")
+        .await?;
+    assert_failure_with(res, "Upgradable: No staged code in slot");
+
+    // Deploying a slot removes its code, while other slots are not affected. Deploy the current
+    // code of the contract, which still allows viewing slots.
+    let current_code =
+        common::repo::compile_project(Path::new(PROJECT_PATH), "upgradable").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_named(&dao, "c", current_code.clone())
+        .await?;
+    assert_success_with(res, Some(convert_code_to_crypto_hash(&current_code)));
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_named(&dao, "c")
+        .await?;
+    assert_success_with_unit_return(res);
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staged_code_named(viewer, "c")
+            .await?,
+        None,
+    );
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staged_code_named(viewer, "b")
+            .await?,
+        Some(code.clone()),
+    );
+
+    // Deploy the code staged in slot "b".
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_named(&dao, "b")
+        .await?;
+    assert_success_with_unit_return(res);
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stage_code_in_chunks_and_deploy() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    // Upload the code of the other version of the contract in chunks.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let total_len = code.len() as u64;
+    let chunk_size = code.len() / 3 + 1;
+    let mut offset = 0;
+    for chunk in code.chunks(chunk_size) {
+        let res = setup
+            .upgradable_contract
+            .up_stage_code_chunk(&dao, offset, chunk.to_vec(), total_len)
+            .await?;
+        assert_success_with_unit_return(res);
+        offset += chunk.len() as u64;
+    }
+
+    // Chunks are not staged before finalization.
+    setup.assert_staged_code(None).await;
+    let res = setup
+        .upgradable_contract
+        .up_finalize_staged_code(&dao)
+        .await?;
+    assert_success_with_unit_return(res);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Deploy staged code.
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_stage_code_in_chunks_failures() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    // Uploading chunks is restricted to `code_stagers`.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_chunk(&setup.unauth_account, 0, vec![1, 2], 4)
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_stage_code_chunk",
+        vec!["CodeStager".to_string(), "DAO".to_string()],
+    );
+
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_chunk(&dao, 0, vec![1, 2], 4)
+        .await?;
+    assert_success_with_unit_return(res);
+
+    // Chunks must be uploaded in order and be consistent with `total_len`.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_chunk(&dao, 1, vec![3, 4], 4)
+        .await?;
+    assert_failure_with(
+        res,
+        "Upgradable: Chunk offset must equal the length of uploaded code",
+    );
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_chunk(&dao, 2, vec![3, 4], 5)
+        .await?;
+    assert_failure_with(res, "Upgradable: Total length differs from previous chunks");
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_chunk(&dao, 2, vec![3, 4, 5], 4)
+        .await?;
+    assert_failure_with(res, "Upgradable: Chunk exceeds total length");
+
+    // Incomplete uploads cannot be finalized.
+    let res = setup
+        .upgradable_contract
+        .up_finalize_staged_code(&dao)
+        .await?;
+    assert_failure_with(
+        res,
+        "Upgradable: Length of uploaded code differs from total length",
+    );
+
+    // Partial uploads are discarded by `up_unstage_code`.
+    let res = setup.upgradable_contract.up_unstage_code(&dao).await?;
+    assert_success_with_unit_return(res);
+    let res = setup
+        .upgradable_contract
+        .up_finalize_staged_code(&dao)
+        .await?;
+    assert_failure_with(res, "Upgradable: No chunked upload in progress");
+    let res = setup
+        .upgradable_contract
+        .up_stage_code_chunk(&dao, 2, vec![3, 4], 4)
+        .await?;
+    assert_failure_with(res, "Upgradable: No chunked upload in progress");
+    setup.assert_staged_code(None).await;
+
+    Ok(())
+}
+
+/// Verifies the upgrade was successful by calling a method that's available only on the upgraded
+/// contract. Ensures the new contract can be deployed and state remains valid without
+/// explicit state migration.
+#[tokio::test]
+async fn test_deploy_code_and_call_method() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    // Verify function `is_upgraded` is not defined in the initial contract.
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_method_not_found_failure(res);
+
+    // Compile the other version of the contract and stage its code.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Deploy staged code.
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+
+    // The newly deployed contract defines the function `is_upgraded`. Calling it successfully
+    // verifies the staged contract is deployed and there are no issues with state migration.
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_code_with_version() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_version(&setup.unauth_account)
+            .await?,
+        None
+    );
+
+    // A deployment that fails doesn't set the version.
+    let code = common::repo::compile_project(
+        Path::new(PROJECT_PATH_STATE_MIGRATION),
+        "upgradable_state_migration",
+    )
+    .await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    let function_call_args = FunctionCallArgs {
+        function_name: "migrate_with_failure".to_string(),
+        arguments: Vec::new(),
+        amount: NearToken::from_yoctonear(0),
+        gas: Gas::from_tgas(2),
+    };
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_with_version(&dao, Some(function_call_args), "v0.9.0")
+        .await?;
+    assert_failure_with(res, "Failing migration on purpose");
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_version(&setup.unauth_account)
+            .await?,
+        None
+    );
+
+    // Stage and deploy the other version of the contract with a version.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code_with_version(&dao, None, "v1.4.2")
+        .await?;
+    assert_success_with_unit_return(res);
+
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_version(&setup.unauth_account)
+            .await?,
+        Some("v1.4.2".to_string())
+    );
+
+    Ok(())
+}
+
+/// Deploys a new version of the contract that requires state migration and verifies the migration
+/// succeeded.
+#[tokio::test]
+async fn test_deploy_code_with_migration() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    // Verify function `is_migrated` is not defined in the initial contract.
+    let res = setup.call_is_migrated(&setup.unauth_account).await?;
+    assert_method_not_found_failure(res);
+
+    // Compile the other version of the contract and stage its code.
+    let code = common::repo::compile_project(
+        Path::new(PROJECT_PATH_STATE_MIGRATION),
+        "upgradable_state_migration",
+    )
+    .await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Deploy staged code and call the new contract's `migrate` method.
+    let function_call_args = FunctionCallArgs {
+        function_name: "migrate".to_string(),
+        arguments: Vec::new(),
+        amount: NearToken::from_yoctonear(0),
+        gas: Gas::from_tgas(1),
+    };
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code(&dao, Some(function_call_args))
+        .await?;
+    assert_success_with_unit_return(res);
+
+    // The newly deployed contract defines the function `is_migrated`. Calling it successfully
+    // verifies the staged contract is deployed and state migration succeeded.
+    let res = setup.call_is_migrated(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    Ok(())
+}
+
+/// Deploys a new version of the contract and, batched with the `DeployContractAction`, calls a
+/// migration method that fails. Verifies the failure rolls back the deployment, i.e. the initial
+/// code remains active.
+#[tokio::test]
+async fn test_deploy_code_with_migration_failure_rollback() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    // Compile the other version of the contract and stage its code.
+    let code = common::repo::compile_project(
+        Path::new(PROJECT_PATH_STATE_MIGRATION),
+        "upgradable_state_migration",
+    )
+    .await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Deploy staged code and call the new contract's `migrate_with_failure` method.
+    let function_call_args = FunctionCallArgs {
+        function_name: "migrate_with_failure".to_string(),
+        arguments: Vec::new(),
+        amount: NearToken::from_yoctonear(0),
+        gas: Gas::from_tgas(1),
+    };
+    let res = setup
+        .upgradable_contract
+        .up_deploy_code(&dao, Some(function_call_args))
+        .await?;
+    assert_failure_with(res, "Failing migration on purpose");
+
+    // Verify `code` wasn't deployed by calling a function that is defined only in the initial
+    // contract but not in the contract corresponding to the `code`.
+    setup.assert_is_set_up(&setup.unauth_account).await;
+
+    // Staged code was removed nonetheless, since `keep_staged` wasn't set.
+    setup.assert_staged_code(None).await;
 
     Ok(())
 }
 
 /// Deploys staged code in a batch transaction with two function call actions:
 ///
-/// 1. `up_deploy_code` with a function call to a migration method that fails
+/// 1. `up_deploy_code` with a function call to a migration method that fails, keeping staged code
 /// 2. `up_stage_code` to remove staged code from storage
 ///
 /// The pitfall is that a failure in the promise returned by 1 does _not_ make the transaction fail
@@ -586,64 +1387,220 @@ async fn test_deploy_code_in_batch_transaction_pitfall() -> anyhow::Result<()> {
         "upgradable_state_migration",
     )
     .await?;
-    let res = setup
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Construct the function call actions to be executed in a batch transaction.
+    // Note that we are attaching a call to `migrate_with_failure`, which will fail.
+    let fn_call_deploy = near_workspaces::operations::Function::new("up_deploy_code")
+        .args_json(json!({ "function_call_args": FunctionCallArgs {
+        function_name: "migrate_with_failure".to_string(),
+        arguments: Vec::new(),
+        amount: NearToken::from_yoctonear(0),
+        gas: Gas::from_tgas(1),
+    }, "keep_staged": true }))
+        .gas(Gas::from_tgas(201));
+    let fn_call_remove_code = near_workspaces::operations::Function::new("up_stage_code")
+        .args_borsh(Vec::<u8>::new())
+        .gas(Gas::from_tgas(90));
+
+    let res = dao
+        .batch(setup.contract.id())
+        .call(fn_call_deploy)
+        .call(fn_call_remove_code)
+        .transact()
+        .await?;
+
+    // Here is the pitfall: Despite the failure of `migrate_with_failure`, the transaction succeeds.
+    // This is due to `fn_call_deploy` _successfully_ returning a promise `p`. The promise `p`
+    // fails, however that does not affect the result of the transaction, which is the result of
+    // `fn_call_remove_code`.
+    assert_staged_with_hash(res.clone(), &[]);
+
+    // Verify the promise resulting from `fn_call_deploy` failed. There seems to be no public API to
+    // get the status of an `ExecutionOutcome`, hence `is_failure` is used in combination with debug
+    // formatting. Since this is test code we can use debug formatting for this purpose.
+    let fn_call_deploy_receipt = res
+        .receipt_outcomes()
+        .get(1)
+        .expect("There should be at least two receipts");
+    assert!(fn_call_deploy_receipt.is_failure());
+    assert!(format!("{:?}", fn_call_deploy_receipt).contains("Failing migration on purpose"));
+
+    // Verify `code` wasn't deployed by calling a function that is defined only in the initial
+    // contract but not in the contract corresponding to `code`.
+    setup.assert_is_set_up(&setup.unauth_account).await;
+
+    // However the staged code was removed, i.e. `fn_call_remove_code` was executed anyway.
+    setup.assert_staged_code(None).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_deploy_code_with_delay() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let staging_duration = sdk_duration_from_secs(3);
+    let setup = Setup::new(
+        worker.clone(),
+        Some(dao.id().clone()),
+        Some(staging_duration),
+    )
+    .await?;
+
+    // Stage some code.
+    let code = MINIMAL_WASM.to_vec();
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+    setup.assert_staged_code(Some(code)).await;
+
+    // Let the staging duration pass.
+    fast_forward_beyond(&worker, staging_duration).await;
+
+    // Deploy staged code.
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_delay_status_json() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let staging_duration = sdk_duration_from_secs(42);
+    let setup = Setup::new(worker, Some(dao.id().clone()), Some(staging_duration)).await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, vec![1, 2, 3])
+        .await?;
+    assert_staged_with_hash(res, &[1, 2, 3]);
+
+    // Values returned by `up_get_delay_status` are JSON numbers.
+    let res = setup
+        .unauth_account
+        .call(setup.contract.id(), "up_get_delay_status")
+        .view()
+        .await?;
+    let status = res.json::<near_sdk::serde_json::Value>()?;
+    let staging_timestamp = status["staging_timestamp"]
+        .as_u64()
+        .expect("Staging timestamp should be a number");
+    assert_eq!(
+        status,
+        json!({
+            "staging_duration": staging_duration,
+            "staging_timestamp": staging_timestamp,
+            "new_staging_duration": null,
+            "new_staging_duration_timestamp": null,
+        })
+    );
+
+    // Values returned by `up_get_delay_status_json` are JSON strings.
+    let res = setup
+        .unauth_account
+        .call(setup.contract.id(), "up_get_delay_status_json")
+        .view()
+        .await?;
+    assert_eq!(
+        res.json::<near_sdk::serde_json::Value>()?,
+        json!({
+            "staging_duration": staging_duration.to_string(),
+            "staging_timestamp": staging_timestamp.to_string(),
+            "new_staging_duration": null,
+            "new_staging_duration_timestamp": null,
+        })
+    );
+    let status_json = setup
+        .upgradable_contract
+        .up_get_delay_status_json(&setup.unauth_account)
+        .await?;
+    let status = setup
+        .upgradable_contract
+        .up_get_delay_status(&setup.unauth_account)
+        .await?;
+    assert_eq!(status_json, UpgradableDurationStatusJson::from(status));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_time_until_deployable() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let staging_duration = sdk_duration_from_secs(6);
+    let setup = Setup::new(
+        worker.clone(),
+        Some(dao.id().clone()),
+        Some(staging_duration),
+    )
+    .await?;
+
+    // Nothing is staged.
+    let remaining = setup
         .upgradable_contract
-        .up_stage_code(&dao, code.clone())
+        .up_time_until_deployable(&setup.unauth_account)
         .await?;
-    assert_success_with_unit_return(res);
-    setup.assert_staged_code(Some(code)).await;
-
-    // Construct the function call actions to be executed in a batch transaction.
-    // Note that we are attaching a call to `migrate_with_failure`, which will fail.
-    let fn_call_deploy = near_workspaces::operations::Function::new("up_deploy_code")
-        .args_json(json!({ "function_call_args": FunctionCallArgs {
-        function_name: "migrate_with_failure".to_string(),
-        arguments: Vec::new(),
-        amount: NearToken::from_yoctonear(0),
-        gas: Gas::from_tgas(1),
-    } }))
-        .gas(Gas::from_tgas(201));
-    let fn_call_remove_code = near_workspaces::operations::Function::new("up_stage_code")
-        .args_borsh(Vec::<u8>::new())
-        .gas(Gas::from_tgas(90));
+    assert_eq!(remaining, None);
 
-    let res = dao
-        .batch(setup.contract.id())
-        .call(fn_call_deploy)
-        .call(fn_call_remove_code)
-        .transact()
+    // Stage some code.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, vec![1, 2, 3])
         .await?;
+    assert_staged_with_hash(res, &[1, 2, 3]);
+    let initial = setup
+        .upgradable_contract
+        .up_time_until_deployable(&setup.unauth_account)
+        .await?
+        .expect("Code should be staged");
+    assert!(initial > 0 && initial <= staging_duration);
 
-    // Here is the pitfall: Despite the failure of `migrate_with_failure`, the transaction succeeds.
-    // This is due to `fn_call_deploy` _successfully_ returning a promise `p`. The promise `p`
-    // fails, however that does not affect the result of the transaction.
-    assert_success_with_unit_return(res.clone());
-
-    // Verify the promise resulting from `fn_call_deploy` failed. There seems to be no public API to
-    // get the status of an `ExecutionOutcome`, hence `is_failure` is used in combination with debug
-    // formatting. Since this is test code we can use debug formatting for this purpose.
-    let fn_call_deploy_receipt = res
-        .receipt_outcomes()
-        .get(1)
-        .expect("There should be at least two receipts");
-    assert!(fn_call_deploy_receipt.is_failure());
-    assert!(format!("{:?}", fn_call_deploy_receipt).contains("Failing migration on purpose"));
+    // Let some time pass but not enough.
+    fast_forward_beyond(&worker, sdk_duration_from_secs(1)).await;
+    let remaining = setup
+        .upgradable_contract
+        .up_time_until_deployable(&setup.unauth_account)
+        .await?
+        .expect("Code should be staged");
+    assert!(remaining > 0 && remaining < initial);
 
-    // Verify `code` wasn't deployed by calling a function that is defined only in the initial
-    // contract but not in the contract corresponding to `code`.
-    setup.assert_is_set_up(&setup.unauth_account).await;
+    // Let the staging duration pass.
+    fast_forward_beyond(&worker, staging_duration).await;
+    let remaining = setup
+        .upgradable_contract
+        .up_time_until_deployable(&setup.unauth_account)
+        .await?;
+    assert_eq!(remaining, Some(0));
 
-    // However the staged code was removed, i.e. `fn_call_remove_code` was executed anyway.
-    setup.assert_staged_code(None).await;
+    // Removing staged code resets the remaining time.
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, vec![])
+        .await?;
+    assert_staged_with_hash(res, &[]);
+    let remaining = setup
+        .upgradable_contract
+        .up_time_until_deployable(&setup.unauth_account)
+        .await?;
+    assert_eq!(remaining, None);
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_deploy_code_with_delay() -> anyhow::Result<()> {
+async fn test_time_until_duration_update_applicable() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
     let dao = worker.dev_create_account().await?;
-    let staging_duration = sdk_duration_from_secs(3);
+    let staging_duration = sdk_duration_from_secs(6);
     let setup = Setup::new(
         worker.clone(),
         Some(dao.id().clone()),
@@ -651,20 +1608,48 @@ async fn test_deploy_code_with_delay() -> anyhow::Result<()> {
     )
     .await?;
 
-    // Stage some code.
-    let code = vec![1, 2, 3];
+    // No update is staged.
+    let remaining = setup
+        .upgradable_contract
+        .up_time_until_duration_update_applicable(&setup.unauth_account)
+        .await?;
+    assert_eq!(remaining, None);
+
+    // Stage an update of the staging duration.
     let res = setup
         .upgradable_contract
-        .up_stage_code(&dao, code.clone())
+        .up_stage_update_staging_duration(&dao, sdk_duration_from_secs(2))
         .await?;
     assert_success_with_unit_return(res);
-    setup.assert_staged_code(Some(code)).await;
+    let initial = setup
+        .upgradable_contract
+        .up_time_until_duration_update_applicable(&setup.unauth_account)
+        .await?
+        .expect("Update should be staged");
+    assert!(initial > 0 && initial <= staging_duration);
+
+    // Let some time pass but not enough.
+    fast_forward_beyond(&worker, sdk_duration_from_secs(1)).await;
+    let remaining = setup
+        .upgradable_contract
+        .up_time_until_duration_update_applicable(&setup.unauth_account)
+        .await?
+        .expect("Update should be staged");
+    assert!(remaining > 0 && remaining < initial);
 
     // Let the staging duration pass.
     fast_forward_beyond(&worker, staging_duration).await;
+    let remaining = setup
+        .upgradable_contract
+        .up_time_until_duration_update_applicable(&setup.unauth_account)
+        .await?;
+    assert_eq!(remaining, Some(0));
 
-    // Deploy staged code.
-    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    // The update can be applied once the countdown reached zero.
+    let res = setup
+        .upgradable_contract
+        .up_apply_update_staging_duration(&dao)
+        .await?;
     assert_success_with_unit_return(res);
 
     Ok(())
@@ -687,7 +1672,7 @@ async fn test_deploy_code_with_delay_failure_too_early() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
     setup.assert_staged_code(Some(code)).await;
 
     // Let some time pass but not enough.
@@ -704,6 +1689,89 @@ async fn test_deploy_code_with_delay_failure_too_early() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Emergency deployments bypass the staging duration, while regular deployments are still
+/// rejected as too early.
+#[tokio::test]
+async fn test_emergency_deploy_code() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(
+        worker.clone(),
+        Some(dao.id().clone()),
+        Some(sdk_duration_from_secs(1024)),
+    )
+    .await?;
+    let emergency_deployer = worker.dev_create_account().await?;
+    setup
+        .acl_contract
+        .acl_grant_role(
+            setup.contract.as_account(),
+            "EmergencyDeployer",
+            emergency_deployer.id(),
+        )
+        .await?;
+
+    // Stage the code of another version of the contract.
+    let code = common::repo::compile_project(Path::new(PROJECT_PATH_2), "upgradable_2").await?;
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+
+    // Regular deployment still has to wait for the staging duration.
+    let res = setup.upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_DEPLOY_CODE_TOO_EARLY);
+
+    // Emergency deployment succeeds immediately and emits an event.
+    let res = setup
+        .upgradable_contract
+        .up_emergency_deploy_code(&emergency_deployer, None)
+        .await?;
+    assert!(res
+        .logs()
+        .iter()
+        .any(|log| log.starts_with("EVENT_JSON:") && log.contains("emergency_code_deployed")));
+    assert_success_with_unit_return(res);
+
+    let res = setup.call_is_upgraded(&setup.unauth_account).await?;
+    assert_success_with(res, true);
+
+    // Like a regular deployment, the emergency deployment removed staged code.
+    setup.assert_staged_code(None).await;
+    setup.assert_staging_timestamp(None).await;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_emergency_deploy_code_permission_failure() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker.clone(), Some(dao.id().clone()), None).await?;
+
+    let code = vec![1, 2, 3];
+    let res = setup
+        .upgradable_contract
+        .up_stage_code(&dao, code.clone())
+        .await?;
+    assert_staged_with_hash(res, &code);
+
+    // `DAO` is not passed to `emergency_code_deployers`, so it may not deploy in an emergency.
+    let res = setup
+        .upgradable_contract
+        .up_emergency_deploy_code(&dao, None)
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_emergency_deploy_code",
+        vec!["EmergencyDeployer".to_string()],
+    );
+    setup.assert_is_set_up(&setup.unauth_account).await;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_deploy_code_permission_failure() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -716,7 +1784,7 @@ async fn test_deploy_code_permission_failure() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&dao, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
     setup.assert_staged_code(Some(code)).await;
 
     // Only the roles passed as `code_deployers` to the `Upgradable` derive macro may successfully
@@ -814,6 +1882,75 @@ async fn test_init_staging_duration() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// The seconds-based setters store the same nanosecond durations as their counterparts.
+#[tokio::test]
+async fn test_staging_duration_secs() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let setup = Setup::new(worker, Some(dao.id().clone()), None).await?;
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staging_duration_secs(&dao)
+            .await?,
+        None
+    );
+
+    // Initialize the staging duration.
+    let res = setup
+        .upgradable_contract
+        .up_init_staging_duration_secs(&dao, 42)
+        .await?;
+    assert_success_with_unit_return(res);
+    setup
+        .assert_staging_duration(Some(sdk_duration_from_secs(42)))
+        .await;
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_staging_duration_secs(&dao)
+            .await?,
+        Some(42)
+    );
+
+    // Stage an update of the staging duration.
+    let res = setup
+        .upgradable_contract
+        .up_stage_update_staging_duration_secs(&dao, 23)
+        .await?;
+    assert_success_with_unit_return(res);
+    setup
+        .assert_new_staging_duration(Some(sdk_duration_from_secs(23)))
+        .await;
+    assert_eq!(
+        setup
+            .upgradable_contract
+            .up_new_staging_duration_secs(&dao)
+            .await?,
+        Some(23)
+    );
+
+    // Durations which overflow in nanoseconds are rejected.
+    let res = setup
+        .upgradable_contract
+        .up_stage_update_staging_duration_secs(&dao, u64::MAX)
+        .await?;
+    assert_failure_with(res, "Upgradable: Staging duration overflows");
+
+    // Access control applies as well.
+    let res = setup
+        .upgradable_contract
+        .up_stage_update_staging_duration_secs(&setup.unauth_account, 1)
+        .await?;
+    assert_insufficient_acl_permissions(
+        res,
+        "up_stage_update_staging_duration",
+        vec!["DurationManager".to_string(), "DAO".to_string()],
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_stage_update_staging_duration_permission_failure() -> anyhow::Result<()> {
     let worker = near_workspaces::sandbox().await?;
@@ -1002,7 +2139,7 @@ async fn test_acl_permission_scope() -> anyhow::Result<()> {
         .upgradable_contract
         .up_stage_code(&code_stager, code.clone())
         .await?;
-    assert_success_with_unit_return(res);
+    assert_staged_with_hash(res, &code);
     setup.assert_staged_code(Some(code)).await;
 
     // Verify `code_stager` is not authorized to deploy staged code. Only grantees of at least one
@@ -1024,3 +2161,170 @@ async fn test_acl_permission_scope() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+async fn get_deploy_count(contract: &Contract) -> anyhow::Result<u64> {
+    let res = contract.view("get_deploy_count").await?;
+    Ok(res.json::<u64>()?)
+}
+
+/// The hook passed via `#[upgradable(on_deploy = "...")]` is called after successful deployments
+/// only.
+#[tokio::test]
+async fn test_on_deploy_hook() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_ON_DEPLOY), "upgradable_on_deploy")
+            .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    contract
+        .call("new")
+        .args_json(json!({ "dao": dao.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let upgradable_contract = UpgradableContract::new(contract.clone());
+    assert_eq!(get_deploy_count(&contract).await?, 0);
+
+    // The callback calling the hook is private.
+    let res = dao
+        .call(contract.id(), "up_on_deploy_callback")
+        .max_gas()
+        .transact()
+        .await?;
+    assert_private_method_failure(res, "up_on_deploy_callback");
+
+    // Stage and deploy the same version of the contract.
+    let res = upgradable_contract
+        .up_stage_code(&dao, wasm.clone())
+        .await?;
+    assert_staged_with_hash(res, &wasm);
+    let res = upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+    assert_eq!(get_deploy_count(&contract).await?, 1);
+
+    // A failed deployment doesn't call the hook. Code must be staged again since the previous
+    // deployment removed it.
+    let res = upgradable_contract
+        .up_stage_code(&dao, wasm.clone())
+        .await?;
+    assert_staged_with_hash(res, &wasm);
+    let function_call_args = FunctionCallArgs {
+        function_name: "fail_on_purpose".to_string(),
+        arguments: Vec::new(),
+        amount: NearToken::from_yoctonear(0),
+        gas: Gas::from_tgas(2),
+    };
+    let res = upgradable_contract
+        .up_deploy_code(&dao, Some(function_call_args))
+        .await?;
+    assert_failure_with(res, "Upgradable: Deployment failed");
+    assert_eq!(get_deploy_count(&contract).await?, 1);
+
+    // A deployment that is too early doesn't call the hook.
+    let res = upgradable_contract
+        .up_init_staging_duration(&dao, sdk_duration_from_secs(1024))
+        .await?;
+    assert_success_with_unit_return(res);
+    let res = upgradable_contract
+        .up_stage_code(&dao, wasm.clone())
+        .await?;
+    assert_staged_with_hash(res, &wasm);
+    let res = upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_DEPLOY_CODE_TOO_EARLY);
+    assert_eq!(get_deploy_count(&contract).await?, 1);
+
+    Ok(())
+}
+
+/// With `#[upgradable(deploy_requires_paused)]`, code can be deployed only while "ALL" features are
+/// paused.
+#[tokio::test]
+async fn test_deploy_code_requires_paused() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let wasm =
+        common::repo::compile_project(Path::new(PROJECT_PATH_PAUSABLE), "upgradable_pausable")
+            .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    contract
+        .call("new")
+        .args_json(json!({ "dao": dao.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let upgradable_contract = UpgradableContract::new(contract.clone());
+    let pausable_contract = PausableContract::new(contract.clone());
+
+    let res = upgradable_contract
+        .up_stage_code(&dao, wasm.clone())
+        .await?;
+    assert_staged_with_hash(res, &wasm);
+
+    // Deployment fails while the contract is not paused.
+    let res = upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_DEPLOY_REQUIRES_PAUSED);
+
+    // Pausing an individual feature is not sufficient.
+    let res = pausable_contract
+        .pa_pause_feature(&dao, "some_feature")
+        .await?;
+    assert_success_with(res, true);
+    let res = upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_failure_with(res, ERR_MSG_DEPLOY_REQUIRES_PAUSED);
+
+    // Deployment succeeds during a global pause.
+    let res = pausable_contract.pa_pause_feature(&dao, "ALL").await?;
+    assert_success_with(res, true);
+    let res = upgradable_contract.up_deploy_code(&dao, None).await?;
+    assert_success_with_unit_return(res);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_no_overwrite_staged() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let dao = worker.dev_create_account().await?;
+    let wasm = common::repo::compile_project(
+        Path::new(PROJECT_PATH_NO_OVERWRITE_STAGED),
+        "upgradable_no_overwrite_staged",
+    )
+    .await?;
+    let contract = worker.dev_deploy(&wasm).await?;
+    contract
+        .call("new")
+        .args_json(json!({ "dao": dao.id() }))
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+    let upgradable_contract = UpgradableContract::new(contract);
+
+    let res = upgradable_contract
+        .up_stage_code(&dao, vec![1, 2, 3])
+        .await?;
+    assert_staged_with_hash(res, &[1, 2, 3]);
+
+    // Staging again fails and keeps the staged code.
+    let res = upgradable_contract
+        .up_stage_code(&dao, vec![4, 5, 6])
+        .await?;
+    assert_failure_with(res, ERR_MSG_ALREADY_STAGED);
+    let staged = upgradable_contract.up_staged_code(&dao).await?;
+    assert_eq!(staged, Some(vec![1, 2, 3]));
+
+    // After unstaging, code can be staged again.
+    let res = upgradable_contract.up_unstage_code(&dao).await?;
+    assert_success_with_unit_return(res);
+    let res = upgradable_contract
+        .up_stage_code(&dao, vec![4, 5, 6])
+        .await?;
+    assert_staged_with_hash(res, &[4, 5, 6]);
+    let staged = upgradable_contract.up_staged_code(&dao).await?;
+    assert_eq!(staged, Some(vec![4, 5, 6]));
+
+    Ok(())
+}
@@ -1,4 +1,20 @@
 /// Represents permissions for the [`AccessControllable`](crate::AccessControllable) plugin.
+///
+/// When deriving this trait, the permissions of a variant are determined by its position, which
+/// defaults to its index in the enum. Removing or reordering variants therefore changes the
+/// permissions of other variants. Positions can be fixed with `#[acl_bit(n)]` on every variant,
+/// which allows removing a variant while keeping its position reserved:
+///
+/// ```ignore
+/// #[derive(AccessControlRole, Deserialize, Serialize, Copy, Clone)]
+/// pub enum Role {
+///     #[acl_bit(0)]
+///     Minter,
+///     // Position 1 belonged to a removed variant.
+///     #[acl_bit(2)]
+///     Burner,
+/// }
+/// ```
 pub trait AccessControlRole {
     /// Returns the names of all role variants.
     fn acl_role_variants() -> Vec<&'static str>;
@@ -15,6 +15,13 @@
 //! method to grantees of the specified `roles`. The method panics if it is called by an account
 //! which is not a grantee of any of the `roles`.
 //!
+//! Super-admins are not allowed to call such a method unless they were granted one of the `roles`.
+//! Passing `allow_super_admin`, as in `#[access_control_any(roles(...), allow_super_admin)]`,
+//! additionally permits super-admins to call the method.
+//!
+//! Similarly, `#[access_control_all(roles(...))]` requires the caller to be a grantee of _all_ of
+//! the specified `roles`. If roles are missing, the method panics with an error listing them.
+//!
 //! In addition, methods like `AccessControllable::has_role` can be used within other contract
 //! methods to restrict access to certain features or actions.
 //!
@@ -26,17 +33,188 @@
 //! admin for role `r` but not a grantee of role `r` may not use methods or features restricted to
 //! role `r`.
 //!
+//! Deployments which want admins to only grant and revoke their role may reserve the management of
+//! admins to super-admins:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), admins_manage_admins = false)]
+//! ```
+//!
+//! Then `acl_add_admin`, `acl_revoke_admin` and `acl_transfer_admin` behave as if the predecessor
+//! lacked permissions unless it is a super-admin. Admins may still renounce their own admin
+//! permissions.
+//!
 //! Besides (regular) admins the `AccessControllable` trait also defines super-admins. A super-admin
 //! is considered admin for every role. An `AccessControllable` contract can have zero or more
 //! super-admins.
 //!
+//! Revoking the last super-admin via `acl_revoke_super_admin` leaves the contract without
+//! governance. To forbid that, the contract may opt in to:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), prevent_last_super_admin_removal)]
+//! ```
+//!
+//! Then `acl_revoke_super_admin` panics if `account_id` is the only super-admin. Only super-admins
+//! stored in the bearer set are counted, i.e. an owner treated as super-admin is not. The
+//! `*_unchecked` methods are not affected.
+//!
+//! ## Rate limiting
+//!
+//! As a defense in depth against compromised keys, calls of sensitive methods may be limited even
+//! for authorized accounts. `#[access_control]` generates a method which is not exposed as a
+//! contract method and can be called at the top of such methods:
+//!
+//! ```ignore
+//! #[access_control_any(roles(Role::Minter))]
+//! pub fn mint(&mut self, amount: U128) {
+//!     // At most 10 calls per account within an hour.
+//!     self.acl_check_and_record_rate_limit("mint", 10, 3_600_000_000_000);
+//!     // ...
+//! }
+//! ```
+//!
+//! It panics if the predecessor exceeds the limit, with a message prefixed by the code of
+//! [`PluginError::RateLimitExceeded`](crate::PluginError::RateLimitExceeded). Call counts are
+//! stored per key and account in the plugin's storage.
+//!
+//! ## Expiring roles
+//!
+//! Roles may be granted with an expiration via `acl_grant_role_until`, e.g. to a contractor who
+//! should deploy code for a week. This requires opting in:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), expiring_roles)]
+//! ```
+//!
+//! Then checking a role, including the checks of `access_control_any`, reads the expiration of
+//! the grant from storage. Without `expiring_roles`, no expirations are read and
+//! `acl_grant_role_until` panics.
+//!
+//! Once a grant has expired the account is no longer considered a grantee, though it is still
+//! returned by getters like `acl_get_grantees` until the role is revoked. Expired grants can be
+//! revoked by anyone via `acl_prune_expired_grants`.
+//!
+//! ## Owner as super-admin
+//!
+//! A contract which is also `Ownable` may opt in to treating its owner as super-admin:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), owner_is_super_admin)]
+//! ```
+//!
+//! The owner then passes every super-admin check without being added explicitly, and loses these
+//! permissions once ownership is transferred or renounced. With `#[ownable(multi)]`, every
+//! co-owner passes these checks. Since the owner is not stored in the bearer sets, it is not
+//! returned by methods like `acl_get_super_admins`. If the owner is stored under a custom key, the
+//! same key must be passed via `owner_storage_key = "..."`.
+//!
+//! ## Exposing unchecked methods
+//!
+//! The `*_unchecked` methods of the struct returned by `acl_get_or_init` skip all permission
+//! checks and are not contract methods. Contracts that want the contract account itself to be able
+//! to call some of them, e.g. for administration via full access keys, may let the macro generate
+//! `#[private]` contract methods:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), expose_unchecked(grant_role, revoke_role))]
+//! ```
+//!
+//! This generates `acl_grant_role_unchecked(role, account_id)` and
+//! `acl_revoke_role_unchecked(role, account_id)`, which may only be called by the contract
+//! itself. The supported operations are `add_super_admin`, `revoke_super_admin`, `add_admin`,
+//! `revoke_admin`, `grant_role` and `revoke_role`.
+//!
+//! ## Delegating roles
+//!
+//! Admins for a role may grant and revoke it, but admin permissions are set per role. To allow
+//! grantees of a role to grant and revoke a fixed subset of other roles without making them
+//! admins, roles can be delegated:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), delegated_roles(Hr(Role::Operator, Role::Viewer)))]
+//! ```
+//!
+//! Then grantees of `Role::Hr` may grant and revoke `Role::Operator` and `Role::Viewer` in
+//! addition to the roles they are admin for. Delegation does not allow managing admins.
+//!
+//! ## Simulating guards
+//!
+//! To check whether an account would be allowed to call a method guarded by
+//! `access_control_any`, the method can be registered with `#[access_control]`:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), guarded_methods(mint))]
+//! ```
+//!
+//! Then `acl_would_authorize("mint", account_id)` returns whether `account_id` would pass the
+//! guard of `mint`. The roles and `allow_super_admin` are taken from the guard itself, which
+//! generates a hidden function next to the method.
+//!
+//! Since methods are expanded independently of `#[access_control]`, they can't be discovered and
+//! the list must be complete. This is checked at compile time:
+//!
+//! - Compilation fails if a registered method is not guarded by `access_control_any`.
+//! - Once `guarded_methods` is set, compilation fails if a `pub` method guarded by
+//!   `access_control_any` is missing in the list.
+//!
+//! Only `pub` methods outside of trait impls can be registered. Guards of other methods, e.g.
+//! the ones generated by plugins, can't be simulated and `acl_would_authorize` panics for them.
+//!
+//! ## Pagination
+//!
+//! Paginated getters like `acl_get_grantees` panic with the code of
+//! [`PluginError::PageSizeExceeded`](crate::PluginError::PageSizeExceeded) if `limit` exceeds 500,
+//! preventing calls that would run out of gas due to an accidentally huge `limit`. The maximum can
+//! be configured:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), max_page_size = 100)]
+//! ```
+//!
+//! ## Audit log
+//!
+//! Events are available only to indexers. Contracts that want a small on-chain history of
+//! permission changes may opt in to an audit log which stores the most recent changes:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), audit_log(capacity = 64))]
+//! ```
+//!
+//! Every change that emits one of the [`events`] is recorded as an [`AclAuditEntry`]. Once
+//! `capacity` entries are stored, new entries overwrite the oldest ones, which bounds the storage
+//! used by the log. Entries are returned by `acl_recent_events`, starting with the most recent
+//! one.
+//!
+//! ## Relayed calls
+//!
+//! By default, permissions of the predecessor account are checked. If calls are relayed, e.g. by
+//! a relayer of meta transactions, the predecessor might not be the account whose permissions
+//! should be checked. A function `fn() -> AccountId` resolving the effective caller can be
+//! configured:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), caller_resolver = "crate::relay::effective_caller")]
+//! ```
+//!
+//! The resolved account is then used by all permission checks of this plugin, by
+//! `access_control_any` and `access_control_all`, by the role checks of other plugins, e.g.
+//! `#[only(any_of_roles(...))]`, and by `#[frozen_guard]`. It is also the `by` field of events
+//! emitted by this plugin and of events for changes authorized via roles by other plugins, e.g.
+//! pausing a feature. Contract methods may access it via the generated method
+//! `Contract::acl_effective_caller()`. The resolver must not return an arbitrary account
+//! controlled by the predecessor, since that would allow anyone to act with the permissions of
+//! that account.
+//!
 //! ## Credits
 //!
 //! Inspired by OpenZeppelin's
 //! [AccessControl](https://docs.openzeppelin.com/contracts/3.x/api/access#AccessControl) module.
 
+use near_sdk::borsh::{BorshDeserialize, BorshSerialize};
 use near_sdk::serde::{Deserialize, Serialize};
-use near_sdk::AccountId;
+use near_sdk::json_types::U64;
+use near_sdk::{AccountId, CryptoHash, Timestamp};
 use std::collections::HashMap;
 
 /// # Representation of roles
@@ -61,9 +239,11 @@ pub trait AccessControllable {
     /// Attribute `storage_prefix` can be used to set a different prefix:
     ///
     /// ```ignore
-    /// #[access_controllable(storage_prefix="CUSTOM_KEY")]
+    /// #[access_control(role_type(Role), storage_prefix="CUSTOM_KEY")]
     /// struct Contract { /* ... */}
     /// ```
+    ///
+    /// All keys used by the plugin start with this prefix.
     fn acl_storage_prefix() -> &'static [u8];
 
     /// Returns the names of all variants of the enum that represents roles.
@@ -152,6 +332,9 @@ pub trait AccessControllable {
     /// whether `account_id` was a super-admin. Without permissions, `None` is
     /// returned and internal state is not modified.
     ///
+    /// With `prevent_last_super_admin_removal`, it panics if `account_id` is the
+    /// last super-admin.
+    ///
     /// If super-admin permissions are revoked, the following event will be
     /// emitted:
     ///
@@ -208,7 +391,8 @@ pub trait AccessControllable {
     fn acl_transfer_super_admin(&mut self, account_id: AccountId) -> Option<bool>;
 
     /// Makes `account_id` an admin provided that the predecessor has sufficient
-    /// permissions, i.e. is an admin as defined by [`acl_is_admin`].
+    /// permissions, i.e. is an admin as defined by [`acl_is_admin`]. With
+    /// `admins_manage_admins = false`, the predecessor must be a super-admin.
     ///
     /// In case of sufficient permissions, the returned `Some(bool)` indicates
     /// whether `account_id` is a new admin for `role`. Without permissions,
@@ -232,6 +416,21 @@ pub trait AccessControllable {
     /// ```
     fn acl_add_admin(&mut self, role: String, account_id: AccountId) -> Option<bool>;
 
+    /// Transfers admin permissions for `role` from the predecessor to `account_id` provided that
+    /// the predecessor has sufficient permissions, i.e. is an admin as defined by
+    /// [`acl_is_admin`]. Analogous to [`Self::acl_transfer_super_admin`], `account_id` is added as
+    /// admin before the predecessor's admin permissions for `role` are revoked. If the predecessor
+    /// is a super-admin, their super-admin permissions are not affected. With
+    /// `admins_manage_admins = false`, the predecessor must be a super-admin.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates whether `account_id`
+    /// is a new admin for `role`. Transferring to the predecessor itself is a no-op which returns
+    /// `Some(true)`. Without permissions, `None` is returned and internal state is not modified.
+    ///
+    /// If admin permissions are transferred, the events described in [`Self::acl_add_admin`] and
+    /// [`Self::acl_revoke_admin`] will be emitted.
+    fn acl_transfer_admin(&mut self, role: String, account_id: AccountId) -> Option<bool>;
+
     /// Returns whether `account_id` is an admin for `role`. Super-admins are
     /// admins for _every_ role.
     ///
@@ -240,10 +439,19 @@ pub trait AccessControllable {
     /// explicitly. The same applies to super-admins.
     fn acl_is_admin(&self, role: String, account_id: AccountId) -> bool;
 
+    /// Returns whether `account_id` was explicitly added as admin for `role`.
+    ///
+    /// In contrast to [`Self::acl_is_admin`], super-admins are not considered
+    /// admins for every role, unless they were also added as admin for `role`.
+    /// This allows to distinguish explicit admins from super-admins, e.g. when
+    /// displaying the admins of a role.
+    fn acl_is_role_admin_exact(&self, role: String, account_id: AccountId) -> bool;
+
     /// Revokes admin permissions for `role` from `account_id` provided that the
     /// predecessor has sufficient permissions, i.e. is an admin as defined by
     /// [`acl_is_admin`]. This means an admin for `role` may revoke admin
-    /// permissions from any other account that is admin for `role`.
+    /// permissions from any other account that is admin for `role`. With
+    /// `admins_manage_admins = false`, the predecessor must be a super-admin.
     ///
     /// In case of sufficient permissions, the returned `Some(bool)` indicates
     /// whether `account_id` was an admin for `role`. Without permissions,
@@ -273,7 +481,9 @@ pub trait AccessControllable {
     fn acl_renounce_admin(&mut self, role: String) -> bool;
 
     /// Grants `role` to `account_id` provided that the predecessor has
-    /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`].
+    /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`]
+    /// or a grantee of a role which `role` is delegated to (see
+    /// [Delegating roles](crate::access_controllable#delegating-roles)).
     ///
     /// In case of sufficient permissions, the returned `Some(bool)` indicates
     /// whether `account_id` is a new grantee of `role`. Without permissions,
@@ -295,13 +505,167 @@ pub trait AccessControllable {
     /// ```
     fn acl_grant_role(&mut self, role: String, account_id: AccountId) -> Option<bool>;
 
+    /// Behaves like [`Self::acl_grant_role`], but includes `memo` in the
+    /// emitted event, e.g. to justify the grant on-chain. The memo is only
+    /// logged, not stored. If `account_id` already is a grantee, no event is
+    /// emitted and the memo is dropped.
+    ///
+    /// ```json
+    /// {
+    ///    "standard":"AccessControllable",
+    ///    "version":"1.0.0",
+    ///    "event":"role_granted",
+    ///    "data": {
+    ///       "role":"<ROLE>",
+    ///       "to":"<GRANTEE>",
+    ///       "by":"<ADMIN>",
+    ///       "memo":"<MEMO>"
+    ///    }
+    /// }
+    /// ```
+    ///
+    /// The field `memo` is omitted in events emitted by other methods.
+    fn acl_grant_role_with_memo(
+        &mut self,
+        role: String,
+        account_id: AccountId,
+        memo: String,
+    ) -> Option<bool>;
+
+    /// Grants each of the `roles` to `account_id`, e.g. to onboard a new operator in a single
+    /// transaction.
+    ///
+    /// Permissions are checked per role, as in [`Self::acl_grant_role`]. The returned vector
+    /// contains the result of granting `roles[i]` at index `i`, with `None` for roles the
+    /// predecessor is not admin of. An event as described in [`Self::acl_grant_role`] is emitted
+    /// for every role that is granted.
+    ///
+    /// It panics if any of the `roles` is invalid, in which case no role is granted.
+    fn acl_grant_roles(&mut self, roles: Vec<String>, account_id: AccountId) -> Vec<Option<bool>>;
+
+    /// Makes `account_id` both an admin for `role` and a grantee of `role`, e.g. to onboard an
+    /// operator who administers the role they hold in a single call. The predecessor must be a
+    /// super-admin.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates whether any change
+    /// occurred, i.e. whether `account_id` is a new admin or a new grantee of `role`. Without
+    /// permissions, `None` is returned and internal state is not modified.
+    ///
+    /// The events described in [`Self::acl_add_admin`] and [`Self::acl_grant_role`] are emitted for
+    /// each change.
+    fn acl_make_role_operator(&mut self, role: String, account_id: AccountId) -> Option<bool>;
+
+    /// Grants `role` to `account_id` provided that the predecessor has
+    /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`]
+    /// or a grantee of a role which `role` is delegated to (see
+    /// [Delegating roles](crate::access_controllable#delegating-roles)).
+    ///
+    /// It behaves like [`Self::acl_grant_role`], except that the outcome is
+    /// represented by an [`AclGrantResult`]. This allows callers to
+    /// distinguish missing permissions from `account_id` already being a
+    /// grantee of `role` without having to interpret an `Option<bool>`.
+    ///
+    /// If a role is granted, the event described in [`Self::acl_grant_role`]
+    /// will be emitted.
+    fn acl_try_grant_role(&mut self, role: String, account_id: AccountId) -> AclGrantResult;
+
+    /// Ensures `account_id` is a grantee of `role`, e.g. for scripts that reconcile the desired
+    /// state of access control. Returns `true` if `role` was granted by this call and `false` if
+    /// `account_id` already was a grantee.
+    ///
+    /// Like [`Self::acl_grant_role`], it requires the predecessor to be an admin as defined by
+    /// [`acl_is_admin`]. In contrast to `acl_grant_role`, which returns `None` without
+    /// permissions, this method panics if the predecessor is not authorized. Hence the returned
+    /// value only describes whether state was modified.
+    ///
+    /// If a role is granted, the event described in [`Self::acl_grant_role`]
+    /// will be emitted.
+    fn acl_ensure_role(&mut self, role: String, account_id: AccountId) -> bool;
+
+    /// Grants `to` all permissions of `from`, e.g. to rotate an operator to a
+    /// new account. Roles, admin permissions and super-admin permissions are
+    /// copied. Returns the number of permissions that `to` was newly granted,
+    /// i.e. permissions which `to` already had are not counted.
+    ///
+    /// Grants of `from` which have expired are not copied, while grants with
+    /// an expiration that has not passed yet are copied along with their
+    /// expiration. If `to` already bears a role with an expiration, `to` keeps
+    /// the role at least as long as `from`: the expiration is extended if the
+    /// one of `from` is later and removed if `from` bears the role permanently.
+    /// Such extensions are not counted as newly granted. Permissions of `from`
+    /// are not modified, revoking them is left to the caller.
+    ///
+    /// Only super-admins may call this method. It panics if the predecessor
+    /// is not a super-admin. Events are emitted as if each permission was
+    /// granted individually.
+    fn acl_clone_permissions(&mut self, from: AccountId, to: AccountId) -> u32;
+
+    /// Grants `role` to `account_id` until the block timestamp reaches
+    /// `expires_at`, provided that the predecessor has sufficient permissions,
+    /// i.e. is an admin as defined by [`acl_is_admin`]. Once the grant has
+    /// expired, `account_id` is no longer considered a grantee of `role`.
+    ///
+    /// `expires_at` is a timestamp in nanoseconds. It is passed as a string in
+    /// JSON, since JavaScript numbers cannot represent such timestamps
+    /// exactly. The method panics with the code of
+    /// [`PluginError::InvalidExpiration`](crate::PluginError::InvalidExpiration)
+    /// if `expires_at` is not later than the current block timestamp.
+    ///
+    /// In case of sufficient permissions, the returned `Some(bool)` indicates
+    /// whether `account_id` is a new grantee of `role`. Without permissions,
+    /// `None` is returned and internal state is not modified. If `account_id`
+    /// already is a grantee of `role`, its expiration is set to `expires_at`.
+    ///
+    /// Granting `role` via [`Self::acl_grant_role`] removes the expiration and
+    /// revoking `role` clears it.
+    ///
+    /// Note that accounts whose grant expired are still returned by
+    /// [`Self::acl_get_grantees`] and counted by [`Self::acl_get_grantees_count`]
+    /// until `role` is revoked from them, e.g. via
+    /// [`Self::acl_prune_expired_grants`].
+    ///
+    /// If a role is granted, the event described in [`Self::acl_grant_role`]
+    /// will be emitted. The method panics unless expiring roles are enabled,
+    /// see the [module documentation](self).
+    fn acl_grant_role_until(
+        &mut self,
+        role: String,
+        account_id: AccountId,
+        expires_at: U64,
+    ) -> Option<bool>;
+
+    /// Revokes `role` from every account in `account_ids` whose grant of
+    /// `role` has expired, and returns the number of such accounts. Other
+    /// accounts are not modified. This removes expired grantees from getters
+    /// like [`Self::acl_get_grantees`] and releases their storage.
+    ///
+    /// Since expired grants are no longer effective, any account may call this
+    /// method. Expired grantees may be found by checking the accounts returned
+    /// by [`Self::acl_get_grantees`] with [`Self::acl_has_role`]. It panics if
+    /// the number of `account_ids` exceeds the configured maximum page size.
+    ///
+    /// For every revoked grant, the event described in
+    /// [`Self::acl_revoke_role`] is emitted.
+    fn acl_prune_expired_grants(&mut self, role: String, account_ids: Vec<AccountId>) -> u32;
+
     /// Returns whether `account_id` has been granted `role`. Note that adding
     /// an account as (super-)admin for `role` does not make that account a
     /// grantee of `role`. Instead, `role` has to be granted explicitly.
+    ///
+    /// A grant whose expiration has passed is treated as absent.
     fn acl_has_role(&self, role: String, account_id: AccountId) -> bool;
 
+    /// Returns whether `account_id` has been granted `role`, like
+    /// [`Self::acl_has_role`]. Instead of reading the permissions of
+    /// `account_id`, it checks whether `account_id` is contained in the set of
+    /// grantees of `role`. This may be cheaper for tooling that tests many
+    /// candidate accounts for a single role.
+    fn acl_role_contains(&self, role: String, account_id: AccountId) -> bool;
+
     /// Revokes `role` from `account_id` provided that the predecessor has
-    /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`].
+    /// sufficient permissions, i.e. is an admin as defined by [`acl_is_admin`]
+    /// or a grantee of a role which `role` is delegated to (see
+    /// [Delegating roles](crate::access_controllable#delegating-roles)).
     ///
     /// In case of sufficient permissions, the returned `Some(bool)` indicates
     /// whether `account_id` was a grantee of `role`. Without permissions,
@@ -323,6 +687,20 @@ pub trait AccessControllable {
     /// ```
     fn acl_revoke_role(&mut self, role: String, account_id: AccountId) -> Option<bool>;
 
+    /// Previews the outcome of `revoker` calling [`Self::acl_revoke_role`] with `role` and
+    /// `account_id`, without modifying state. This allows front-ends to avoid transactions which
+    /// would return `None` or `Some(false)`.
+    ///
+    /// The revoker is passed explicitly since the predecessor is not available in view calls.
+    /// The returned `authorized` mirrors the permission check of [`Self::acl_revoke_role`] and
+    /// `would_change` indicates whether `account_id` is a grantee of `role`.
+    fn acl_revoke_role_preview(
+        &self,
+        role: String,
+        account_id: AccountId,
+        revoker: AccountId,
+    ) -> RevokeRolePreview;
+
     /// Revokes `role` from the predecessor and returns whether it was a grantee
     /// of `role`.
     ///
@@ -330,21 +708,185 @@ pub trait AccessControllable {
     /// will be emitted.
     fn acl_renounce_role(&mut self, role: String) -> bool;
 
+    /// Grants `role` to the contract itself without checking permissions and returns whether the
+    /// contract is a new grantee of `role`. This method is `#[private]`, i.e. it may only be
+    /// called by the contract itself.
+    ///
+    /// It is meant for constructors and other private methods that temporarily grant a role to
+    /// the contract to call methods guarded by `access_control_any`:
+    ///
+    /// ```ignore
+    /// contract.acl_grant_role_to_self(Role::DurationManager.into());
+    /// contract.up_init_staging_duration(staging_duration);
+    /// contract.acl_revoke_role_from_self(Role::DurationManager.into());
+    /// ```
+    ///
+    /// If the role is granted, the event described in [`Self::acl_grant_role`] will be emitted.
+    fn acl_grant_role_to_self(&mut self, role: String) -> bool;
+
+    /// Revokes `role` from the contract itself without checking permissions and returns whether
+    /// the contract was a grantee of `role`. Like [`Self::acl_grant_role_to_self`], this method
+    /// is `#[private]`.
+    ///
+    /// If the role is revoked, the event described in [`Self::acl_revoke_role`] will be emitted.
+    fn acl_revoke_role_from_self(&mut self, role: String) -> bool;
+
     /// Returns whether `account_id` has been granted any of the `roles`.
     fn acl_has_any_role(&self, roles: Vec<String>, account_id: AccountId) -> bool;
 
+    /// Returns whether `account_id` would pass the `access_control_any` guard of `method`. This
+    /// is meant for debugging authorization and does not modify state.
+    ///
+    /// The method must be registered via the `guarded_methods` passed to `#[access_control]`,
+    /// which must list every `pub` method guarded by `access_control_any`, see the
+    /// [module documentation](self). It panics if `method` is not registered there.
+    fn acl_would_authorize(&self, method: String, account_id: AccountId) -> bool;
+
+    /// Returns whether `account_id` has been granted all of the `roles`.
+    fn acl_has_all_roles(&self, roles: Vec<String>, account_id: AccountId) -> bool;
+
+    /// Returns the roles granted to the predecessor, in the order of role variants. It allows a
+    /// connected wallet to introspect its own permissions without passing its account id.
+    ///
+    /// Since the predecessor is not available in view calls, this method must be called in a
+    /// transaction. The result equals calling [`Self::acl_has_role`] for every role.
+    fn acl_my_roles(&self) -> Vec<String>;
+
+    /// Returns whether the predecessor is super-admin, like [`Self::acl_is_super_admin`] does for
+    /// an explicitly passed account. It must be called in a transaction as well.
+    fn acl_am_i_super_admin(&self) -> bool;
+
+    /// Returns the raw bitflags stored for `account_id`, or 0 if no permissions are stored.
+    ///
+    /// This is a low-level view for tooling, which may decode the returned value using
+    /// `AccessControlRole::acl_super_admin_permission`, `AccessControlRole::acl_permission` and
+    /// `AccessControlRole::acl_admin_permission`. Note that the stored bits do not account for
+    /// expiration of roles granted via [`Self::acl_grant_role_until`].
+    fn acl_get_permissions_raw(&self, account_id: AccountId) -> u128;
+
+    /// Returns the name of the role whose permission is represented by bit `bit_index`, e.g. in
+    /// the value returned by [`Self::acl_get_permissions_raw`]. It is `None` if the bit doesn't
+    /// represent a role permission, which includes the bits of super-admin and admin permissions.
+    ///
+    /// Permissions are stored by bit position, which is derived from the position of a role's
+    /// variant in the enum. Renaming a variant keeps permissions valid while changing the role's
+    /// name, so this method allows tooling to detect and repair drift between names and bits.
+    fn acl_role_name_for_bit(&self, bit_index: u8) -> Option<String>;
+
+    /// Returns the index of the bit representing the permission of role `name`, or `None` if
+    /// `name` is not a valid role. The corresponding admin permission is represented by the next
+    /// higher bit.
+    fn acl_bit_for_role_name(&self, name: String) -> Option<u8>;
+
     /// Enables paginated retrieval of super-admins. It returns up to `limit`
     /// super-admins and skips the first `skip` super-admins.
     fn acl_get_super_admins(&self, skip: u64, limit: u64) -> Vec<AccountId>;
 
+    /// Returns all super-admins sorted lexicographically by account id. In
+    /// contrast to [`Self::acl_get_super_admins`], the order does not depend on
+    /// the order in which super-admins were added or removed, which allows
+    /// reproducible snapshots.
+    ///
+    /// All super-admins are loaded and sorted on every call, which is
+    /// acceptable since there are typically only few of them.
+    fn acl_get_super_admins_sorted(&self) -> Vec<AccountId>;
+
+    /// Enables paginated retrieval of the audit log, see the [module documentation](self). It
+    /// returns up to `limit` entries, starting with the most recent one, and skips the `skip`
+    /// most recent entries.
+    ///
+    /// The log holds at most the `capacity` passed to `audit_log`. If the audit log is not
+    /// enabled, an empty vector is returned.
+    fn acl_recent_events(&self, skip: u64, limit: u64) -> Vec<AclAuditEntry>;
+
     /// Enables paginated retrieval of admins of `role`. It returns up to
     /// `limit` admins and skips the first `skip` admins.
     fn acl_get_admins(&self, role: String, skip: u64, limit: u64) -> Vec<AccountId>;
 
     /// Enables paginated retrieval of grantees of `role`. It returns up to
     /// `limit` grantees and skips the first `skip` grantees.
+    ///
+    /// Grantees are returned in a consistent order across calls. Revoking
+    /// `role` preserves the relative order of the remaining grantees, though
+    /// the position of grantees that follow the revoked account decreases by
+    /// one. Newly granted accounts may fill positions freed by revocations
+    /// instead of being appended. Hence pages retrieved before and after a
+    /// change may overlap or miss accounts.
+    /// [`Self::acl_get_grantees_sorted`] provides an order that depends only
+    /// on the set of grantees.
     fn acl_get_grantees(&self, role: String, skip: u64, limit: u64) -> Vec<AccountId>;
 
+    /// Like [`Self::acl_get_grantees`], but each grantee is returned along with all roles granted
+    /// to it, in the order of role variants. This allows e.g. admin consoles to display grantees
+    /// and their other roles without querying the roles of every grantee separately.
+    ///
+    /// Since the permissions of every returned grantee are read, this method panics if `limit`
+    /// exceeds 50, or the configured maximum page size if that is smaller.
+    fn acl_get_grantees_detailed(
+        &self,
+        role: String,
+        skip: u64,
+        limit: u64,
+    ) -> Vec<(AccountId, Vec<String>)>;
+
+    /// Returns admins and grantees of `role` in a single call, e.g. for user interfaces that
+    /// display the members of a role. Paging is applied to each list separately, i.e. `admins`
+    /// equals the result of [`Self::acl_get_admins`] and `grantees` equals the result of
+    /// [`Self::acl_get_grantees`] for the same `skip` and `limit`.
+    fn acl_get_role_members(
+        &self,
+        role: String,
+        skip: u64,
+        limit: u64,
+    ) -> PermissionedAccountsPerRole;
+
+    /// Like [`Self::acl_get_grantees`], but grantees are sorted
+    /// lexicographically by account id before `skip` and `limit` are
+    /// applied. Hence granting or revoking `role` does not shift the
+    /// position of accounts that precede the affected account.
+    ///
+    /// All grantees of `role` are loaded and sorted on every call, so the
+    /// cost of this method grows with the number of grantees.
+    fn acl_get_grantees_sorted(&self, role: String, skip: u64, limit: u64) -> Vec<AccountId>;
+
+    /// Enables cursor-based retrieval of grantees of `role`. It returns up to
+    /// `limit` grantees in lexicographic order, starting at the first grantee
+    /// that is greater than or equal to `from`. If `from` is `None`, retrieval
+    /// starts at the first grantee.
+    ///
+    /// The `next` field of the returned page is the cursor to pass as `from`
+    /// to retrieve the next page. It is `None` once all grantees have been
+    /// retrieved. Since the cursor is an account id instead of a position,
+    /// granting or revoking `role` between calls does not cause accounts
+    /// which remain grantees to be skipped or returned twice.
+    ///
+    /// Grantees are read from an index ordered by account id, so the cost of
+    /// a page depends on `limit` rather than on the number of grantees. The
+    /// index contains only accounts granted `role` by a version of the plugin
+    /// that maintains it. While grantees added by a previous version remain,
+    /// all grantees of `role` are loaded and sorted on every call instead.
+    fn acl_get_grantees_page(
+        &self,
+        role: String,
+        from: Option<AccountId>,
+        limit: u64,
+    ) -> GranteesPage;
+
+    /// Returns the number of super-admins. Unlike paging through
+    /// [`Self::acl_get_super_admins`], the cost of this method does not depend
+    /// on the number of super-admins.
+    fn acl_get_super_admins_count(&self) -> u64;
+
+    /// Returns the number of admins of `role`. Unlike paging through
+    /// [`Self::acl_get_admins`], the cost of this method does not depend on the
+    /// number of admins.
+    fn acl_get_admins_count(&self, role: String) -> u64;
+
+    /// Returns the number of grantees of `role`. Unlike paging through
+    /// [`Self::acl_get_grantees`], the cost of this method does not depend on
+    /// the number of grantees.
+    fn acl_get_grantees_count(&self, role: String) -> u64;
+
     /// Convenience method that returns all [`PermissionedAccounts`].
     ///
     /// # Gas limits
@@ -361,6 +903,169 @@ pub trait AccessControllable {
     ///
     /// [gas limit]: https://github.com/near/nearcore/pull/4381
     fn acl_get_permissioned_accounts(&self) -> PermissionedAccounts;
+
+    /// Returns all admins and grantees of `role`, i.e. the entry of `role` in the result of
+    /// [`Self::acl_get_permissioned_accounts`]. In contrast to the latter, only the permissions of
+    /// `role` are read, which keeps gas usage low for contracts with many roles.
+    ///
+    /// Like [`Self::acl_get_role_members`], it returns admins and grantees in a single call,
+    /// though without paging.
+    fn acl_get_permissioned_accounts_for_role(&self, role: String) -> PermissionedAccountsPerRole;
+
+    /// Returns the sha256 hash of all permissioned accounts, as computed by
+    /// [`PermissionedAccounts::snapshot_hash`]. A DAO may store the hash it
+    /// expects and compare it to the live hash to detect unexpected permission
+    /// changes. The expected hash can be computed off-chain from the result of
+    /// [`Self::acl_get_permissioned_accounts`].
+    ///
+    /// # Gas limits
+    ///
+    /// Like [`Self::acl_get_permissioned_accounts`], this method reads _all_
+    /// permissioned accounts. In addition, it sorts and hashes them. Hence its
+    /// gas usage grows with the number of roles and permissioned accounts and
+    /// it may exceed the [gas limit] of view calls for large sets.
+    ///
+    /// [gas limit]: https://github.com/near/nearcore/pull/4381
+    fn acl_permissions_snapshot_hash(&self) -> CryptoHash;
+
+    /// Enables retrieving _all_ permissioned accounts in chunks, for sets that
+    /// are too large to be returned by [`Self::acl_get_permissioned_accounts`]
+    /// within the gas and return size limits of a single call.
+    ///
+    /// Accounts are returned in the following order: super-admins, followed by
+    /// admins and grantees of every role in the order of
+    /// [`Self::acl_role_variants`]. A chunk contains at most `max_accounts`
+    /// accounts and may span several roles. Its `next_cursor` is the cursor to
+    /// pass to retrieve the next chunk. It is `None` once all permissioned
+    /// accounts have been retrieved. If `cursor` is `None`, retrieval starts at
+    /// the first super-admin.
+    ///
+    /// Within a set of bearers, accounts are returned in lexicographic order
+    /// and the cursor refers to the next account by its id, like when paging
+    /// via [`Self::acl_get_grantees_page`]. Hence permission changes between
+    /// calls neither cause accounts to be skipped nor to be returned twice,
+    /// except for the changed accounts themselves.
+    ///
+    /// Panics if `max_accounts` is zero or exceeds the configured maximum
+    /// page size, or if `cursor` does not refer to a role of the contract.
+    fn acl_get_permissioned_accounts_chunk(
+        &self,
+        cursor: Option<AclCursor>,
+        max_accounts: u32,
+    ) -> PermissionedAccountsChunk;
+}
+
+/// The outcome of [`AccessControllable::acl_try_grant_role`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AclGrantResult {
+    /// The predecessor lacks the permissions required to grant the role. State was not modified.
+    Unauthorized,
+    /// The account was already a grantee of the role. State was not modified.
+    AlreadyGranted,
+    /// The role was newly granted to the account.
+    Granted,
+}
+
+/// A page of grantees returned by
+/// [`AccessControllable::acl_get_grantees_page`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct GranteesPage {
+    /// Grantees in lexicographic order.
+    pub accounts: Vec<AccountId>,
+    /// The cursor to retrieve the next page, `None` if there are no more
+    /// grantees.
+    pub next: Option<AccountId>,
+}
+
+/// The kind of permission by which an account is contained in a
+/// [`PermissionedAccountsChunk`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AclPermissionKind {
+    SuperAdmin,
+    Admin,
+    Grantee,
+}
+
+/// Position in the set of all permissioned accounts, as returned by
+/// [`AccessControllable::acl_get_permissioned_accounts_chunk`].
+///
+/// Clients should treat the cursor as opaque and pass it back unmodified. Its
+/// serialization is stable, so it may be persisted to resume retrieval later.
+/// It remains valid as long as the variants of the role enum are not
+/// reordered. If the account it refers to loses its permission in the
+/// meantime, retrieval resumes at the next account of the set.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AclCursor {
+    /// Index of the role in [`AccessControllable::acl_role_variants`]. It is
+    /// zero for super-admins.
+    pub role_index: u32,
+    /// The set of bearers the cursor points into.
+    pub kind: AclPermissionKind,
+    /// The next account to retrieve from the set of bearers. Accounts are
+    /// retrieved in lexicographic order, starting at the first account which
+    /// is greater than or equal to `from`. If `None`, retrieval starts at the
+    /// first account of the set.
+    pub from: Option<AccountId>,
+}
+
+/// An account contained in a [`PermissionedAccountsChunk`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PermissionedAccount {
+    pub account_id: AccountId,
+    pub kind: AclPermissionKind,
+    /// The role the account is admin or grantee of, `None` for super-admins.
+    pub role: Option<String>,
+}
+
+/// A chunk of permissioned accounts returned by
+/// [`AccessControllable::acl_get_permissioned_accounts_chunk`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PermissionedAccountsChunk {
+    pub accounts: Vec<PermissionedAccount>,
+    /// The cursor to retrieve the next chunk, `None` if there are no more
+    /// permissioned accounts.
+    pub next_cursor: Option<AclCursor>,
+}
+
+/// The kind of permission change recorded in an [`AclAuditEntry`].
+#[derive(
+    BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq,
+)]
+#[borsh(crate = "near_sdk::borsh")]
+pub enum AclAuditAction {
+    SuperAdminAdded,
+    SuperAdminRevoked,
+    AdminAdded,
+    AdminRevoked,
+    RoleGranted,
+    RoleRevoked,
+}
+
+/// An entry of the audit log as returned by [`AccessControllable::acl_recent_events`].
+#[derive(BorshDeserialize, BorshSerialize, Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[borsh(crate = "near_sdk::borsh")]
+pub struct AclAuditEntry {
+    /// The kind of permission change.
+    pub action: AclAuditAction,
+    /// The role whose permissions changed, `None` for changes of super-admin permissions.
+    pub role: Option<String>,
+    /// The account whose permissions changed.
+    pub account: AccountId,
+    /// The account that made the change.
+    pub by: AccountId,
+    /// The block timestamp of the change, in nanoseconds.
+    pub timestamp: Timestamp,
+}
+
+/// The outcome of revoking a role as returned by
+/// [`AccessControllable::acl_revoke_role_preview`].
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RevokeRolePreview {
+    /// Whether the revoker is allowed to revoke the role.
+    pub authorized: bool,
+    /// Whether revoking the role would modify state, i.e. whether the account is a grantee of the
+    /// role.
+    pub would_change: bool,
 }
 
 /// Collects super admin accounts and accounts that have been granted permissions defined by
@@ -407,6 +1112,33 @@ pub struct PermissionedAccounts {
     pub roles: HashMap<String, PermissionedAccountsPerRole>,
 }
 
+impl PermissionedAccounts {
+    /// Returns the sha256 hash of the borsh serialization of the accounts in
+    /// canonical order: super-admins sorted by account id, followed by the
+    /// roles sorted by name, each with its sorted admins and grantees. Hence the
+    /// hash does not depend on the order in which accounts are stored.
+    pub fn snapshot_hash(&self) -> CryptoHash {
+        let mut super_admins = self.super_admins.clone();
+        super_admins.sort();
+        let mut roles: Vec<(String, Vec<AccountId>, Vec<AccountId>)> = self
+            .roles
+            .iter()
+            .map(|(role, accounts)| {
+                let mut admins = accounts.admins.clone();
+                admins.sort();
+                let mut grantees = accounts.grantees.clone();
+                grantees.sort();
+                (role.clone(), admins, grantees)
+            })
+            .collect();
+        roles.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let bytes = near_sdk::borsh::to_vec(&(super_admins, roles))
+            .unwrap_or_else(|_| near_sdk::env::panic_str("Failed to serialize accounts"));
+        near_sdk::env::sha256_array(&bytes)
+    }
+}
+
 /// Collects all admins and grantees of a role.
 ///
 /// # Uniqueness and ordering
@@ -420,6 +1152,47 @@ pub struct PermissionedAccountsPerRole {
     pub grantees: Vec<AccountId>,
 }
 
+/// The bearers of a permission ordered by account id, which back
+/// [`AccessControllable::acl_get_grantees_page`]. Used by the code generated by
+/// `#[access_control]`.
+#[doc(hidden)]
+pub type BearersIndex = near_sdk::store::TreeMap<AccountId, ()>;
+
+/// Returns whether `name` is contained in `registered`, or `true` if nothing is registered. It is
+/// evaluated at compile time by the code generated by `access_control_any` and `#[pause]` to
+/// verify that methods are registered in the attributes of the contract.
+#[doc(hidden)]
+pub const fn is_registered(registered: Option<&[&str]>, name: &str) -> bool {
+    let registered = match registered {
+        Some(registered) => registered,
+        None => return true,
+    };
+    let mut i = 0;
+    while i < registered.len() {
+        if str_eq(registered[i], name) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Compares strings in a `const` context, which doesn't support `==` on `str`.
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
 pub mod events {
     use crate::events::{AsEvent, EventMetadata};
     use near_sdk::serde::Serialize;
@@ -526,6 +1299,9 @@ pub mod events {
         pub to: AccountId,
         /// Account that granted the role.
         pub by: AccountId,
+        /// Optional justification passed to `acl_grant_role_with_memo`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub memo: Option<String>,
     }
 
     impl AsEvent<RoleGranted> for RoleGranted {
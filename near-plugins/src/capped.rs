@@ -0,0 +1,110 @@
+//! # Capped:
+//!
+//! Trait which allows contracts to maintain a `u128` counter that may never exceed a cap, e.g. the
+//! total supply of a token which is increased by privileged minters. The plugin is unaware of the
+//! meaning of the counter, it only enforces the cap.
+//!
+//! ## Default implementation:
+//!
+//! The counter is stored in a single storage slot and the cap is fixed at compile time. Calls of
+//! [`Capped::capped_add`] that would make the counter exceed the cap panic with
+//! "CAP001: Capped: Cap exceeded".
+//!
+//! As a precondition for being `Capped` a contract must be `AccessControllable`. Access control is
+//! used to define the accounts that may increase the counter, via the `adder_roles` passed to the
+//! derive macro. The cap is passed as string, since `u128` values may exceed the range of integer
+//! literals accepted by the macro:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role))]
+//! #[near(contract_state)]
+//! #[derive(Capped)]
+//! #[capped(cap = "1000000", adder_roles(Role::Minter))]
+//! struct Contract { /* ... */ }
+//!
+//! #[near]
+//! impl Contract {
+//!     pub fn mint(&mut self, amount: U128) {
+//!         // Panics if the caller is not a grantee of `Role::Minter` or the cap is exceeded.
+//!         self.capped_add(amount);
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! Note that [`Capped::capped_add`] is a contract method itself. Hence grantees of the
+//! `adder_roles` may call it directly to increase the counter without calling a method like `mint`
+//! in the example above, i.e. without minting anything. The counter is therefore an upper bound
+//! of the amounts added via contract specific methods, and `adder_roles` should only be granted to
+//! accounts which are trusted to increase the counter by arbitrary amounts up to the cap.
+use crate::events::{AsEvent, EventMetadata};
+use near_sdk::json_types::U128;
+use near_sdk::AccountId;
+use serde::Serialize;
+
+/// Trait describing the functionality of the `Capped` plugin.
+pub trait Capped {
+    /// Returns the key of the storage slot which contains the counter. By default `b"__CAPPED__"`
+    /// is used.
+    ///
+    /// Attribute `storage_prefix` can be used to set a different key:
+    ///
+    /// ```ignore
+    /// #[capped(cap = "1000000", adder_roles(Role::Minter), storage_prefix="CUSTOM_KEY")]
+    /// struct Contract { /* ... */}
+    /// ```
+    fn capped_storage_key(&self) -> &'static [u8];
+
+    /// Returns the cap, i.e. the maximum value of the counter.
+    fn capped_get_cap(&self) -> U128;
+
+    /// Returns the current value of the counter. It is zero if the counter was never increased.
+    fn capped_get_total(&self) -> U128;
+
+    /// Increases the counter by `amount` and returns the new value of the counter. This method
+    /// fails if the caller has not been granted one of the access control `adder_roles` passed to
+    /// the `Capped` plugin. It panics if the counter would exceed the cap.
+    ///
+    /// In the default implementation this method is exposed as contract method. Grantees of the
+    /// `adder_roles` may call it directly, bypassing contract methods which call it, e.g. to mint
+    /// tokens. See the [module documentation](crate::capped) for implications.
+    ///
+    /// The following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///   "standard":"Capped",
+    ///   "version":"1.0.0",
+    ///   "event":"add",
+    ///   "data":
+    ///     {
+    ///       "by":"<ADDER_ACCOUNT>",
+    ///       "amount":"<AMOUNT>",
+    ///       "total":"<TOTAL>"
+    ///     }
+    /// }
+    /// ```
+    fn capped_add(&mut self, amount: U128) -> U128;
+}
+
+/// Event emitted when the counter is increased.
+#[derive(Serialize, Clone)]
+pub struct CappedAdded {
+    /// Account Id that increased the counter.
+    pub by: AccountId,
+    /// The amount by which the counter was increased.
+    pub amount: U128,
+    /// The value of the counter after the increase.
+    pub total: U128,
+}
+
+impl AsEvent<CappedAdded> for CappedAdded {
+    fn metadata(&self) -> EventMetadata<CappedAdded> {
+        EventMetadata {
+            standard: "Capped".to_string(),
+            version: "1.0.0".to_string(),
+            event: "add".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+//! # ContractMetadata:
+//!
+//! Trait which aggregates the state of the plugins used by a contract into a single view, sparing
+//! dashboards from calling the views of each plugin individually.
+//!
+//! ## Default implementation:
+//!
+//! The plugins to aggregate are passed to the derive macro. Each of them must be implemented by the
+//! contract, otherwise compilation fails with an unsatisfied trait bound:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role))]
+//! #[near(contract_state)]
+//! #[derive(Ownable, Pausable, Upgradable, ContractMetadata)]
+//! #[contract_metadata(ownable, pausable, access_controllable, upgradable)]
+//! struct Contract { /* ... */ }
+//! ```
+//!
+//! Fields of [`PluginStatus`] which correspond to plugins that are not passed are `None`.
+use near_sdk::{AccountId, CryptoHash};
+use serde::{Deserialize, Serialize};
+
+/// Trait describing the functionality of the `ContractMetadata` plugin.
+pub trait ContractMetadata {
+    /// Returns the state of the plugins used by the contract, e.g.:
+    ///
+    /// ```json
+    /// {
+    ///   "ownable": { "owner": "alice.near" },
+    ///   "pausable": { "paused": ["ALL"] },
+    ///   "access_controllable": { "super_admin_count": 1 },
+    ///   "upgradable": null
+    /// }
+    /// ```
+    fn plugin_status(&self) -> PluginStatus;
+}
+
+/// The state of the plugins used by a contract as returned by
+/// [`ContractMetadata::plugin_status`]. A field is `None` if the contract does not use the
+/// corresponding plugin.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct PluginStatus {
+    pub ownable: Option<OwnableStatus>,
+    pub pausable: Option<PausableStatus>,
+    pub access_controllable: Option<AccessControllableStatus>,
+    pub upgradable: Option<UpgradableStatus>,
+}
+
+/// State of the `Ownable` plugin.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct OwnableStatus {
+    /// The current owner, as returned by [`crate::Ownable::owner_get`].
+    pub owner: Option<AccountId>,
+}
+
+/// State of the `Pausable` plugin.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct PausableStatus {
+    /// The paused features in lexicographic order, as returned by
+    /// [`crate::Pausable::pa_all_paused`].
+    pub paused: Vec<String>,
+}
+
+/// State of the `AccessControllable` plugin.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct AccessControllableStatus {
+    /// The number of super-admins, as returned by
+    /// [`crate::AccessControllable::acl_get_super_admins_count`].
+    pub super_admin_count: u64,
+}
+
+/// State of the `Upgradable` plugin.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct UpgradableStatus {
+    /// The hash of the staged code, as returned by [`crate::Upgradable::up_staged_code_hash`].
+    pub staged_code_hash: Option<CryptoHash>,
+}
@@ -0,0 +1,110 @@
+//! # Errors
+//!
+//! Some checks generated by `near-plugins-derive` panic with messages that start with a stable,
+//! machine-readable code, followed by a human-readable description:
+//!
+//! ```text
+//! ACL001: Insufficient permissions for method increase restricted by access control. ...
+//! ```
+//!
+//! The description may change between versions, while the code is stable. Clients which need to
+//! distinguish failures, e.g. front-ends, should therefore branch on the code, which is available
+//! via [`PluginError::code`].
+
+/// Errors which are raised with a stable code by the default implementations of plugins.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluginError {
+    /// The caller was not granted the roles required by `access_control_any` or
+    /// `access_control_all`.
+    InsufficientPermissions,
+    /// A string does not correspond to a role.
+    InvalidRole,
+    /// A value does not correspond to a permission.
+    InvalidPermission,
+    /// An account exceeded the limit checked by `acl_check_and_record_rate_limit`.
+    RateLimitExceeded,
+    /// A paginated getter of `AccessControllable` was called with a `limit` exceeding the maximum
+    /// page size.
+    PageSizeExceeded,
+    /// `AccessControllable::acl_grant_role_until` was called with an expiration that is not later
+    /// than the current block timestamp.
+    InvalidExpiration,
+    /// `AccessControllable::acl_revoke_super_admin` was called to revoke the last super-admin of
+    /// a contract which sets `prevent_last_super_admin_revocation`.
+    LastSuperAdmin,
+    /// `AccessControllable::acl_get_permissioned_accounts_chunk` was called with a cursor that
+    /// does not refer to a set of permissioned accounts.
+    InvalidCursor,
+    /// `AccessControllable::acl_get_permissioned_accounts_chunk` was called with `max_accounts`
+    /// equal to zero.
+    InvalidChunkSize,
+    /// A method guarded by `#[only]` was called by an account which satisfies none of the listed
+    /// conditions.
+    Unauthorized,
+    /// A method guarded by `#[pause]` was called while it is paused. The code is prefixed to custom
+    /// messages passed via `#[pause(message = "...")]` as well. It is also raised by
+    /// `PausableExt::pa_assert_not_paused`, hence by `#[unpaused]`.
+    Paused,
+    /// A method guarded by `#[if_paused]` was called while its feature is not paused. It is raised
+    /// by `PausableExt::pa_assert_paused`.
+    NotPaused,
+    /// `Upgradable::up_deploy_code` was called before the staging timestamp.
+    DeployTooEarly,
+    /// `Upgradable::up_apply_update_staging_duration` was called before the staging timestamp of
+    /// the new duration.
+    UpdateDurationTooEarly,
+    /// `Upgradable::up_deploy_code` was called in a pause state that is not permitted by
+    /// `deploy_requires_paused` or `deploy_forbidden_when_paused`.
+    DeployPauseState,
+    /// A method guarded by `#[only_during]` was called outside of its time window.
+    OutsideTimeWindow,
+    /// `Capped::capped_add` was called with an amount that would make the counter exceed the cap.
+    CapExceeded,
+    /// A method guarded by `#[frozen_guard]` was called by a frozen account.
+    Frozen,
+}
+
+impl PluginError {
+    /// Returns the code prefixed to the panic message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            PluginError::InsufficientPermissions => "ACL001",
+            PluginError::InvalidRole => "ACL002",
+            PluginError::InvalidPermission => "ACL003",
+            PluginError::RateLimitExceeded => "ACL004",
+            PluginError::PageSizeExceeded => "ACL005",
+            PluginError::InvalidExpiration => "ACL006",
+            PluginError::LastSuperAdmin => "ACL007",
+            PluginError::InvalidCursor => "ACL008",
+            PluginError::InvalidChunkSize => "ACL009",
+            PluginError::Unauthorized => "OWN001",
+            PluginError::Paused => "PAU001",
+            PluginError::NotPaused => "PAU002",
+            PluginError::DeployTooEarly => "UPG001",
+            PluginError::DeployPauseState => "UPG002",
+            PluginError::UpdateDurationTooEarly => "UPG003",
+            PluginError::OutsideTimeWindow => "WIN001",
+            PluginError::CapExceeded => "CAP001",
+            PluginError::Frozen => "FRZ001",
+        }
+    }
+
+    /// Returns whether `message`, e.g. the error of a failed transaction, contains the code of
+    /// this error.
+    pub fn is_contained_in(&self, message: &str) -> bool {
+        message.contains(&format!("{}: ", self.code()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_contained_in() {
+        let message = "Smart contract panicked: PAU001: Pausable: Method is paused";
+        assert!(PluginError::Paused.is_contained_in(message));
+        assert!(!PluginError::DeployTooEarly.is_contained_in(message));
+        assert!(!PluginError::NotPaused.is_contained_in(message));
+    }
+}
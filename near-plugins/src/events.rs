@@ -1,6 +1,31 @@
 //! # NEAR Events
 //!
 //! Description of Events on NEAR following [NEP-297](https://nomicon.io/Standards/EventsFormat)
+//!
+//! By default events are logged in the format of NEP-297, i.e. as JSON prefixed with
+//! `EVENT_JSON:`. Enabling the crate feature `plain-events` logs the bare JSON object instead,
+//! for indexers which do not parse NEP-297 logs:
+//!
+//! ```text
+//! // default
+//! EVENT_JSON:{"standard":"Pausable","version":"1.0.0","event":"pause","data":{...}}
+//! // with feature `plain-events`
+//! {"standard":"Pausable","version":"1.0.0","event":"pause","data":{...}}
+//! ```
+//!
+//! The `standard` and `version` of events emitted by plugins may be customized via the
+//! `event_standard` and `event_version` attributes of the plugin's macro, for instance:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role), event_standard = "my_acl", event_version = "2.0.0")]
+//! ```
+//!
+//! Attributes which are omitted default to the values defined by the plugin.
+//!
+//! For size sensitive contracts, the crate feature `minimal-events` formats the envelope of events
+//! by hand and uses `serde` only to serialize their `data`. This avoids instantiating `serde` code
+//! for the envelope of every event type, reducing the size of the wasm binary. The logs are the
+//! same as without the feature. It may be combined with `plain-events`.
 use serde::Serialize;
 
 /// Interface to capture metadata about an event
@@ -25,18 +50,109 @@ pub trait AsEvent<T: Serialize> {
 
     /// Returns the string representation of the event.
     fn event(&self) -> String {
-        format!(
-            "EVENT_JSON:{}",
-            near_sdk::serde_json::to_string(&self.metadata()).unwrap()
-        )
+        format_event(&self.metadata())
+    }
+
+    /// Like [`AsEvent::event`], but `standard` and `version` replace the corresponding fields of
+    /// the metadata if they are `Some`.
+    fn event_with(&self, standard: Option<&str>, version: Option<&str>) -> String {
+        let mut metadata = self.metadata();
+        if let Some(standard) = standard {
+            metadata.standard = standard.to_string();
+        }
+        if let Some(version) = version {
+            metadata.version = version.to_string();
+        }
+        format_event(&metadata)
     }
 
     /// Emits the event on chain.
     fn emit(&self) {
-        near_sdk::log!(self.event());
+        near_sdk::env::log_str(&self.event());
+    }
+
+    /// Emits the event on chain with the envelope returned by [`AsEvent::event_with`].
+    fn emit_with(&self, standard: Option<&str>, version: Option<&str>) {
+        near_sdk::env::log_str(&self.event_with(standard, version));
     }
 }
 
+/// Returns the string representation of an event with `metadata`. With feature `plain-events`, the
+/// `EVENT_JSON:` prefix is omitted.
+fn format_event<T: Serialize>(metadata: &EventMetadata<T>) -> String {
+    let json = event_json(metadata);
+    if cfg!(feature = "plain-events") {
+        json
+    } else {
+        format!("EVENT_JSON:{}", json)
+    }
+}
+
+/// Serializes `metadata` to JSON.
+#[cfg(not(feature = "minimal-events"))]
+fn event_json<T: Serialize>(metadata: &EventMetadata<T>) -> String {
+    near_sdk::serde_json::to_string(metadata).unwrap()
+}
+
+/// Formats the envelope of `metadata` as JSON by hand. Only `data` is serialized via `serde`, the
+/// envelope is formatted by [`envelope_json`] which is instantiated once for all event types.
+#[cfg(feature = "minimal-events")]
+fn event_json<T: Serialize>(metadata: &EventMetadata<T>) -> String {
+    let data = metadata
+        .data
+        .as_ref()
+        .map(|data| near_sdk::serde_json::to_string(data).unwrap());
+    envelope_json(
+        &metadata.standard,
+        &metadata.version,
+        &metadata.event,
+        data.as_deref(),
+    )
+}
+
+/// Returns the JSON object of an event, where `data` is already serialized.
+#[cfg(feature = "minimal-events")]
+fn envelope_json(standard: &str, version: &str, event: &str, data: Option<&str>) -> String {
+    let mut out = String::with_capacity(64 + data.map_or(0, str::len));
+    out.push_str(r#"{"standard":"#);
+    push_json_string(&mut out, standard);
+    out.push_str(r#","version":"#);
+    push_json_string(&mut out, version);
+    out.push_str(r#","event":"#);
+    push_json_string(&mut out, event);
+    if let Some(data) = data {
+        out.push_str(r#","data":"#);
+        out.push_str(data);
+    }
+    out.push('}');
+    out
+}
+
+/// Appends `value` as a JSON string literal, including the surrounding quotes. Avoids
+/// `core::fmt` to keep the wasm binary small.
+#[cfg(feature = "minimal-events")]
+fn push_json_string(out: &mut String, value: &str) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                out.push_str("\\u00");
+                out.push(HEX[(c as usize) >> 4] as char);
+                out.push(HEX[(c as usize) & 0xf] as char);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
 #[cfg(test)]
 mod tests {
     use crate::events::{AsEvent, EventMetadata};
@@ -57,7 +173,8 @@ mod tests {
     }
 
     /// Helper function to check if an event is well formed and follows NEP-297
-    /// i.e. tries to deserialize the json object.
+    /// i.e. tries to deserialize the json object. With feature `plain-events`,
+    /// the prefix must be absent.
     fn valid_event(event: String) -> bool {
         #[derive(serde::Deserialize)]
         struct EventFormat {
@@ -72,14 +189,19 @@ mod tests {
         }
 
         let prefix = "EVENT_JSON:";
-        if !event.starts_with(prefix) {
-            return false;
-        }
-        let r = &event[prefix.len()..];
+        let r = if cfg!(feature = "plain-events") {
+            event.as_str()
+        } else {
+            match event.strip_prefix(prefix) {
+                Some(r) => r,
+                None => return false,
+            }
+        };
         near_sdk::serde_json::from_str::<EventFormat>(r).is_ok()
     }
 
     #[test]
+    #[cfg(not(feature = "plain-events"))]
     fn event_no_data() {
         let compile_event = CompileEvent { info: None };
         let event_log = compile_event.event();
@@ -90,6 +212,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(not(feature = "plain-events"))]
     fn event_with_data() {
         let compile_event = CompileEvent {
             info: Some("Compilation successful".to_string()),
@@ -99,4 +222,53 @@ mod tests {
         assert_eq!(event_log, expected);
         assert!(valid_event(event_log));
     }
+
+    #[test]
+    #[cfg(not(feature = "plain-events"))]
+    fn event_with_custom_envelope() {
+        let compile_event = CompileEvent { info: None };
+        let event_log = compile_event.event_with(Some("Build"), Some("2.0.0"));
+        let expected =
+            r#"EVENT_JSON:{"standard":"Build","version":"2.0.0","event":"compile_test"}"#;
+        assert_eq!(event_log, expected);
+        assert!(valid_event(event_log));
+
+        // Omitted fields keep the values of the metadata.
+        let event_log = compile_event.event_with(None, Some("2.0.0"));
+        let expected =
+            r#"EVENT_JSON:{"standard":"Compile","version":"2.0.0","event":"compile_test"}"#;
+        assert_eq!(event_log, expected);
+        assert_eq!(compile_event.event_with(None, None), compile_event.event());
+    }
+
+    #[test]
+    #[cfg(feature = "plain-events")]
+    fn plain_event_no_data() {
+        let compile_event = CompileEvent { info: None };
+        let event_log = compile_event.event();
+        let expected = r#"{"standard":"Compile","version":"0.0.1","event":"compile_test"}"#;
+        assert_eq!(event_log, expected);
+        assert!(valid_event(event_log));
+    }
+
+    #[test]
+    #[cfg(feature = "plain-events")]
+    fn plain_event_with_data() {
+        let compile_event = CompileEvent {
+            info: Some("Compilation successful".to_string()),
+        };
+        let event_log = compile_event.event();
+        let expected = r#"{"standard":"Compile","version":"0.0.1","event":"compile_test","data":"Compilation successful"}"#;
+        assert_eq!(event_log, expected);
+        assert!(valid_event(event_log));
+    }
+
+    #[test]
+    #[cfg(feature = "minimal-events")]
+    fn minimal_event_escapes_strings() {
+        let compile_event = CompileEvent { info: None };
+        let event_log = compile_event.event_with(Some("a\"b\\c\n\u{1}"), None);
+        assert!(event_log.contains(r#""standard":"a\"b\\c\n\u0001""#));
+        assert!(valid_event(event_log));
+    }
 }
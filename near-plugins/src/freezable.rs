@@ -0,0 +1,138 @@
+//! # Freezable:
+//!
+//! Trait which allows contracts to freeze individual accounts. While [`Pausable`] stops a feature
+//! for every caller, freezing an account prevents only that account from calling guarded methods.
+//! This is useful e.g. for token-like contracts which need to block a compromised account.
+//!
+//! ## Default implementation:
+//!
+//! Methods annotated with `#[frozen_guard]` panic if the predecessor account is frozen, or the
+//! account returned by the `caller_resolver` of `AccessControllable` if one is configured. The
+//! message is prefixed with the code of [`PluginError::Frozen`](crate::PluginError::Frozen). Each
+//! frozen account is stored in its own storage slot, with a key composed of the storage prefix and
+//! the account id. Hence the cost of checking whether an account is frozen does not depend on the
+//! number of frozen accounts.
+//!
+//! As a precondition for being `Freezable` a contract must be `AccessControllable`. Access control
+//! is used to define the permissions required to freeze and unfreeze accounts, via the
+//! `manager_roles` passed to the derive macro:
+//!
+//! ```ignore
+//! #[access_control(role_type(Role))]
+//! #[near(contract_state)]
+//! #[derive(Freezable)]
+//! #[freezable(manager_roles(Role::FreezeManager))]
+//! struct Contract { /* ... */ }
+//!
+//! #[near]
+//! impl Contract {
+//!     #[frozen_guard]
+//!     pub fn transfer(&mut self, receiver_id: AccountId, amount: U128) { /* ... */ }
+//! }
+//! ```
+//!
+//! [`Pausable`]: crate::Pausable
+use crate::events::{AsEvent, EventMetadata};
+use near_sdk::AccountId;
+use serde::Serialize;
+
+/// Trait describing the functionality of the `Freezable` plugin.
+pub trait Freezable {
+    /// Returns the prefix of storage keys under which frozen accounts are stored. By default
+    /// `b"__FREEZE__"` is used.
+    ///
+    /// Attribute `storage_prefix` can be used to set a different prefix:
+    ///
+    /// ```ignore
+    /// #[freezable(manager_roles(Role::FreezeManager), storage_prefix="CUSTOM_KEY")]
+    /// struct Contract { /* ... */}
+    /// ```
+    fn fr_storage_prefix(&self) -> &'static [u8];
+
+    /// Returns whether `account_id` is frozen.
+    fn fr_is_frozen(&self, account_id: AccountId) -> bool;
+
+    /// Freezes `account_id`, which makes methods annotated with `#[frozen_guard]` panic if called by
+    /// it. This method fails if the caller has not been granted one of the access control
+    /// `manager_roles` passed to the `Freezable` plugin.
+    ///
+    /// It returns `true` if the account is frozen as a result of this function call and `false`
+    /// if the account was already frozen.
+    ///
+    /// If the account is newly frozen, the following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///   "standard":"Freezable",
+    ///   "version":"1.0.0",
+    ///   "event":"freeze",
+    ///   "data":
+    ///     {
+    ///       "by":"<MANAGER_ACCOUNT>",
+    ///       "account":"<FROZEN_ACCOUNT>"
+    ///     }
+    /// }
+    /// ```
+    fn fr_freeze_account(&mut self, account_id: AccountId) -> bool;
+
+    /// Unfreezes `account_id`. This method fails if the caller has not been granted one of the
+    /// access control `manager_roles` passed to the `Freezable` plugin.
+    ///
+    /// It returns whether the account was frozen.
+    ///
+    /// If the account was frozen, the following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///   "standard":"Freezable",
+    ///   "version":"1.0.0",
+    ///   "event":"unfreeze",
+    ///   "data":
+    ///     {
+    ///       "by":"<MANAGER_ACCOUNT>",
+    ///       "account":"<UNFROZEN_ACCOUNT>"
+    ///     }
+    /// }
+    /// ```
+    fn fr_unfreeze_account(&mut self, account_id: AccountId) -> bool;
+}
+
+/// Event emitted when an account is frozen.
+#[derive(Serialize, Clone)]
+pub struct AccountFrozen {
+    /// Account Id that froze the account.
+    pub by: AccountId,
+    /// The account that was frozen.
+    pub account: AccountId,
+}
+
+impl AsEvent<AccountFrozen> for AccountFrozen {
+    fn metadata(&self) -> EventMetadata<AccountFrozen> {
+        EventMetadata {
+            standard: "Freezable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "freeze".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when an account is unfrozen.
+#[derive(Serialize, Clone)]
+pub struct AccountUnfrozen {
+    /// Account Id that unfroze the account.
+    pub by: AccountId,
+    /// The account that was unfrozen.
+    pub account: AccountId,
+}
+
+impl AsEvent<AccountUnfrozen> for AccountUnfrozen {
+    fn metadata(&self) -> EventMetadata<AccountUnfrozen> {
+        EventMetadata {
+            standard: "Freezable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "unfreeze".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
@@ -1,14 +1,23 @@
 pub mod access_control_role;
 pub mod access_controllable;
+pub mod capped;
+pub mod contract_metadata;
+pub mod errors;
 pub mod events;
+pub mod freezable;
 pub mod ownable;
 pub mod pausable;
 pub mod upgradable;
 
 pub use access_control_role::AccessControlRole;
 pub use access_controllable::AccessControllable;
+pub use capped::Capped;
+pub use contract_metadata::ContractMetadata;
+pub use errors::PluginError;
+pub use freezable::Freezable;
 pub use near_plugins_derive::{
-    access_control, access_control_any, if_paused, only, pause, AccessControlRole, Ownable,
+    access_control, access_control_all, access_control_any, frozen_guard, if_paused, only,
+    only_during, pause, unpaused, AccessControlRole, Capped, ContractMetadata, Freezable, Ownable,
     Pausable, Upgradable,
 };
 pub use ownable::Ownable;
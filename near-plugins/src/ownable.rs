@@ -7,6 +7,78 @@
 //! During creation of the contract set the owner using `owner_set`. Protect functions that should
 //! only be called by the owner using #[only(owner)].
 //!
+//! Ownership can be transferred in a single step using `owner_set` or in two steps using
+//! `owner_propose` followed by `owner_accept`, which requires the new owner to accept ownership.
+//!
+//! ## Combining conditions with `only`
+//!
+//! `#[only(...)]` accepts any combination of `self`, `owner` and `any_of_roles(...)`. The method
+//! may be called if _any_ of the provided conditions holds, there is no precedence among them:
+//!
+//! ```ignore
+//! #[only(owner, self, any_of_roles(Role::Operator, Role::Admin))]
+//! pub fn rebalance(&mut self) { /* ... */ }
+//! ```
+//!
+//! | Caller                                   | `owner` | `self` | `any_of_roles(...)` | combined |
+//! |------------------------------------------|---------|--------|---------------------|----------|
+//! | owner                                    | ✓       | ✗      | ✗                   | ✓        |
+//! | contract itself                          | ✗       | ✓      | ✗                   | ✓        |
+//! | grantee of a listed role                 | ✗       | ✗      | ✓                   | ✓        |
+//! | any other account                        | ✗       | ✗      | ✗                   | ✗        |
+//!
+//! An account that satisfies several conditions, e.g. an owner who is also a grantee, passes as
+//! well. Passing `any_of_roles` requires the contract to be `AccessControllable`, otherwise
+//! compilation fails with an unsatisfied trait bound. With `any_of_roles`, all conditions are
+//! checked by a single guard which panics with a message listing them.
+//!
+//! With `any_of_roles`, all conditions are checked for the account returned by
+//! `acl_effective_caller`, which is the predecessor unless `AccessControllable` is configured with
+//! a `caller_resolver`. Then `owner` and `self` hold only if the resolved account is the owner or
+//! the contract itself. For instance, a call relayed by the contract on behalf of a user is
+//! checked for the user and doesn't pass as `self`. Without `any_of_roles`, the contract need not
+//! be `AccessControllable` and `owner` and `self` are checked for the predecessor.
+//!
+//! The guarded method may take `&mut self`, `&self` or no receiver at all. Since roles are checked
+//! via `AccessControllable` methods, `any_of_roles` requires a receiver and fails to compile
+//! otherwise:
+//!
+//! ```ignore
+//! #[only(owner)]
+//! pub fn ping() -> bool { true }
+//! ```
+//!
+//! ## Co-owners
+//!
+//! A contract may be owned by several co-owners, any of which may act as owner, by opting in to
+//! `multi`:
+//!
+//! ```ignore
+//! #[derive(Ownable)]
+//! #[ownable(multi)]
+//! struct Contract { /* ... */}
+//! ```
+//!
+//! Then `#[only(owner)]` passes if the predecessor is any of the co-owners, which are managed via
+//! [`Ownable::owner_add`] and [`Ownable::owner_remove`] and returned by [`Ownable::owner_get_all`].
+//! Methods which set a single owner, e.g. [`Ownable::owner_set`] and [`Ownable::owner_accept`],
+//! replace all co-owners and [`Ownable::owner_renounce`] removes all of them.
+//! [`Ownable::owner_get`] returns the co-owner that was added first.
+//!
+//! Combined with the `owner_is_super_admin` option of `AccessControllable`, every co-owner is
+//! treated as super-admin.
+//!
+//! ## Storage layout
+//!
+//! The default implementation stores its state in the following slots, which may be read via raw
+//! storage queries, e.g. by light clients:
+//!
+//! - Key [`Ownable::owner_storage_key`]: the account id of the owner as UTF-8 bytes. With `multi`,
+//!   the borsh-serialized `Vec<AccountId>` of co-owners. The slot is absent if no owner is set.
+//! - Key `<owner_storage_key>_PENDING`: the borsh-serialized tuple `(AccountId, u64)` of the
+//!   proposed owner and the nonce of a pending proposal.
+//! - Key `<owner_storage_key>_NONCE`: the borsh-serialized `u64` nonce of the latest proposal.
+//!
 //! ## Credits:
 //!
 //! Inspired by Open Zeppelin Ownable module:
@@ -27,10 +99,60 @@ pub trait Ownable {
     /// ```
     fn owner_storage_key(&self) -> &'static [u8];
 
+    /// Returns the key returned by [`Self::owner_storage_key`] as a string, which is more
+    /// convenient for tooling than a vector of bytes. Keys passed via the `owner_storage_key`
+    /// attribute are strings, hence they are always valid UTF-8.
+    fn owner_storage_key_str(&self) -> String;
+
     /// Returns the current owner of the contract. Result must be a NEAR valid account id or None,
-    /// in case the account doesn't have an owner.
+    /// in case the account doesn't have an owner. With `#[ownable(multi)]`, the co-owner that was
+    /// added first is returned.
     fn owner_get(&self) -> Option<AccountId>;
 
+    /// Returns all owners of the contract. Without `#[ownable(multi)]`, it contains at most one
+    /// account.
+    fn owner_get_all(&self) -> Vec<AccountId>;
+
+    /// Adds `account_id` as co-owner and returns whether it is a new co-owner.
+    ///
+    /// # Default Implementation:
+    ///
+    /// Only co-owners can call this method. If no owner is set, only self can call it. If a
+    /// `validate_owner` function is specified, it panics if the function rejects `account_id`. It
+    /// panics unless `#[ownable(multi)]` is set.
+    ///
+    /// # Event
+    ///
+    /// If a co-owner is added, the following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///    "standard": "Ownable",
+    ///    "version": "1.0.0",
+    ///    "event": "owner_added",
+    ///    "data": {
+    ///       "account": "<NEW_CO_OWNER_ACCOUNT>",
+    ///       "by": "<CALLER_ACCOUNT>"
+    ///    }
+    /// }
+    /// ```
+    fn owner_add(&mut self, account_id: AccountId) -> bool;
+
+    /// Removes `account_id` from the co-owners and returns whether it was a co-owner.
+    ///
+    /// # Default Implementation:
+    ///
+    /// Only co-owners can call this method, so a co-owner may remove themselves. Removing the last
+    /// co-owner panics if renouncing is forbidden via `#[ownable(allow_renounce = false)]`. A
+    /// pending ownership proposal is removed, since it might have been made by the removed
+    /// co-owner. It panics unless `#[ownable(multi)]` is set.
+    ///
+    /// # Event
+    ///
+    /// If a co-owner is removed, an `owner_removed` event with the same data as the event described
+    /// in [`Self::owner_add`] is emitted.
+    fn owner_remove(&mut self, account_id: AccountId) -> bool;
+
     /// Replaces the current owner of the contract by a new owner. Use `None` to remove the owner of
     /// the contract.
     ///
@@ -39,6 +161,37 @@ pub trait Ownable {
     /// Only the current owner can call this method. If no owner is set, only self can call this
     /// method. Notice that if the owner is set, self will not be able to call `owner_set` by default.
     ///
+    /// Removing the owner by passing `None` can be forbidden with attribute `allow_renounce`. Then
+    /// this method panics if `owner` is `None`:
+    ///
+    /// ```ignore
+    /// #[ownable(allow_renounce = false)]
+    /// struct Contract { /* ... */}
+    /// ```
+    ///
+    /// A policy for new owners can be enforced with attribute `validate_owner`, which names a
+    /// function `fn(&Self, &Option<AccountId>) -> bool`. This method panics if the function returns
+    /// `false` for `owner`. It is consulted by [`Self::owner_propose`] as well.
+    ///
+    /// ```ignore
+    /// #[ownable(validate_owner = "is_named_account")]
+    /// struct Contract { /* ... */}
+    /// ```
+    ///
+    /// # Cross-contract calls
+    ///
+    /// The checks above are evaluated when this method is executed, which may happen blocks
+    /// after a transaction scheduled it, e.g. in a callback. In particular, if no owner is set or
+    /// the contract is its own owner, a callback of the contract calling `owner_set` succeeds even
+    /// if the state it was scheduled for has changed in between. Attribute
+    /// `no_self_reentrant_set` rules this out by making this method panic if the contract itself
+    /// is the predecessor and promise results are available, i.e. in callbacks:
+    ///
+    /// ```ignore
+    /// #[ownable(no_self_reentrant_set)]
+    /// struct Contract { /* ... */}
+    /// ```
+    ///
     /// # Event
     ///
     /// If ownership is successfully transferred, the following event will be emitted:
@@ -56,6 +209,74 @@ pub trait Ownable {
     /// ```
     fn owner_set(&mut self, owner: Option<AccountId>);
 
+    /// Removes the owner of the contract. In contrast to `owner_set(None)`, this method states the
+    /// intent explicitly and emits a dedicated event.
+    ///
+    /// # Default Implementation:
+    ///
+    /// It panics if no owner is set. Only the current owner or self can call this method. It panics
+    /// as well if renouncing is forbidden via `#[ownable(allow_renounce = false)]`.
+    ///
+    /// # Event
+    ///
+    /// If ownership is successfully renounced, the following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///    "standard": "Ownable",
+    ///    "version": "1.0.0",
+    ///    "event": "ownership_renounced",
+    ///    "data": {
+    ///       "previous_owner": "<PREV_OWNER_ACCOUNT>",
+    ///       "by": "<CALLER_ACCOUNT>"
+    ///    }
+    /// }
+    /// ```
+    fn owner_renounce(&mut self);
+
+    /// Proposes `new_owner` as the next owner of the contract. Ownership is transferred only once
+    /// `new_owner` calls [`Self::owner_accept`] with the returned nonce.
+    ///
+    /// # Default Implementation:
+    ///
+    /// Only the current owner can call this method. Every proposal is assigned a new nonce, which
+    /// replaces any previous proposal. Hence a stale proposal can't be accepted after it was
+    /// cancelled or replaced by another proposal. If a `validate_owner` function is specified, it
+    /// panics if the function rejects `new_owner`.
+    ///
+    /// # Event
+    ///
+    /// The following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///    "standard": "Ownable",
+    ///    "version": "1.0.0",
+    ///    "event": "ownership_proposed",
+    ///    "data": {
+    ///       "owner": "<OWNER_ACCOUNT>",
+    ///       "proposed_owner": "<PROPOSED_OWNER_ACCOUNT>",
+    ///       "nonce": <NONCE>
+    ///    }
+    /// }
+    /// ```
+    fn owner_propose(&mut self, new_owner: AccountId) -> u64;
+
+    /// Accepts the pending ownership proposal identified by `nonce`.
+    ///
+    /// # Default Implementation:
+    ///
+    /// It panics if there is no pending proposal, if the caller is not the proposed owner or if
+    /// `nonce` doesn't match the nonce of the pending proposal. On success, the proposal is
+    /// removed and the event described in [`Self::owner_set`] is emitted.
+    fn owner_accept(&mut self, nonce: u64);
+
+    /// Removes the pending ownership proposal, if any. Only the current owner can call this method.
+    fn owner_cancel_proposal(&mut self);
+
+    /// Returns the account proposed as next owner, if there is a pending proposal.
+    fn owner_get_pending(&self) -> Option<AccountId>;
+
     /// Returns true if the predecessor account id is the owner of the contract.
     ///
     /// # View calls
@@ -65,6 +286,39 @@ pub trait Ownable {
     ///
     /// [not allowed]: https://nomicon.io/Proposals/view-change-method
     fn owner_is(&self) -> bool;
+
+    /// Returns true if `account_id` is the owner of the contract and false if it isn't or if no
+    /// owner is set.
+    ///
+    /// In contrast to [`Self::owner_is`], this method is available in view calls.
+    fn owner_is_account(&self, account_id: AccountId) -> bool;
+
+    /// Panics if the predecessor is not the owner of the contract. It allows guarding a section of
+    /// a method body in the same way `#[only(owner)]` guards the whole method:
+    ///
+    /// ```ignore
+    /// pub fn increase_by(&mut self, value: u64) {
+    ///     if value > 1 {
+    ///         self.assert_owner();
+    ///     }
+    ///     self.counter += value;
+    /// }
+    /// ```
+    ///
+    /// This method is not exposed as a contract method.
+    fn assert_owner(&self) {
+        near_sdk::require!(self.owner_is(), "Ownable: Method must be called from owner");
+    }
+
+    /// Panics if the predecessor is neither the owner nor the contract itself. It is the inline
+    /// counterpart of `#[only(self, owner)]`.
+    ///
+    /// This method is not exposed as a contract method.
+    fn assert_owner_or_self(&self) {
+        if !self.owner_is() {
+            near_sdk::assert_self();
+        }
+    }
 }
 
 /// Event emitted when ownership is changed.
@@ -86,3 +340,85 @@ impl AsEvent<OwnershipTransferred> for OwnershipTransferred {
         }
     }
 }
+
+/// Event emitted when a new owner is proposed.
+#[derive(Serialize, Clone)]
+pub struct OwnershipProposed {
+    /// The current owner, who made the proposal.
+    pub owner: AccountId,
+    /// The account proposed as next owner.
+    pub proposed_owner: AccountId,
+    /// The nonce which must be passed to `owner_accept`.
+    pub nonce: u64,
+}
+
+impl AsEvent<OwnershipProposed> for OwnershipProposed {
+    fn metadata(&self) -> EventMetadata<OwnershipProposed> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "ownership_proposed".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when a co-owner is added.
+#[derive(Serialize, Clone)]
+pub struct OwnerAdded {
+    /// The new co-owner.
+    pub account: AccountId,
+    /// The co-owner or contract that added `account`.
+    pub by: AccountId,
+}
+
+impl AsEvent<OwnerAdded> for OwnerAdded {
+    fn metadata(&self) -> EventMetadata<OwnerAdded> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "owner_added".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when a co-owner is removed.
+#[derive(Serialize, Clone)]
+pub struct OwnerRemoved {
+    /// The removed co-owner.
+    pub account: AccountId,
+    /// The co-owner that removed `account`.
+    pub by: AccountId,
+}
+
+impl AsEvent<OwnerRemoved> for OwnerRemoved {
+    fn metadata(&self) -> EventMetadata<OwnerRemoved> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "owner_removed".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when ownership is renounced.
+#[derive(Serialize, Clone)]
+pub struct OwnershipRenounced {
+    /// The owner before ownership was renounced.
+    pub previous_owner: AccountId,
+    /// The account that renounced ownership, either the previous owner or the contract itself.
+    pub by: AccountId,
+}
+
+impl AsEvent<OwnershipRenounced> for OwnershipRenounced {
+    fn metadata(&self) -> EventMetadata<OwnershipRenounced> {
+        EventMetadata {
+            standard: "Ownable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "ownership_renounced".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
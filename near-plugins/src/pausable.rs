@@ -15,36 +15,259 @@
 //! by storing all paused keys in a single slot on the storage. Notice that unpausing "ALL" will not
 //! necessarily unpause all features, if other features are still present in the paused_list.
 //!
+//! The pause guard of a method may depend on contract state via `#[pause(when = "path::to::fn")]`,
+//! where the referenced function has signature `fn(&Self) -> bool`. Such a method is paused only if
+//! its feature is paused _and_ the function returns `true`. Compilation fails if the function is
+//! missing or its signature differs.
+//!
+//! Contracts which should launch in a paused state can declare features that are paused until
+//! they are unpaused for the first time, without pausing them in the constructor:
+//!
+//! ```ignore
+//! #[pausable(manager_roles(Role::PauseManager), default_paused("increase_1", "Increase by two"))]
+//! struct Contract { /* ... */}
+//! ```
+//!
+//! Alternatively, `default_paused_all` makes "ALL" paused by default. Default paused features are
+//! applied only as long as no pause state is stored. Once a feature is paused or unpaused, the
+//! stored state is authoritative.
+//!
+//! By default a paused method panics with "PAU001: Pausable: Method is paused". A custom message,
+//! e.g. to let front-ends distinguish pause failures from other errors, can be set via
+//! `#[pause(name = "deposit", message = "Deposits are temporarily halted")]`. Custom messages are
+//! prefixed with the code of [`PluginError::Paused`](crate::PluginError::Paused) as well.
+//!
+//! Several methods can be paused at once by assigning them to a group, e.g. via
+//! `#[pause(group = "trading")]`. Such a method is paused if either its own key or the group is
+//! paused, so pausing "trading" halts all methods of the group while each of them can still be
+//! paused individually. If `features(...)` is passed, it must contain groups like any other key,
+//! see `Pausable::pa_all_feature_names`.
+//!
+//! Methods may also be guarded explicitly by the feature they depend on via
+//! `#[unpaused(name = "feature")]`, which panics if that feature (or "ALL") is paused. It is the
+//! counterpart of `#[if_paused(name = "feature")]` and allows a method to depend on a feature
+//! whose name differs from the method's name.
+//!
+//! To avoid typos in feature keys, `feature_enum` generates an enum with a variant per key passed
+//! to `features(...)` and one for "ALL". Variants are named by converting keys to upper camel case,
+//! e.g. "Increase by two" becomes `IncreaseByTwo`. The contract additionally gets the inherent,
+//! non-exported methods `pa_pause`, `pa_unpause` and `pa_is_feature_paused`, which take a variant
+//! and call the corresponding string-based methods with its key. Hence storage is shared by both
+//! APIs.
+//!
+//! ```ignore
+//! #[pausable(manager_roles(Role::PauseManager), features("Increase by two"), feature_enum = "Feature")]
+//! struct Contract { /* ... */}
+//!
+//! self.pa_pause(Feature::IncreaseByTwo, None);
+//! ```
+//!
+//! Custom pause logic within method bodies can use the helpers of [`PausableExt`], which is
+//! implemented for every `Pausable` contract:
+//!
+//! ```ignore
+//! use near_plugins::pausable::PausableExt;
+//!
+//! pub fn careful_increase(&mut self) {
+//!     if self.counter >= 3 {
+//!         self.pa_assert_not_paused("increase_big");
+//!     }
+//!     self.counter += 1;
+//! }
+//! ```
+//!
+//! ## Pausing view methods
+//!
+//! The guard generated by `#[pause]` only reads state, hence it can be applied to `&self` methods
+//! as well, e.g. to gate reads during maintenance windows. Calling such a view while its feature
+//! is paused fails like any other paused method. However, `except` must not be used on methods
+//! that are called as views: checking exemptions requires the predecessor account, which is not
+//! available in view calls, so these calls would fail even if the feature is not paused.
+//!
+//! ## Pause state across promises
+//!
+//! A feature may be paused or unpaused after a method schedules a cross-contract call and before
+//! the corresponding callback is executed. If the callback must behave consistently with the
+//! method that scheduled it, capture the pause state on entry with `Pausable::pa_snapshot` and
+//! pass the resulting [`PauseSnapshot`] as argument to the callback:
+//!
+//! ```ignore
+//! #[pause(name = "transfer")]
+//! pub fn transfer(&mut self, receiver_id: AccountId) -> Promise {
+//!     let snapshot = self.pa_snapshot("transfer".to_string());
+//!     ext_receiver::ext(receiver_id)
+//!         .on_transfer()
+//!         .then(Self::ext(env::current_account_id()).transfer_callback(snapshot))
+//! }
+//!
+//! #[private]
+//! pub fn transfer_callback(&mut self, snapshot: PauseSnapshot) {
+//!     if snapshot.was_paused_at_entry() {
+//!         // ...
+//!     }
+//! }
+//! ```
+//!
+//! Threading the snapshot through arguments requires no storage and each callback receives the
+//! state captured by the call that scheduled it, even if calls are interleaved.
+//!
 //! As a precondition for being `Pausable` a contract must be `AccessControllable`. Access control
 //! is used to define the permissions required to pause and unpause features. In addition, grantees
 //! of access control roles may be allowed to call methods that are `#[pause]` or `#[if_paused]`
 //! unrestrictedly via the `except` argument.
 //!
+//! Besides `roles`, `except` accepts `owner` and `self`, which exempt the owner of an `Ownable`
+//! contract and the contract itself. Exceptions may be combined, and the method may be called
+//! while paused if _any_ of them holds:
+//!
+//! ```ignore
+//! #[pause(except(roles(Role::Operator), owner, self))]
+//! pub fn rebalance(&mut self) { /* ... */ }
+//! ```
+//!
+//! Passing `owner` to a contract which is not `Ownable` fails compilation with an unsatisfied trait
+//! bound, as does passing `roles` to a contract which is not `AccessControllable`.
+//!
+//! ## Missing `#[derive(Pausable)]`
+//!
+//! `#[pause]`, `#[if_paused]` and `#[unpaused]` require the contract to derive `Pausable`. If the
+//! derive is forgotten, as in
+//!
+//! ```ignore
+//! #[access_control(role_type(Role))]
+//! #[near(contract_state)]
+//! pub struct Counter {
+//!     counter: u64,
+//! }
+//!
+//! #[near]
+//! impl Counter {
+//!     #[pause]
+//!     pub fn increase(&mut self) {
+//!         self.counter += 1;
+//!     }
+//! }
+//! ```
+//!
+//! compilation fails with an error pointing to the method and naming the missing derive:
+//!
+//! ```text
+//! error[E0277]: the trait bound `Counter: Pausable` is not satisfied
+//!   --> src/lib.rs
+//!    |
+//!    |     pub fn increase(&mut self) {
+//!    |            ^^^^^^^^ the trait `Pausable` is not implemented for `Counter`
+//!    |
+//! note: required by a bound in `requires_derive_pausable`
+//! ```
+//!
+//! Adding `#[derive(Pausable)]` to the contract struct fixes the error.
+//!
 //! ## Credits:
 //!
 //! Inspired by Open Zeppelin Pausable module:
 //! https://github.com/OpenZeppelin/openzeppelin-contracts/blob/master/contracts/security/Pausable.sol
 use crate::events::{AsEvent, EventMetadata};
 use near_sdk::AccountId;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
 /// Trait describing the functionality of the `Pausable` plugin.
 pub trait Pausable {
     /// Returns the key of the storage slot which contains the list of features that are paused. By
-    /// default `b"__PAUSED__"` is used.
+    /// default `b"__PAUSE__"` is used.
     ///
-    /// Attribute `paused_storage_key` can be used to set a different key:
+    /// Attribute `storage_prefix` can be used to set a different key:
     ///
     /// ```ignore
-    /// #[pausable(paused_storage_key="CUSTOM_KEY")]
+    /// #[pausable(storage_prefix="CUSTOM_KEY")]
     /// struct Contract { /* ... */}
     /// ```
+    ///
+    /// For backwards compatibility, the attribute `paused_storage_key` is supported as well. At
+    /// most one of `storage_prefix` and `paused_storage_key` may be specified.
     fn pa_storage_key(&self) -> &'static [u8];
 
     /// Returns whether feature `key` is paused.
     fn pa_is_paused(&self, key: String) -> bool;
 
+    /// Returns whether any of the features `keys` is paused, e.g. to disable an action in a
+    /// front-end which depends on multiple features. If "ALL" is paused, this is `true` for any
+    /// non-empty `keys`. For empty `keys`, it returns `false`.
+    ///
+    /// Paused features are read once, so it is cheaper than calling [`Self::pa_is_paused`] for
+    /// every key.
+    fn pa_is_paused_any(&self, keys: Vec<String>) -> bool;
+
+    /// Returns whether all of the features `keys` are paused. If "ALL" is paused, this is `true`
+    /// for any `keys`. For empty `keys`, it returns `true`.
+    fn pa_is_paused_all(&self, keys: Vec<String>) -> bool;
+
+    /// Returns the names of all features the contract declares as pausable, for instance to
+    /// render a dashboard of features that may be paused.
+    ///
+    /// Each macro expands independently, so `#[derive(Pausable)]` cannot discover the features
+    /// referenced by `#[pause]` on methods. Instead, they are declared via the `features`
+    /// attribute, which takes explicit `name` values as well as the names of methods that use the
+    /// default feature name:
+    ///
+    /// ```ignore
+    /// #[pausable(manager_roles(Role::PauseManager), features("increase_1", "Increase by two"))]
+    /// struct Contract { /* ... */}
+    /// ```
+    ///
+    /// The list must contain the feature and the `group`, if any, of every method annotated with
+    /// `#[pause]`, which is checked at compile time. It may contain further keys, e.g. the ones
+    /// checked by `#[if_paused]`. Duplicates are removed. If the attribute is omitted, `None` is
+    /// returned, since the features are unknown.
+    fn pa_all_feature_names(&self) -> Option<Vec<String>>;
+
+    /// Returns the roles whose grantees may call methods of feature `key` while it is paused.
+    /// Combined with `AccessControllable::acl_get_grantees`, this allows listing the accounts
+    /// which may bypass a paused feature.
+    ///
+    /// Like features, methods annotated with `#[pause]` cannot be discovered by
+    /// `#[derive(Pausable)]`. They are registered via the `exempt_roles` attribute:
+    ///
+    /// ```ignore
+    /// #[pausable(manager_roles(Role::PauseManager), exempt_roles(increase_4))]
+    /// struct Contract { /* ... */}
+    /// ```
+    ///
+    /// The roles passed to `except(roles(...))` of the registered methods are returned for their
+    /// feature and their `group`, if any. They are taken from the annotation itself, which
+    /// generates a hidden function next to the method. An empty list is returned for features
+    /// without exempt roles.
+    ///
+    /// The list must contain every method annotated with `#[pause(except(roles(...)))]`, which is
+    /// checked at compile time. Only `pub` methods outside of trait impls can be registered, hence
+    /// compilation fails if other methods pass `except(roles(...))` while `exempt_roles` is set.
+    /// Without `exempt_roles`, `None` is returned, since the exempt roles are unknown.
+    fn pa_feature_exempt_roles(&self, key: String) -> Option<Vec<String>>;
+
+    /// Returns whether feature `key` was paused or unpaused most recently, along with the time and
+    /// the account of that change. It is `None` if the feature was never paused.
+    ///
+    /// Recording changes requires additional storage, hence it is opt-in:
+    ///
+    /// ```ignore
+    /// #[pausable(manager_roles(Role::PauseManager), track_history)]
+    /// struct Contract { /* ... */}
+    /// ```
+    ///
+    /// Without `track_history`, this method panics. Note that `paused` reflects only changes of
+    /// `key` itself, i.e. a feature may be paused via "ALL" while `paused` is `false`.
+    fn pa_feature_status(&self, key: String) -> Option<FeatureStatus>;
+
+    /// Captures whether feature `key` is currently paused. The returned snapshot can be passed to
+    /// a callback, allowing it to act on the pause state at the time the callback was scheduled.
+    ///
+    /// This method is not exposed as a contract method.
+    fn pa_snapshot(&self, key: String) -> PauseSnapshot {
+        let paused = self.pa_is_paused(key.clone());
+        PauseSnapshot { key, paused }
+    }
+
     /// Returns all features that are currently paused.
     fn pa_all_paused(&self) -> Option<HashSet<String>>;
 
@@ -55,6 +278,9 @@ pub trait Pausable {
     /// the feature was already paused. In either case, the feature is paused after the function
     /// returns successfully.
     ///
+    /// An optional human-readable `reason` may be provided, e.g. to document an incident for
+    /// auditors. It is not stored in the contract, only included in the emitted event.
+    ///
     /// If the feature is newly paused (the return value is `true`), the following event will be
     /// emitted:
     ///
@@ -66,11 +292,14 @@ pub trait Pausable {
     ///   "data":
     ///     {
     ///       "by":"<OWNER_ACCOUNT>",
-    ///       "key":"<KEY>"
+    ///       "key":"<KEY>",
+    ///       "reason":"<REASON>"
     ///     }
     /// }
     /// ```
-    fn pa_pause_feature(&mut self, key: String) -> bool;
+    ///
+    /// The field `reason` is omitted if no reason is provided.
+    fn pa_pause_feature(&mut self, key: String, reason: Option<String>) -> bool;
 
     /// Unpauses feature `key`. This method fails if the caller has not been granted one of the
     /// access control `manager_roles` passed to the `Pausable` plugin.
@@ -94,19 +323,159 @@ pub trait Pausable {
     /// }
     /// ```
     fn pa_unpause_feature(&mut self, key: String) -> bool;
+
+    /// Pauses the features in `pause` and unpauses the features in `unpause` atomically, e.g. to
+    /// switch between features during a migration. This method fails if the caller has not been
+    /// granted one of the access control `manager_roles` passed to the `Pausable` plugin.
+    ///
+    /// A feature must not be contained in both `pause` and `unpause`, otherwise this method
+    /// panics.
+    ///
+    /// It returns whether any feature was paused or unpaused as a result of this function call.
+    /// The events of [`Self::pa_pause_feature`] and [`Self::pa_unpause_feature`] are emitted for
+    /// every feature that is newly paused or was paused, respectively. Events emitted for paused
+    /// features do not contain a `reason`.
+    fn pa_set_features(&mut self, pause: Vec<String>, unpause: Vec<String>) -> bool;
+}
+
+/// Helpers to compose pause checks in method bodies. They are implemented for every type that
+/// implements [`Pausable`] and, like [`Pausable::pa_is_paused`], take "ALL" into account.
+///
+/// `#[if_paused]` and `#[unpaused]` delegate to these helpers, hence a method body calling them
+/// fails with the same messages as these macros.
+pub trait PausableExt: Pausable {
+    /// Panics if feature `key` is paused. The message is prefixed with the code of
+    /// [`PluginError::Paused`](crate::PluginError::Paused).
+    fn pa_assert_not_paused(&self, key: &str) {
+        if self.pa_is_paused(key.to_string()) {
+            near_sdk::env::panic_str(&format!(
+                "PAU001: Pausable: {key} must not be paused to use this function"
+            ));
+        }
+    }
+
+    /// Panics if feature `key` is not paused. The message is prefixed with the code of
+    /// [`PluginError::NotPaused`](crate::PluginError::NotPaused).
+    fn pa_assert_paused(&self, key: &str) {
+        if !self.pa_is_paused(key.to_string()) {
+            near_sdk::env::panic_str(&format!(
+                "PAU002: Pausable: {key} must be paused to use this function"
+            ));
+        }
+    }
+
+    /// Returns whether any of the features `keys` is paused. If "ALL" is paused, this is `true`
+    /// for any non-empty `keys`.
+    fn pa_any_paused(&self, keys: &[&str]) -> bool {
+        keys.iter().any(|key| self.pa_is_paused(key.to_string()))
+    }
+}
+
+impl<T: Pausable + ?Sized> PausableExt for T {}
+
+/// Called by the code generated by `#[pause]`, `#[if_paused]` and `#[unpaused]`. If the contract
+/// does not implement [`Pausable`], compilation fails with an unsatisfied trait bound of this
+/// function, whose name tells how to fix the error.
+#[doc(hidden)]
+pub fn requires_derive_pausable<T: Pausable + ?Sized>() {}
+
+/// Attributes of `#[derive(Pausable)]` which are checked at compile time by the code generated by
+/// `#[pause]`.
+#[doc(hidden)]
+pub trait PausableAttributes {
+    /// Keys passed to `features`, or `None` if the attribute is omitted.
+    const FEATURES: Option<&'static [&'static str]>;
+    /// Names of the methods passed to `exempt_roles`, or `None` if the attribute is omitted.
+    const EXEMPT_ROLES_METHODS: Option<&'static [&'static str]>;
+}
+
+/// Describes a method annotated with `#[pause]`. It is implemented by the code generated by
+/// `#[pause]`.
+#[doc(hidden)]
+pub trait PauseAnnotation {
+    /// Name of the method.
+    const METHOD: &'static str;
+    /// Keys under which the method is paused, i.e. its feature and its group, if any.
+    const KEYS: &'static [&'static str];
+    /// Whether roles are passed to `except(roles(...))`.
+    const HAS_EXEMPT_ROLES: bool;
+    /// Whether the method can be registered in attributes of `#[derive(Pausable)]`, which holds
+    /// for `pub` methods.
+    const REGISTRABLE: bool;
+}
+
+/// Evaluating [`Self::CHECK`] fails compilation if the method annotated with `#[pause]`
+/// described by `A` is not registered in the attributes of contract `C`.
+#[doc(hidden)]
+pub struct CheckPauseAnnotation<C, A>(std::marker::PhantomData<(C, A)>);
+
+impl<C: PausableAttributes, A: PauseAnnotation> CheckPauseAnnotation<C, A> {
+    pub const CHECK: () = {
+        if C::FEATURES.is_some() {
+            let mut i = 0;
+            while i < A::KEYS.len() {
+                assert!(
+                    crate::access_controllable::is_registered(C::FEATURES, A::KEYS[i]),
+                    "Pausable: The feature or group of a method annotated with #[pause] is missing in features",
+                );
+                i += 1;
+            }
+        }
+        if A::HAS_EXEMPT_ROLES && C::EXEMPT_ROLES_METHODS.is_some() {
+            assert!(
+                A::REGISTRABLE,
+                "Pausable: Methods passing except(roles(...)) must be pub to be registered in exempt_roles",
+            );
+            assert!(
+                crate::access_controllable::is_registered(C::EXEMPT_ROLES_METHODS, A::METHOD),
+                "Pausable: A method passing except(roles(...)) is missing in exempt_roles",
+            );
+        }
+    };
+}
+
+/// The most recent change of the pause state of a feature, returned by
+/// [`Pausable::pa_feature_status`].
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct FeatureStatus {
+    /// Whether the feature was paused by the change.
+    pub paused: bool,
+    /// Block timestamp of the change.
+    pub last_changed: near_sdk::Timestamp,
+    /// Account that paused or unpaused the feature.
+    pub by: AccountId,
+}
+
+/// Pause state of a feature captured via [`Pausable::pa_snapshot`].
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct PauseSnapshot {
+    /// Key identifying the feature.
+    pub key: String,
+    /// Whether the feature was paused when the snapshot was taken.
+    pub paused: bool,
+}
+
+impl PauseSnapshot {
+    /// Returns whether the feature was paused when the snapshot was taken.
+    pub fn was_paused_at_entry(&self) -> bool {
+        self.paused
+    }
 }
 
 /// Event emitted when a feature is paused.
 #[derive(Serialize, Clone)]
-pub struct Pause {
+pub struct FeaturePaused {
     /// Account Id that triggered the pause.
     pub by: AccountId,
     /// Key identifying the feature that was paused.
     pub key: String,
+    /// Optional human-readable reason for pausing the feature.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
 }
 
-impl AsEvent<Pause> for Pause {
-    fn metadata(&self) -> EventMetadata<Pause> {
+impl AsEvent<FeaturePaused> for FeaturePaused {
+    fn metadata(&self) -> EventMetadata<FeaturePaused> {
         EventMetadata {
             standard: "Pausable".to_string(),
             version: "1.0.0".to_string(),
@@ -118,15 +487,15 @@ impl AsEvent<Pause> for Pause {
 
 /// Event emitted when a feature is unpaused.
 #[derive(Serialize, Clone)]
-pub struct Unpause {
+pub struct FeatureUnpaused {
     /// Account Id that triggered the unpause.
     pub by: AccountId,
     /// Key identifying the feature that was unpaused.
     pub key: String,
 }
 
-impl AsEvent<Unpause> for Unpause {
-    fn metadata(&self) -> EventMetadata<Unpause> {
+impl AsEvent<FeatureUnpaused> for FeatureUnpaused {
+    fn metadata(&self) -> EventMetadata<FeatureUnpaused> {
         EventMetadata {
             standard: "Pausable".to_string(),
             version: "1.0.0".to_string(),
@@ -135,3 +504,130 @@ impl AsEvent<Unpause> for Unpause {
         }
     }
 }
+
+/// Former name of [`FeaturePaused`].
+#[deprecated(note = "use `FeaturePaused` instead")]
+pub type Pause = FeaturePaused;
+
+/// Former name of [`FeatureUnpaused`].
+#[deprecated(note = "use `FeatureUnpaused` instead")]
+pub type Unpause = FeatureUnpaused;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mimics the default implementation of `Pausable` provided by `#[derive(Pausable)]`.
+    struct Contract {
+        paused: HashSet<String>,
+    }
+
+    impl Contract {
+        fn with_paused(keys: &[&str]) -> Self {
+            Self {
+                paused: keys.iter().map(|key| key.to_string()).collect(),
+            }
+        }
+    }
+
+    impl Pausable for Contract {
+        fn pa_storage_key(&self) -> &'static [u8] {
+            b"__PAUSE__"
+        }
+
+        fn pa_is_paused(&self, key: String) -> bool {
+            self.paused.contains(&key) || self.paused.contains("ALL")
+        }
+
+        fn pa_is_paused_any(&self, keys: Vec<String>) -> bool {
+            keys.into_iter().any(|key| self.pa_is_paused(key))
+        }
+
+        fn pa_is_paused_all(&self, keys: Vec<String>) -> bool {
+            keys.into_iter().all(|key| self.pa_is_paused(key))
+        }
+
+        fn pa_all_feature_names(&self) -> Option<Vec<String>> {
+            None
+        }
+
+        fn pa_feature_exempt_roles(&self, _key: String) -> Option<Vec<String>> {
+            None
+        }
+
+        fn pa_feature_status(&self, _key: String) -> Option<FeatureStatus> {
+            None
+        }
+
+        fn pa_all_paused(&self) -> Option<HashSet<String>> {
+            Some(self.paused.clone())
+        }
+
+        fn pa_pause_feature(&mut self, key: String, _reason: Option<String>) -> bool {
+            self.paused.insert(key)
+        }
+
+        fn pa_unpause_feature(&mut self, key: String) -> bool {
+            self.paused.remove(&key)
+        }
+
+        fn pa_set_features(&mut self, pause: Vec<String>, unpause: Vec<String>) -> bool {
+            let mut changed = false;
+            for key in pause {
+                changed |= self.paused.insert(key);
+            }
+            for key in unpause {
+                changed |= self.paused.remove(&key);
+            }
+            changed
+        }
+    }
+
+    #[test]
+    fn test_pa_assert_not_paused() {
+        let contract = Contract::with_paused(&["transfer"]);
+        contract.pa_assert_not_paused("mint");
+    }
+
+    #[test]
+    #[should_panic(expected = "PAU001: Pausable: transfer must not be paused to use this function")]
+    fn test_pa_assert_not_paused_fails() {
+        let contract = Contract::with_paused(&["transfer"]);
+        contract.pa_assert_not_paused("transfer");
+    }
+
+    #[test]
+    #[should_panic(expected = "PAU001: Pausable: mint must not be paused to use this function")]
+    fn test_pa_assert_not_paused_fails_if_all_paused() {
+        let contract = Contract::with_paused(&["ALL"]);
+        contract.pa_assert_not_paused("mint");
+    }
+
+    #[test]
+    fn test_pa_assert_paused() {
+        let contract = Contract::with_paused(&["transfer"]);
+        contract.pa_assert_paused("transfer");
+
+        let contract = Contract::with_paused(&["ALL"]);
+        contract.pa_assert_paused("mint");
+    }
+
+    #[test]
+    #[should_panic(expected = "PAU002: Pausable: mint must be paused to use this function")]
+    fn test_pa_assert_paused_fails() {
+        let contract = Contract::with_paused(&["transfer"]);
+        contract.pa_assert_paused("mint");
+    }
+
+    #[test]
+    fn test_pa_any_paused() {
+        let contract = Contract::with_paused(&["transfer"]);
+        assert!(contract.pa_any_paused(&["mint", "transfer"]));
+        assert!(!contract.pa_any_paused(&["mint", "burn"]));
+        assert!(!contract.pa_any_paused(&[]));
+
+        let contract = Contract::with_paused(&["ALL"]);
+        assert!(contract.pa_any_paused(&["mint"]));
+        assert!(!contract.pa_any_paused(&[]));
+    }
+}
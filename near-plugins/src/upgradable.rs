@@ -23,6 +23,21 @@
 //! method. The documentation of these methods and the [example contract] explain how to define and
 //! whitelist roles to manage authorization for the `Upgradable` plugin.
 //!
+//! Deriving `Upgradable` without the `access_control_roles` attribute fails compilation with an
+//! error pointing to the contract:
+//!
+//! ```ignore
+//! #[near(contract_state)]
+//! #[derive(Upgradable, PanicOnDefault)]
+//! pub struct Contract;
+//! // error: Upgradable requires the contract to be AccessControllable. Add
+//! // `#[access_control(role_type(...))]` to the contract and pass the roles that may call
+//! // `Upgradable` methods via `#[upgradable(access_control_roles(...))]`.
+//! ```
+//!
+//! If `access_control_roles` are specified but the contract is not `#[access_control]`, the error
+//! states that the trait bound `Contract: AccessControllable` is not satisfied.
+//!
 //! ## State migration
 //!
 //! Upgrading a contract might require [state migration]. The `Upgradable` plugin allows to attach a
@@ -31,10 +46,77 @@
 //! initial code remains active. More detailed information is available in the documentation of
 //! [`Upgradable::up_deploy_code`].
 //!
+//! ## Post-deployment hook
+//!
+//! A method of the contract can be specified to run after code was deployed successfully, for
+//! example to bump a version counter:
+//!
+//! ```ignore
+//! #[upgradable(on_deploy = "on_deploy", access_control_roles(/* ... */))]
+//! ```
+//!
+//! Instead of a method name, a path to a function with signature `fn(&mut Self)` may be passed,
+//! e.g. `on_deploy = "hooks::on_deploy"`. Compilation fails if the value is not a valid path.
+//!
+//! The hook is called with `&mut self` from a callback that is scheduled by
+//! [`Upgradable::up_deploy_code`] and [`Upgradable::up_emergency_deploy_code`]. It is not called
+//! if the deployment fails.
+//!
+//! ## Interaction with `Pausable`
+//!
+//! By default `Upgradable` is independent of `Pausable`. For contracts deriving both plugins,
+//! [`Upgradable::up_deploy_code`] can be made to consult whether "ALL" features are paused:
+//!
+//! ```ignore
+//! // Deployments succeed only during a global pause.
+//! #[upgradable(deploy_requires_paused, access_control_roles(/* ... */))]
+//! // Deployments fail during a global pause.
+//! #[upgradable(deploy_forbidden_when_paused, access_control_roles(/* ... */))]
+//! ```
+//!
+//! Both options require the contract to be `Pausable` and cannot be combined. Emergency
+//! deployments via [`Upgradable::up_emergency_deploy_code`] are not affected.
+//!
+//! ## Named slots
+//!
+//! Coordinated upgrades consisting of several steps may require staging more than one code at a
+//! time. With the opt-in `named_slots` option, code can be staged in slots identified by a name:
+//!
+//! ```ignore
+//! #[upgradable(named_slots, access_control_roles(/* ... */))]
+//! ```
+//!
+//! Each slot has its own staged code and staging timestamp, which are managed via
+//! [`Upgradable::up_stage_code_named`] and [`Upgradable::up_deploy_code_named`]. The default slot
+//! used by [`Upgradable::up_stage_code`] and [`Upgradable::up_deploy_code`] remains available and
+//! is independent of named slots. Without `named_slots`, methods operating on named slots panic.
+//!
+//! ## Preventing overwrites of staged code
+//!
+//! By default, staging code discards previously staged code and resets the staging timestamp. To
+//! prevent repeatedly postponing deployments that way, the opt-in `no_overwrite_staged` option
+//! makes staging fail while code is staged in the same slot:
+//!
+//! ```ignore
+//! #[upgradable(no_overwrite_staged, access_control_roles(/* ... */))]
+//! ```
+//!
+//! Staged code must then be removed via [`Upgradable::up_unstage_code`] before new code can be
+//! staged.
+//!
+//! ## Versions
+//!
+//! Deployments may set a human readable version, which is stored under the storage prefix of the
+//! plugin and returned by [`Upgradable::up_version`]. See [`Upgradable::up_deploy_code`] for
+//! details.
+//!
 //! ## Stale staged code
 //!
-//! After the code is deployed, it should be removed from staging to unstake tokens and avoid the
-//! issues described in [`Self::up_deploy_code`].
+//! By default, [`Upgradable::up_deploy_code`] removes the deployed code from staging, which
+//! releases the storage staked for the code and prevents deploying it twice by accident. Passing `keep_staged = Some(true)`
+//! keeps it staged, e.g. to deploy it again. See [`Upgradable::up_deploy_code`] for details.
+//! [`Upgradable::up_emergency_deploy_code`] and [`Upgradable::up_deploy_code_named`] always
+//! remove the deployed code from staging.
 //!
 //! ## Upgrading code that contains a security vulnerability
 //!
@@ -60,6 +142,7 @@
 //! [batch transaction]: https://docs.near.org/concepts/basics/transactions/overview
 //! [time between scheduling and execution]: https://docs.near.org/sdk/rust/promises/intro
 use crate::events::{AsEvent, EventMetadata};
+use near_sdk::json_types::U64;
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{AccountId, CryptoHash, Gas, NearToken, Promise};
 
@@ -79,24 +162,142 @@ pub trait Upgradable {
     /// Returns all staging durations and timestamps.
     fn up_get_delay_status(&self) -> UpgradableDurationStatus;
 
+    /// Returns the same values as [`Upgradable::up_get_delay_status`], serialized as JSON strings
+    /// to be consumed without loss of precision by clients like JavaScript front-ends.
+    fn up_get_delay_status_json(&self) -> UpgradableDurationStatusJson;
+
+    /// Returns the time remaining until staged code may be deployed. It is `Some(0)` if staged
+    /// code may be deployed now and `None` if no code is staged.
+    fn up_time_until_deployable(&self) -> Option<near_sdk::Duration>;
+
+    /// Returns the time remaining until the staged update of the staging duration may be applied.
+    /// It is `Some(0)` if the update may be applied now and `None` if no update is staged.
+    fn up_time_until_duration_update_applicable(&self) -> Option<near_sdk::Duration>;
+
+    /// Returns the staging duration in seconds, rounded down. It is `None` if the staging duration
+    /// was not initialized.
+    fn up_staging_duration_secs(&self) -> Option<u64>;
+
+    /// Returns the staged update of the staging duration in seconds, rounded down. It is `None` if
+    /// no update is staged.
+    fn up_new_staging_duration_secs(&self) -> Option<u64>;
+
     /// Allows an authorized account to stage code to be potentially deployed later. It sets the
     /// staging timestamp, which is the earliest time at which `code` may be deployed. The staging
     /// timestamp is calculated as the block timestamp plus the staging duration. Any code that was
-    /// staged previously is discarded.
+    /// staged previously is discarded, unless the `no_overwrite_staged` option of the `Upgradable`
+    /// macro is set. Then this method panics while code is staged, which prevents resetting the
+    /// staging timestamp by staging code again. Staged code must be removed via
+    /// [`Upgradable::up_unstage_code`] first.
     ///
     /// If `code` is empty, previously staged code and the corresponding staging timestamp are
     /// removed.
     ///
+    /// Returns the hash of the staged code, which equals the value subsequently returned by
+    /// [`Upgradable::up_staged_code_hash`]. This allows recording the hash, e.g. for review,
+    /// without another call. If `code` is empty, `None` is returned.
+    ///
     /// In the default implementation, this method is protected by access control provided by the
     /// `AccessControllable` plugin. The roles which may successfully call this method are
     /// specified via the `code_stagers` field of the `Upgradable` macro's `access_control_roles`
     /// attribute. The example contract (accessible via the `README`) shows how access control roles
     /// can be defined and passed on to the `Upgradable` macro.
-    fn up_stage_code(&mut self, code: Vec<u8>);
+    fn up_stage_code(&mut self, code: Vec<u8>) -> Option<CryptoHash>;
+
+    /// Behaves like [`Upgradable::up_stage_code`], but allows announcing the time at which `code`
+    /// will be deployed. The staging timestamp is set to `deploy_timestamp`, unless it is earlier
+    /// than the block timestamp plus the staging duration. In that case the latter is used, so
+    /// the staging duration cannot be circumvented.
+    ///
+    /// If `code` is empty, previously staged code and the corresponding staging timestamp are
+    /// removed and `deploy_timestamp` is ignored.
+    ///
+    /// Like `up_stage_code`, this method is restricted to the roles specified via `code_stagers`.
+    fn up_stage_code_at(
+        &mut self,
+        code: Vec<u8>,
+        deploy_timestamp: near_sdk::Timestamp,
+    ) -> Option<CryptoHash>;
+
+    /// Behaves like [`Upgradable::up_stage_code`], though its arguments are passed as JSON with
+    /// `code` encoded as base64 string. The optional `deploy_timestamp` behaves like the one of
+    /// [`Upgradable::up_stage_code_at`] and may be omitted. It is a timestamp in nanoseconds
+    /// encoded as string, since JavaScript numbers cannot represent such timestamps exactly:
+    ///
+    /// ```json
+    /// { "code": "AGFzbQE...", "deploy_timestamp": "1700000000000000000" }
+    /// ```
+    ///
+    /// `up_stage_code` takes borsh serialized arguments, which is more efficient for large code.
+    /// This method is meant for tools that can only send JSON arguments, e.g. most CLIs. Both
+    /// methods are protected by the same `code_stagers` roles, stage code identically and return
+    /// the hash of staged code.
+    fn up_stage_code_json(
+        &mut self,
+        code: near_sdk::json_types::Base64VecU8,
+        deploy_timestamp: Option<near_sdk::json_types::U64>,
+    ) -> Option<CryptoHash>;
+
+    /// Allows an authorized account to upload code in chunks, for code which is too large to be
+    /// passed to [`Upgradable::up_stage_code`] in a single transaction. Chunks are accumulated in
+    /// a buffer which is separate from the staged code.
+    ///
+    /// Chunks must be uploaded in order: `offset` must equal the number of bytes uploaded so far
+    /// and `total_len` must be the same for all chunks of an upload. A chunk with `offset` 0
+    /// starts a new upload, discarding any partially uploaded code. Once all chunks are uploaded,
+    /// [`Upgradable::up_finalize_staged_code`] stages the assembled code.
+    ///
+    /// Like `up_stage_code`, this method is restricted to the roles specified via `code_stagers`.
+    fn up_stage_code_chunk(&mut self, offset: u64, chunk: Vec<u8>, total_len: u64);
+
+    /// Allows an authorized account to stage the code assembled via
+    /// [`Upgradable::up_stage_code_chunk`]. It panics if the length of the assembled code differs
+    /// from the `total_len` passed with the chunks. Otherwise it behaves like `up_stage_code`
+    /// called with the assembled code and clears the buffer.
+    ///
+    /// Like `up_stage_code`, this method is restricted to the roles specified via `code_stagers`.
+    fn up_finalize_staged_code(&mut self);
+
+    /// Allows an authorized account to remove staged code, the corresponding staging timestamp and
+    /// any partially uploaded chunks.
+    ///
+    /// Like `up_stage_code`, this method is restricted to the roles specified via `code_stagers`.
+    fn up_unstage_code(&mut self);
+
+    /// Allows an authorized account to stage code that is provided by a code registry, e.g. to
+    /// upgrade multiple contracts sharing the same code without uploading it to each of them.
+    ///
+    /// NEAR does not allow reading the code deployed to another account. Instead, the code is
+    /// requested from `source_account`, which is expected to implement [`CodeRegistry`]. Once the
+    /// registry responded, [`Self::up_stage_code_from_hash_callback`] verifies that the hash of the
+    /// returned code equals `expected_hash` and stages the code like [`Self::up_stage_code`]. Hence
+    /// a registry cannot inject code other than the code that was requested.
+    ///
+    /// The returned promise fails if the registry does not provide code for `expected_hash`.
+    ///
+    /// Like `up_stage_code`, this method is restricted to the roles specified via `code_stagers`.
+    fn up_stage_code_from_hash(
+        &mut self,
+        source_account: AccountId,
+        expected_hash: CryptoHash,
+    ) -> Promise;
+
+    /// Callback of [`Self::up_stage_code_from_hash`] which stages the code returned by the
+    /// registry after verifying its hash.
+    ///
+    /// In the default implementation, this method is private and may only be called by the
+    /// contract itself.
+    fn up_stage_code_from_hash_callback(&mut self, expected_hash: CryptoHash);
 
     /// Returns the staged code.
     fn up_staged_code(&self) -> Option<Vec<u8>>;
 
+    /// Returns the length in bytes of the staged code or `None` if no code is staged.
+    ///
+    /// Clients which only need to know whether or how much code is staged should prefer this
+    /// method over [`Upgradable::up_staged_code`], since it avoids returning the code itself.
+    fn up_staged_code_length(&self) -> Option<u64>;
+
     /// Returns the hash of the staged code
     fn up_staged_code_hash(&self) -> Option<CryptoHash>;
 
@@ -114,23 +315,39 @@ pub trait Upgradable {
     /// version of the contract. A failure during state migration can leave the contract in a broken
     /// state, which is avoided by the roleback mechanism described above.
     ///
+    /// # Setting a version
+    ///
+    /// If `version` is provided, e.g. `"v1.4.2"`, a call to [`Self::up_set_version`] is appended
+    /// to the batch promise. Hence the version is stored only if the deployment succeeds and it is
+    /// returned by [`Self::up_version`] afterwards. Since that call is executed by the new code,
+    /// the new code must be `Upgradable` as well.
+    ///
+    /// # Validation of staged code
+    ///
+    /// Before scheduling the deployment, the staged code is checked to start with the wasm magic
+    /// number `\0asm` followed by a version, i.e. to have a length of at least 8 bytes. Otherwise
+    /// the method panics. This catches obviously corrupt code, e.g. text that was staged by
+    /// accident. It does not guarantee the code is a valid contract.
+    ///
     /// # Removal of staged code
     ///
-    /// After deployment, staged code remains in storage. It is not removed automatically as this
-    /// would cost extra gas and therefore increase the risk of the transaction hitting NEAR's gas
-    /// limit. Moreover, in case the deployment is roled back due to a failure in the attached
-    /// function call, the staged code might still be required.
+    /// If `keep_staged` is `None` or `Some(false)`, the staged code and the staging timestamp are
+    /// removed before the deployment is scheduled. Removal happens in the receipt which executes
+    /// this method, since the batch promise is executed by the new code. Hence staged code is
+    /// removed even if the deployment or the attached function call fails later on, in which case
+    /// it must be staged again before retrying. Removing staged code releases the
+    /// [storage staked] for the code and avoids deploying code and executing an attached function
+    /// call multiple times.
     ///
-    /// Once staged code is no longer needed, it can be removed by passing the appropriate arguments
-    /// to [`Self::up_stage_code`]. Removing staged code allows to [unstake tokens] that are storage
-    /// staked.
+    /// If `keep_staged` is `Some(true)`, the staged code and the staging timestamp remain in
+    /// storage, so the code can be deployed again, e.g. after a failed attached function call.
+    /// Such code can be removed later on via [`Self::up_unstage_code`] or by passing the
+    /// appropriate arguments to [`Self::up_stage_code`].
     ///
-    /// It is recommended to remove staged code as soon as possible to avoid deploying code and
-    /// executing an attached function call multiple times. Using batch transaction for this purpose
-    /// can be dangerous. Since `up_deploy_code` returns a promise, there can be unexpected outcomes
-    /// when it is combined in a batch transaction with another function call that removes code from
-    /// storage. This is demonstrated in the `Upgradable` test
-    /// `test_deploy_code_in_batch_transaction_pitfall`.
+    /// Removing kept code with a separate function call in a batch transaction can be dangerous.
+    /// Since `up_deploy_code` returns a promise, there can be unexpected outcomes when it is
+    /// combined in a batch transaction with another function call that removes code from storage.
+    /// This is demonstrated in the `Upgradable` test `test_deploy_code_in_batch_transaction_pitfall`.
     ///
     /// # Permissions
     ///
@@ -138,12 +355,134 @@ pub trait Upgradable {
     /// `AccessControllable` plugin. The roles which may successfully call this method are
     /// specified via the `code_deployers` field of the `Upgradable` macro's `access_control_roles`
     /// attribute. The example contract (accessible via the `README`) shows how access control roles
-    /// can be defined and passed on to the `Upgradable` macro.
+    /// can be defined and passed on to the `Upgradable` macro. Super-admins may replace these roles
+    /// via [`Self::up_set_code_deployer_roles`].
     ///
     /// [asynchronous design]: https://docs.near.org/concepts/basics/transactions/overview
     /// [state migration]: https://docs.near.org/develop/upgrade#migrating-the-state
     /// [storage staked]: https://docs.near.org/concepts/storage/storage-staking#btw-you-can-remove-data-to-unstake-some-tokens
-    fn up_deploy_code(&mut self, function_call_args: Option<FunctionCallArgs>) -> Promise;
+    fn up_deploy_code(
+        &mut self,
+        function_call_args: Option<FunctionCallArgs>,
+        version: Option<String>,
+        keep_staged: Option<bool>,
+    ) -> Promise;
+
+    /// Stores `version` as the version of the deployed code. It is called in the batch promise
+    /// created by [`Self::up_deploy_code`] if a version is passed to it.
+    ///
+    /// In the default implementation, this method is private and may only be called by the
+    /// contract itself.
+    fn up_set_version(&mut self, version: String);
+
+    /// Returns the version passed to the most recent successful deployment which specified a
+    /// version, allowing explorers to display it alongside the code hash. It is `None` if no
+    /// version was set.
+    fn up_version(&self) -> Option<String>;
+
+    /// Allows an authorized account to deploy the staged code immediately, regardless of the
+    /// staging timestamp. It is meant for emergencies, e.g. fixing an exploit, where waiting for
+    /// the staging duration to pass is not acceptable. Apart from skipping the delay, it behaves like
+    /// [`Self::up_deploy_code`] without `keep_staged`, i.e. the staged code and the staging
+    /// timestamp are removed.
+    ///
+    /// The following event is emitted to make emergency deployments auditable:
+    ///
+    /// ```json
+    /// {
+    ///   "standard":"Upgradable",
+    ///   "version":"1.0.0",
+    ///   "event":"emergency_code_deployed",
+    ///   "data":
+    ///     {
+    ///       "by":"<DEPLOYER_ACCOUNT>",
+    ///       "code_hash":"<CODE_HASH>"
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// In the default implementation, this method is protected by access control provided by the
+    /// `AccessControllable` plugin. The roles which may successfully call this method are
+    /// specified via the optional `emergency_code_deployers` field of the `Upgradable` macro's
+    /// `access_control_roles` attribute. If no roles are specified, this method always panics.
+    fn up_emergency_deploy_code(&mut self, function_call_args: Option<FunctionCallArgs>)
+        -> Promise;
+
+    /// Behaves like [`Upgradable::up_stage_code`], but stages `code` in the named slot `slot`
+    /// instead of the default slot. Code staged in other slots is not affected. Like
+    /// `up_stage_code`, it returns the hash of the staged code, or `None` if `code` is empty and
+    /// the code staged in `slot` was removed. With `no_overwrite_staged`, it fails while code is
+    /// staged in `slot`.
+    ///
+    /// Like `up_stage_code`, this method is restricted to the roles specified via `code_stagers`.
+    /// It panics unless the `named_slots` option of the `Upgradable` macro is set.
+    fn up_stage_code_named(
+        &mut self,
+        slot: String,
+        code: Vec<u8>,
+        deploy_timestamp: Option<near_sdk::Timestamp>,
+    ) -> Option<CryptoHash>;
+
+    /// Returns the code staged in the named slot `slot`.
+    fn up_staged_code_named(&self, slot: String) -> Option<Vec<u8>>;
+
+    /// Returns the hash of the code staged in the named slot `slot`.
+    fn up_staged_code_hash_named(&self, slot: String) -> Option<CryptoHash>;
+
+    /// Behaves like [`Upgradable::up_deploy_code`], but deploys the code staged in the named slot
+    /// `slot`, subject to that slot's staging timestamp. It panics if no code is staged in `slot`.
+    /// Like [`Upgradable::up_deploy_code`] without `keep_staged`, it removes the code and the
+    /// staging timestamp of `slot`, while other slots are not affected.
+    ///
+    /// Like `up_deploy_code`, this method is restricted to the roles specified via
+    /// `code_deployers`. It panics unless the `named_slots` option of the `Upgradable` macro is
+    /// set.
+    fn up_deploy_code_named(
+        &mut self,
+        slot: String,
+        function_call_args: Option<FunctionCallArgs>,
+        version: Option<String>,
+    ) -> Promise;
+
+    /// Returns the roles whose grantees may call [`Self::up_deploy_code`] and
+    /// [`Self::up_deploy_code_named`]. These are the roles passed to `code_deployers`, unless they
+    /// were replaced via [`Self::up_set_code_deployer_roles`].
+    fn up_get_code_deployer_roles(&self) -> Vec<String>;
+
+    /// Replaces the roles whose grantees may deploy code, which allows changing deployment policy
+    /// without recompiling the contract. Initially, the roles passed to `code_deployers` are
+    /// authorized.
+    ///
+    /// It panics if the predecessor is not an `AccessControllable` super-admin, if `roles` is
+    /// empty or if it contains a name which is not a role of the contract.
+    ///
+    /// If roles are updated, the following event will be emitted:
+    ///
+    /// ```json
+    /// {
+    ///    "standard":"Upgradable",
+    ///    "version":"1.0.0",
+    ///    "event":"code_deployer_roles_updated",
+    ///    "data": {
+    ///       "by":"<SUPER_ADMIN>",
+    ///       "roles":["<ROLE>"]
+    ///    }
+    /// }
+    /// ```
+    fn up_set_code_deployer_roles(&mut self, roles: Vec<String>);
+
+    /// Callback attached to deployments if a hook is specified via the `on_deploy` attribute of
+    /// the `Upgradable` macro. It calls the hook only if the deployment succeeded, including the
+    /// function call attached to it. Since the callback is executed after the deployment, it is
+    /// the hook of the _deployed_ code that is called.
+    ///
+    /// Since the caller of `up_deploy_code` receives the result of this callback, it panics if the
+    /// deployment failed. A panic of the hook makes the transaction fail as well, however the
+    /// deployment is not rolled back.
+    ///
+    /// In the default implementation, this method is private and may only be called by the
+    /// contract itself. If no hook is specified, it does nothing.
+    fn up_on_deploy_callback(&mut self);
 
     /// Initializes the duration of the delay for deploying the staged code. It defaults to zero if
     /// code is staged before the staging duration is initialized. Once the staging duration has
@@ -158,6 +497,13 @@ pub trait Upgradable {
     /// how access control roles can be defined and passed on to the `Upgradable` macro.
     fn up_init_staging_duration(&mut self, staging_duration: near_sdk::Duration);
 
+    /// Same as [`Self::up_init_staging_duration`], except that the staging duration is passed in
+    /// seconds instead of nanoseconds. It panics if the duration in nanoseconds overflows `u64`.
+    ///
+    /// In the default implementation, this method is restricted to the same roles as
+    /// `up_init_staging_duration`.
+    fn up_init_staging_duration_secs(&mut self, staging_duration_secs: u64);
+
     /// Allows an authorized account to stage an update of the staging duration. It panics if the
     /// staging duration was not previously initialized with [`Self::up_init_staging_duration`]. It
     /// sets the timestamp for the new staging duration, which is the earliest time at which the
@@ -171,6 +517,14 @@ pub trait Upgradable {
     /// can be defined and passed on to the `Upgradable` macro.
     fn up_stage_update_staging_duration(&mut self, staging_duration: near_sdk::Duration);
 
+    /// Same as [`Self::up_stage_update_staging_duration`], except that the staging duration is
+    /// passed in seconds instead of nanoseconds. It panics if the duration in nanoseconds overflows
+    /// `u64`.
+    ///
+    /// In the default implementation, this method is restricted to the same roles as
+    /// `up_stage_update_staging_duration`.
+    fn up_stage_update_staging_duration_secs(&mut self, staging_duration_secs: u64);
+
     /// Allows an authorized account to apply the staged update of the staging duration. It fails if
     /// no staging duration update is staged.
     ///
@@ -182,14 +536,86 @@ pub trait Upgradable {
     fn up_apply_update_staging_duration(&mut self);
 }
 
-#[derive(Deserialize, Serialize)]
+/// Interface of registries from which [`Upgradable::up_stage_code_from_hash`] requests code.
+///
+/// A registry is a contract that stores code by hash. Arguments and return values of its methods
+/// are serialized with borsh, since code is too large to be passed as JSON efficiently:
+///
+/// ```ignore
+/// #[near]
+/// impl CodeRegistry for Registry {
+///     #[result_serializer(borsh)]
+///     fn get_code(&self, #[serializer(borsh)] code_hash: CryptoHash) -> Option<Vec<u8>> {
+///         // ...
+///     }
+/// }
+/// ```
+pub trait CodeRegistry {
+    /// Returns the code whose sha256 hash is `code_hash` or `None` if the registry does not store
+    /// such code.
+    fn get_code(&self, code_hash: CryptoHash) -> Option<Vec<u8>>;
+}
+
+/// Staging durations and timestamps as returned by [`Upgradable::up_get_delay_status`].
+///
+/// Durations and timestamps are in nanoseconds and serialized as JSON numbers, e.g.:
+///
+/// ```json
+/// {
+///   "staging_duration": 86400000000000,
+///   "staging_timestamp": 1700000000000000000,
+///   "new_staging_duration": null,
+///   "new_staging_duration_timestamp": null
+/// }
+/// ```
+///
+/// Timestamps exceed the range of integers that JavaScript numbers represent exactly. Clients
+/// parsing JSON into such numbers should use [`Upgradable::up_get_delay_status_json`] instead.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct UpgradableDurationStatus {
+    /// The duration that must pass between staging and deploying code.
     pub staging_duration: Option<near_sdk::Duration>,
+    /// The earliest time at which the staged code may be deployed.
     pub staging_timestamp: Option<near_sdk::Timestamp>,
+    /// A staged update of the staging duration.
     pub new_staging_duration: Option<near_sdk::Duration>,
+    /// The earliest time at which the staged update of the staging duration may be applied.
     pub new_staging_duration_timestamp: Option<near_sdk::Timestamp>,
 }
 
+/// Same as [`UpgradableDurationStatus`], except that values are serialized as JSON strings, e.g.:
+///
+/// ```json
+/// {
+///   "staging_duration": "86400000000000",
+///   "staging_timestamp": "1700000000000000000",
+///   "new_staging_duration": null,
+///   "new_staging_duration_timestamp": null
+/// }
+/// ```
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct UpgradableDurationStatusJson {
+    /// The duration that must pass between staging and deploying code.
+    pub staging_duration: Option<U64>,
+    /// The earliest time at which the staged code may be deployed.
+    pub staging_timestamp: Option<U64>,
+    /// A staged update of the staging duration.
+    pub new_staging_duration: Option<U64>,
+    /// The earliest time at which the staged update of the staging duration may be applied.
+    pub new_staging_duration_timestamp: Option<U64>,
+}
+
+impl From<UpgradableDurationStatus> for UpgradableDurationStatusJson {
+    fn from(status: UpgradableDurationStatus) -> Self {
+        Self {
+            staging_duration: status.staging_duration.map(U64),
+            staging_timestamp: status.staging_timestamp.map(U64),
+            new_staging_duration: status.new_staging_duration.map(U64),
+            new_staging_duration_timestamp: status.new_staging_duration_timestamp.map(U64),
+        }
+    }
+}
+
 /// Specifies a function call to be appended to the actions of a promise via
 /// [`near_sdk::Promise::function_call`]).
 #[derive(Deserialize, Serialize, Debug)]
@@ -243,3 +669,45 @@ impl AsEvent<DeployCode> for DeployCode {
         }
     }
 }
+
+/// Event emitted when code is deployed via [`Upgradable::up_emergency_deploy_code`], bypassing the
+/// staging duration.
+#[derive(Serialize, Clone)]
+pub struct EmergencyCodeDeployed {
+    /// The account that deployed the code.
+    pub by: AccountId,
+    /// The hash of the code that was deployed.
+    pub code_hash: CryptoHash,
+}
+
+impl AsEvent<EmergencyCodeDeployed> for EmergencyCodeDeployed {
+    fn metadata(&self) -> EventMetadata<EmergencyCodeDeployed> {
+        EventMetadata {
+            standard: "Upgradable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "emergency_code_deployed".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}
+
+/// Event emitted when the roles authorized to deploy code are replaced via
+/// [`Upgradable::up_set_code_deployer_roles`].
+#[derive(Serialize, Clone)]
+pub struct CodeDeployerRolesUpdated {
+    /// The super-admin that updated the roles.
+    pub by: AccountId,
+    /// The roles whose grantees may deploy code from now on.
+    pub roles: Vec<String>,
+}
+
+impl AsEvent<CodeDeployerRolesUpdated> for CodeDeployerRolesUpdated {
+    fn metadata(&self) -> EventMetadata<CodeDeployerRolesUpdated> {
+        EventMetadata {
+            standard: "Upgradable".to_string(),
+            version: "1.0.0".to_string(),
+            event: "code_deployer_roles_updated".to_string(),
+            data: Some(self.clone()),
+        }
+    }
+}